@@ -13,6 +13,29 @@ mod m20250223_075910_create_tests_table;
 // mod m20250223_075918_create_test_states_table;
 mod m20250223_075930_create_test_answers_table;
 mod m20250404_031734_create_test_question_results_table;
+mod m20261019_080000_add_revision_to_tests_table;
+mod m20261019_090000_create_user_tokens_table;
+mod m20261019_100000_create_role_changes_table;
+mod m20261019_110000_create_rate_limit_tables;
+mod m20261019_120000_add_totp_to_users_table;
+mod m20261019_130000_create_user_identities_table;
+mod m20261019_140000_create_api_tokens_table;
+mod m20261019_150000_create_audit_events_table;
+mod m20261019_160000_add_deleted_at_columns;
+mod m20261019_170000_create_media_table;
+mod m20261019_180000_add_content_format_columns;
+mod m20261019_190000_create_tags_tables;
+mod m20261019_200000_add_difficulty_columns;
+mod m20261019_210000_add_adaptive_testing;
+mod m20261019_220000_add_practice_mode;
+mod m20261019_230000_create_notes_table;
+mod m20261019_240000_create_comments_tables;
+mod m20261019_250000_create_change_requests_table;
+mod m20261019_260000_create_question_revisions_table;
+mod m20261019_270000_create_ownership_transfers_table;
+mod m20261019_280000_backfill_test_session_ids;
+mod m20261019_290000_add_avatar_media_id_to_users;
+mod m20261019_300000_drop_role_changes_table;
 
 pub struct Migrator;
 
@@ -31,6 +54,29 @@ impl MigratorTrait for Migrator {
             Box::new(m20250223_075910_create_tests_table::Migration),
             Box::new(m20250223_075930_create_test_answers_table::Migration),
             Box::new(m20250404_031734_create_test_question_results_table::Migration),
+            Box::new(m20261019_080000_add_revision_to_tests_table::Migration),
+            Box::new(m20261019_090000_create_user_tokens_table::Migration),
+            Box::new(m20261019_100000_create_role_changes_table::Migration),
+            Box::new(m20261019_110000_create_rate_limit_tables::Migration),
+            Box::new(m20261019_120000_add_totp_to_users_table::Migration),
+            Box::new(m20261019_130000_create_user_identities_table::Migration),
+            Box::new(m20261019_140000_create_api_tokens_table::Migration),
+            Box::new(m20261019_150000_create_audit_events_table::Migration),
+            Box::new(m20261019_160000_add_deleted_at_columns::Migration),
+            Box::new(m20261019_170000_create_media_table::Migration),
+            Box::new(m20261019_180000_add_content_format_columns::Migration),
+            Box::new(m20261019_190000_create_tags_tables::Migration),
+            Box::new(m20261019_200000_add_difficulty_columns::Migration),
+            Box::new(m20261019_210000_add_adaptive_testing::Migration),
+            Box::new(m20261019_220000_add_practice_mode::Migration),
+            Box::new(m20261019_230000_create_notes_table::Migration),
+            Box::new(m20261019_240000_create_comments_tables::Migration),
+            Box::new(m20261019_250000_create_change_requests_table::Migration),
+            Box::new(m20261019_260000_create_question_revisions_table::Migration),
+            Box::new(m20261019_270000_create_ownership_transfers_table::Migration),
+            Box::new(m20261019_280000_backfill_test_session_ids::Migration),
+            Box::new(m20261019_290000_add_avatar_media_id_to_users::Migration),
+            Box::new(m20261019_300000_drop_role_changes_table::Migration),
         ]
     }
 }
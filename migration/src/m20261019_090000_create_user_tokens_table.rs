@@ -0,0 +1,121 @@
+use sea_orm_migration::{
+    prelude::{extension::postgres::Type, *},
+    schema::*,
+    sea_orm::{DbBackend, EnumIter, Iterable, Statement},
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column(boolean(Users::IsVerified).default(false))
+                    .to_owned(),
+            )
+            .await?;
+
+        // accounts created before email verification existed are trusted
+        manager
+            .get_connection()
+            .execute(Statement::from_string(
+                DbBackend::Postgres,
+                "UPDATE users SET is_verified = true".to_string(),
+            ))
+            .await?;
+
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(TokenPurposeEnum)
+                    .values(TokenPurpose::iter())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserTokens::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(UserTokens::Id).default(Expr::cust("uuid_generate_v4()")))
+                    .col(uuid_null(UserTokens::UserId))
+                    .col(string(UserTokens::Email))
+                    .col(enumeration(
+                        UserTokens::Purpose,
+                        TokenPurposeEnum,
+                        TokenPurpose::iter(),
+                    ))
+                    .col(json_null(UserTokens::Payload))
+                    .col(timestamp(UserTokens::ExpiresAt))
+                    .col(timestamp_null(UserTokens::UsedAt))
+                    .col(timestamp(UserTokens::CreatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_tokens_user_id")
+                            .from(UserTokens::Table, UserTokens::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(UserTokens::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_type(Type::drop().name(TokenPurposeEnum).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(Users::IsVerified)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+    IsVerified,
+}
+
+#[derive(DeriveIden)]
+pub enum UserTokens {
+    Table,
+    Id,
+    UserId,
+    Email,
+    #[sea_orm(iden = "purpose")]
+    Purpose,
+    Payload,
+    ExpiresAt,
+    UsedAt,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+struct TokenPurposeEnum;
+
+#[derive(Iden, EnumIter)]
+pub enum TokenPurpose {
+    #[iden = "VerifyEmail"]
+    VerifyEmail,
+    #[iden = "ResetPassword"]
+    ResetPassword,
+    #[iden = "InviteToSet"]
+    InviteToSet,
+}
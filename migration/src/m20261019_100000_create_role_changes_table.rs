@@ -0,0 +1,97 @@
+use {
+    crate::m20250223_061404_create_users_table::Users,
+    sea_orm_migration::{
+        prelude::{extension::postgres::Type, *},
+        schema::*,
+        sea_orm::{EnumIter, Iterable},
+    },
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_type(
+                Type::alter()
+                    .name(TokenPurposeEnum)
+                    .add_value(TokenPurpose::InviteStaff),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(RoleChanges::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(RoleChanges::Id).default(Expr::cust("uuid_generate_v4()")))
+                    .col(uuid(RoleChanges::UserId))
+                    .col(uuid_null(RoleChanges::ActorId))
+                    .col(enumeration_null(
+                        RoleChanges::OldRole,
+                        RoleEnum,
+                        Role::iter(),
+                    ))
+                    .col(enumeration(RoleChanges::NewRole, RoleEnum, Role::iter()))
+                    .col(string(RoleChanges::Source))
+                    .col(timestamp(RoleChanges::CreatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_role_changes_user_id")
+                            .from(RoleChanges::Table, RoleChanges::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_role_changes_actor_id")
+                            .from(RoleChanges::Table, RoleChanges::ActorId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // postgres cannot drop a value from an enum type, `InviteStaff` stays
+        manager
+            .drop_table(Table::drop().table(RoleChanges::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum RoleChanges {
+    Table,
+    Id,
+    UserId,
+    ActorId,
+    OldRole,
+    NewRole,
+    Source,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+struct TokenPurposeEnum;
+
+#[derive(DeriveIden)]
+enum TokenPurpose {
+    #[sea_orm(iden = "InviteStaff")]
+    InviteStaff,
+}
+
+#[derive(DeriveIden)]
+pub struct RoleEnum;
+
+#[derive(Iden, EnumIter)]
+pub enum Role {
+    #[iden = "Staff"]
+    Staff,
+    #[iden = "User"]
+    User,
+}
@@ -0,0 +1,61 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RateLimitBuckets::Table)
+                    .if_not_exists()
+                    .col(string(RateLimitBuckets::Key).primary_key())
+                    .col(double(RateLimitBuckets::Tokens))
+                    .col(timestamp(RateLimitBuckets::UpdatedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(LoginFailures::Table)
+                    .if_not_exists()
+                    .col(string(LoginFailures::Key).primary_key())
+                    .col(integer(LoginFailures::Failures).default(0))
+                    .col(timestamp_null(LoginFailures::LockedUntil))
+                    .col(timestamp(LoginFailures::UpdatedAt))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(LoginFailures::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(RateLimitBuckets::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum RateLimitBuckets {
+    Table,
+    Key,
+    Tokens,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum LoginFailures {
+    Table,
+    Key,
+    Failures,
+    LockedUntil,
+    UpdatedAt,
+}
@@ -0,0 +1,78 @@
+use {
+    crate::m20250223_061404_create_users_table::Users,
+    sea_orm_migration::{prelude::*, schema::*},
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column(string_null(Totp::TotpSecret))
+                    .add_column(boolean(Totp::TotpEnabled).default(false))
+                    .add_column(big_integer_null(Totp::TotpLastStep))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(RecoveryCodes::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(RecoveryCodes::Id).default(Expr::cust("uuid_generate_v4()")))
+                    .col(uuid(RecoveryCodes::UserId))
+                    .col(string(RecoveryCodes::CodeHash))
+                    .col(timestamp_null(RecoveryCodes::UsedAt))
+                    .col(timestamp(RecoveryCodes::CreatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_recovery_codes_user_id")
+                            .from(RecoveryCodes::Table, RecoveryCodes::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RecoveryCodes::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(Totp::TotpSecret)
+                    .drop_column(Totp::TotpEnabled)
+                    .drop_column(Totp::TotpLastStep)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Totp {
+    TotpSecret,
+    TotpEnabled,
+    TotpLastStep,
+}
+
+#[derive(DeriveIden)]
+enum RecoveryCodes {
+    Table,
+    Id,
+    UserId,
+    CodeHash,
+    UsedAt,
+    CreatedAt,
+}
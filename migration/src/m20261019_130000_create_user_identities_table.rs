@@ -0,0 +1,88 @@
+use {
+    crate::m20250223_061404_create_users_table::Users,
+    sea_orm_migration::{prelude::*, schema::*},
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserIdentities::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(UserIdentities::Id).default(Expr::cust("uuid_generate_v4()")))
+                    .col(uuid(UserIdentities::UserId))
+                    .col(string(UserIdentities::Issuer))
+                    .col(string(UserIdentities::Subject))
+                    .col(string_null(UserIdentities::Email))
+                    .col(timestamp(UserIdentities::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp_null(UserIdentities::LastLoginAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_identities_user_id")
+                            .from(UserIdentities::Table, UserIdentities::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .name("idx_user_identities_issuer_subject")
+                            .col(UserIdentities::Issuer)
+                            .col(UserIdentities::Subject)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(OidcStates::Table)
+                    .if_not_exists()
+                    .col(string(OidcStates::State).primary_key())
+                    .col(string(OidcStates::Nonce))
+                    .col(string(OidcStates::PkceVerifier))
+                    .col(timestamp(OidcStates::ExpiresAt))
+                    .col(timestamp(OidcStates::CreatedAt).default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(OidcStates::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(UserIdentities::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum UserIdentities {
+    Table,
+    Id,
+    UserId,
+    Issuer,
+    Subject,
+    Email,
+    CreatedAt,
+    LastLoginAt,
+}
+
+#[derive(DeriveIden)]
+enum OidcStates {
+    Table,
+    State,
+    Nonce,
+    PkceVerifier,
+    ExpiresAt,
+    CreatedAt,
+}
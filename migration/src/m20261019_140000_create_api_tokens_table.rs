@@ -0,0 +1,59 @@
+use {
+    crate::m20250223_061404_create_users_table::Users,
+    sea_orm_migration::{prelude::*, schema::*},
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ApiTokens::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(ApiTokens::Id).default(Expr::cust("uuid_generate_v4()")))
+                    .col(uuid(ApiTokens::UserId))
+                    .col(string(ApiTokens::Name))
+                    .col(string_uniq(ApiTokens::TokenHash))
+                    .col(string(ApiTokens::Prefix))
+                    .col(json(ApiTokens::Scopes))
+                    .col(timestamp_null(ApiTokens::LastUsedAt))
+                    .col(timestamp_null(ApiTokens::ExpiresAt))
+                    .col(timestamp_null(ApiTokens::RevokedAt))
+                    .col(timestamp(ApiTokens::CreatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_api_tokens_user_id")
+                            .from(ApiTokens::Table, ApiTokens::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ApiTokens::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ApiTokens {
+    Table,
+    Id,
+    UserId,
+    Name,
+    TokenHash,
+    Prefix,
+    Scopes,
+    LastUsedAt,
+    ExpiresAt,
+    RevokedAt,
+    CreatedAt,
+}
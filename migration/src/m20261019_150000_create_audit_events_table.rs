@@ -0,0 +1,103 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // không có FK tới users để bản ghi không bị đổi khi user bị xoá
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuditEvents::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(AuditEvents::Id).default(Expr::cust("uuid_generate_v4()")))
+                    .col(uuid_null(AuditEvents::ActorId))
+                    .col(string(AuditEvents::Action))
+                    .col(string(AuditEvents::ResourceType))
+                    .col(uuid_null(AuditEvents::ResourceId))
+                    .col(json_null(AuditEvents::Before))
+                    .col(json_null(AuditEvents::After))
+                    .col(json_null(AuditEvents::Changes))
+                    .col(timestamp(AuditEvents::CreatedAt).default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_audit_events_resource")
+                    .table(AuditEvents::Table)
+                    .col(AuditEvents::ResourceType)
+                    .col(AuditEvents::ResourceId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_audit_events_actor_id")
+                    .table(AuditEvents::Table)
+                    .col(AuditEvents::ActorId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_audit_events_created_at")
+                    .table(AuditEvents::Table)
+                    .col(AuditEvents::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        // append-only: chặn UPDATE/DELETE ngay ở database
+        let db = manager.get_connection();
+        db.execute_unprepared(
+            "CREATE OR REPLACE FUNCTION audit_events_append_only() RETURNS trigger AS $$
+            BEGIN
+                RAISE EXCEPTION 'audit_events is append-only';
+            END;
+            $$ LANGUAGE plpgsql",
+        )
+        .await?;
+        db.execute_unprepared(
+            "CREATE TRIGGER audit_events_append_only
+            BEFORE UPDATE OR DELETE ON audit_events
+            FOR EACH ROW EXECUTE FUNCTION audit_events_append_only()",
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AuditEvents::Table).to_owned())
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared("DROP FUNCTION IF EXISTS audit_events_append_only()")
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum AuditEvents {
+    Table,
+    Id,
+    ActorId,
+    Action,
+    ResourceType,
+    ResourceId,
+    Before,
+    After,
+    Changes,
+    CreatedAt,
+}
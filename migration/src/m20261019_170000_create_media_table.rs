@@ -0,0 +1,86 @@
+use {
+    crate::{
+        m20250223_061404_create_users_table::Users,
+        m20250223_065024_create_questions_table::Questions,
+    },
+    sea_orm_migration::{prelude::*, schema::*},
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Media::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(Media::Id).default(Expr::cust("uuid_generate_v4()")))
+                    .col(uuid(Media::OwnerId))
+                    .col(string(Media::Kind))
+                    .col(string(Media::ContentType))
+                    .col(big_integer(Media::Size))
+                    .col(string_uniq(Media::StorageKey))
+                    .col(timestamp(Media::CreatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_media_owner_id")
+                            .from(Media::Table, Media::OwnerId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // media của câu hỏi, media của đáp án nằm trong questions.answers
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Questions::Table)
+                    .add_column(uuid_null(Media::MediaId))
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_questions_media_id")
+                            .from_tbl(Questions::Table)
+                            .from_col(Media::MediaId)
+                            .to_tbl(Media::Table)
+                            .to_col(Media::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Questions::Table)
+                    .drop_foreign_key(Alias::new("fk_questions_media_id"))
+                    .drop_column(Media::MediaId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Media::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Media {
+    Table,
+    Id,
+    OwnerId,
+    Kind,
+    ContentType,
+    Size,
+    StorageKey,
+    CreatedAt,
+    MediaId,
+}
@@ -0,0 +1,90 @@
+use sea_orm_migration::{
+    prelude::{extension::postgres::Type, *},
+    schema::*,
+    sea_orm::{EnumIter, Iterable},
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(ContentFormatEnum)
+                    .values(ContentFormat::iter())
+                    .to_owned(),
+            )
+            .await?;
+
+        // nội dung cũ được hiển thị như văn bản thường
+        for table in [
+            Questions::Table.into_iden(),
+            QuizQuestions::Table.into_iden(),
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .add_column(
+                            enumeration(
+                                Questions::ContentFormat,
+                                ContentFormatEnum,
+                                ContentFormat::iter(),
+                            )
+                            .default("Plain"),
+                        )
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for table in [
+            Questions::Table.into_iden(),
+            QuizQuestions::Table.into_iden(),
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .drop_column(Questions::ContentFormat)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .drop_type(Type::drop().name(ContentFormatEnum).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Questions {
+    Table,
+    ContentFormat,
+}
+
+#[derive(DeriveIden)]
+enum QuizQuestions {
+    Table,
+}
+
+#[derive(DeriveIden)]
+struct ContentFormatEnum;
+
+#[derive(Iden, EnumIter)]
+pub enum ContentFormat {
+    #[iden = "Plain"]
+    Plain,
+    #[iden = "Markdown"]
+    Markdown,
+    #[iden = "Html"]
+    Html,
+}
@@ -0,0 +1,162 @@
+use {
+    crate::{
+        m20250223_061404_create_users_table::Users, m20250223_064318_create_sets_table::Sets,
+        m20250223_065024_create_questions_table::Questions,
+    },
+    sea_orm_migration::{prelude::*, schema::*},
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // tag có parent tạo thành cây chủ đề, tag không có parent là tag gốc
+        manager
+            .create_table(
+                Table::create()
+                    .table(Tags::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(Tags::Id).default(Expr::cust("uuid_generate_v4()")))
+                    .col(string(Tags::Name))
+                    .col(uuid_null(Tags::ParentId))
+                    .col(uuid_null(Tags::CreatorId))
+                    .col(timestamp(Tags::CreatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_tags_parent_id")
+                            .from(Tags::Table, Tags::ParentId)
+                            .to(Tags::Table, Tags::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_tags_creator_id")
+                            .from(Tags::Table, Tags::CreatorId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(QuestionTags::Table)
+                    .if_not_exists()
+                    .col(uuid(QuestionTags::QuestionId))
+                    .col(uuid(QuestionTags::TagId))
+                    .primary_key(
+                        Index::create()
+                            .name("pk_question_tag")
+                            .col(QuestionTags::QuestionId)
+                            .col(QuestionTags::TagId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_question_tags_question_id")
+                            .from(QuestionTags::Table, QuestionTags::QuestionId)
+                            .to(Questions::Table, Questions::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_question_tags_tag_id")
+                            .from(QuestionTags::Table, QuestionTags::TagId)
+                            .to(Tags::Table, Tags::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(SetTags::Table)
+                    .if_not_exists()
+                    .col(uuid(SetTags::SetId))
+                    .col(uuid(SetTags::TagId))
+                    .primary_key(
+                        Index::create()
+                            .name("pk_set_tag")
+                            .col(SetTags::SetId)
+                            .col(SetTags::TagId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_set_tags_set_id")
+                            .from(SetTags::Table, SetTags::SetId)
+                            .to(Sets::Table, Sets::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_set_tags_tag_id")
+                            .from(SetTags::Table, SetTags::TagId)
+                            .to(Tags::Table, Tags::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // lọc theo tag đi từ tag sang câu hỏi/set
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_question_tags_tag_id")
+                    .table(QuestionTags::Table)
+                    .col(QuestionTags::TagId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_set_tags_tag_id")
+                    .table(SetTags::Table)
+                    .col(SetTags::TagId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SetTags::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(QuestionTags::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Tags::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Tags {
+    Table,
+    Id,
+    Name,
+    ParentId,
+    CreatorId,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum QuestionTags {
+    Table,
+    QuestionId,
+    TagId,
+}
+
+#[derive(DeriveIden)]
+enum SetTags {
+    Table,
+    SetId,
+    TagId,
+}
@@ -0,0 +1,61 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // null khi chưa có lượt làm nào được chấm
+        for table in [
+            Questions::Table.into_iden(),
+            QuizQuestions::Table.into_iden(),
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .add_column(double_null(Questions::Difficulty))
+                        .add_column(double_null(Questions::DifficultyConfidence))
+                        .add_column(integer(Questions::DifficultyAttempts).default(0))
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for table in [
+            Questions::Table.into_iden(),
+            QuizQuestions::Table.into_iden(),
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .drop_column(Questions::Difficulty)
+                        .drop_column(Questions::DifficultyConfidence)
+                        .drop_column(Questions::DifficultyAttempts)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Questions {
+    Table,
+    Difficulty,
+    DifficultyConfidence,
+    DifficultyAttempts,
+}
+
+#[derive(DeriveIden)]
+enum QuizQuestions {
+    Table,
+}
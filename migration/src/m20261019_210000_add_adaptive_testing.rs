@@ -0,0 +1,104 @@
+use {
+    crate::{
+        m20250223_071935_create_quiz_questions_table::QuizQuestions,
+        m20250223_075910_create_tests_table::Tests,
+    },
+    sea_orm_migration::{prelude::*, schema::*},
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Tests::Table)
+                    .add_column(boolean(AdaptiveTest::IsAdaptive).default(false))
+                    .add_column(double_null(AdaptiveTest::Ability))
+                    .add_column(double_null(AdaptiveTest::AbilitySe))
+                    .to_owned(),
+            )
+            .await?;
+
+        // đường đi của bài test thích ứng: câu đã hỏi và ước lượng sau mỗi câu
+        manager
+            .create_table(
+                Table::create()
+                    .table(TestAdaptiveSteps::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(TestAdaptiveSteps::Id).default(Expr::cust("uuid_generate_v4()")))
+                    .col(uuid(TestAdaptiveSteps::TestId))
+                    .col(integer(TestAdaptiveSteps::Step))
+                    .col(uuid(TestAdaptiveSteps::QuizQuestionId))
+                    .col(double(TestAdaptiveSteps::Difficulty))
+                    .col(boolean(TestAdaptiveSteps::IsCorrect))
+                    .col(double(TestAdaptiveSteps::Ability))
+                    .col(double(TestAdaptiveSteps::AbilitySe))
+                    .col(timestamp(TestAdaptiveSteps::CreatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_test_adaptive_steps_test_id")
+                            .from(TestAdaptiveSteps::Table, TestAdaptiveSteps::TestId)
+                            .to(Tests::Table, Tests::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_test_adaptive_steps_quiz_question_id")
+                            .from(TestAdaptiveSteps::Table, TestAdaptiveSteps::QuizQuestionId)
+                            .to(QuizQuestions::Table, QuizQuestions::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .index(
+                        Index::create()
+                            .name("uq_test_adaptive_steps_test_id_step")
+                            .col(TestAdaptiveSteps::TestId)
+                            .col(TestAdaptiveSteps::Step)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TestAdaptiveSteps::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Tests::Table)
+                    .drop_column(AdaptiveTest::IsAdaptive)
+                    .drop_column(AdaptiveTest::Ability)
+                    .drop_column(AdaptiveTest::AbilitySe)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum AdaptiveTest {
+    IsAdaptive,
+    Ability,
+    AbilitySe,
+}
+
+#[derive(DeriveIden)]
+enum TestAdaptiveSteps {
+    Table,
+    Id,
+    TestId,
+    Step,
+    QuizQuestionId,
+    Difficulty,
+    IsCorrect,
+    Ability,
+    AbilitySe,
+    CreatedAt,
+}
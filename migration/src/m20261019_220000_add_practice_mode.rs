@@ -0,0 +1,62 @@
+use {
+    crate::m20250223_075910_create_tests_table::Tests,
+    sea_orm_migration::{prelude::*, schema::*},
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Tests::Table)
+                    .add_column(boolean(PracticeTest::IsPractice).default(false))
+                    .to_owned(),
+            )
+            .await?;
+
+        // số lần trả lời một câu, bài luyện tập cho làm lại nhiều lần
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TestQuestionResults::Table)
+                    .add_column(integer(TestQuestionResults::Attempts).default(0))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TestQuestionResults::Table)
+                    .drop_column(TestQuestionResults::Attempts)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Tests::Table)
+                    .drop_column(PracticeTest::IsPractice)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PracticeTest {
+    IsPractice,
+}
+
+#[derive(DeriveIden)]
+enum TestQuestionResults {
+    Table,
+    Attempts,
+}
@@ -0,0 +1,123 @@
+use {
+    crate::{
+        m20250223_061404_create_users_table::Users,
+        m20250223_065024_create_questions_table::Questions,
+        m20250223_071935_create_quiz_questions_table::QuizQuestions,
+    },
+    sea_orm_migration::{prelude::*, schema::*},
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TestQuestionResults::Table)
+                    .add_column(boolean(TestQuestionResults::IsFlagged).default(false))
+                    .to_owned(),
+            )
+            .await?;
+
+        // ghi chú riêng của user, gắn với đúng một câu hỏi hoặc một quiz question
+        manager
+            .create_table(
+                Table::create()
+                    .table(Notes::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(Notes::Id).default(Expr::cust("uuid_generate_v4()")))
+                    .col(uuid(Notes::UserId))
+                    .col(uuid_null(Notes::QuestionId))
+                    .col(uuid_null(Notes::QuizQuestionId))
+                    .col(text(Notes::Content))
+                    .col(timestamp(Notes::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(Notes::UpdatedAt).default(Expr::current_timestamp()))
+                    .check(Expr::cust(
+                        "(question_id IS NULL) <> (quiz_question_id IS NULL)",
+                    ))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_notes_user_id")
+                            .from(Notes::Table, Notes::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_notes_question_id")
+                            .from(Notes::Table, Notes::QuestionId)
+                            .to(Questions::Table, Questions::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_notes_quiz_question_id")
+                            .from(Notes::Table, Notes::QuizQuestionId)
+                            .to(QuizQuestions::Table, QuizQuestions::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // mỗi user một ghi chú cho mỗi câu
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_notes_user_id_question_id")
+                    .table(Notes::Table)
+                    .col(Notes::UserId)
+                    .col(Notes::QuestionId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_notes_user_id_quiz_question_id")
+                    .table(Notes::Table)
+                    .col(Notes::UserId)
+                    .col(Notes::QuizQuestionId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Notes::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TestQuestionResults::Table)
+                    .drop_column(TestQuestionResults::IsFlagged)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TestQuestionResults {
+    Table,
+    IsFlagged,
+}
+
+#[derive(DeriveIden)]
+enum Notes {
+    Table,
+    Id,
+    UserId,
+    QuestionId,
+    QuizQuestionId,
+    Content,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,156 @@
+use {
+    crate::{
+        m20250223_061404_create_users_table::Users, m20250223_064318_create_sets_table::Sets,
+        m20250223_065024_create_questions_table::Questions,
+    },
+    sea_orm_migration::{prelude::*, schema::*},
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // bình luận trên set (question_id null) hoặc trên một câu hỏi của set,
+        // trả lời trỏ `parent_id` về bình luận gốc của thread
+        manager
+            .create_table(
+                Table::create()
+                    .table(Comments::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(Comments::Id).default(Expr::cust("uuid_generate_v4()")))
+                    .col(uuid(Comments::SetId))
+                    .col(uuid_null(Comments::QuestionId))
+                    .col(uuid_null(Comments::ParentId))
+                    .col(uuid(Comments::AuthorId))
+                    .col(text(Comments::Content))
+                    .col(boolean(Comments::IsSuggestion).default(false))
+                    .col(timestamp_null(Comments::ResolvedAt))
+                    .col(uuid_null(Comments::ResolvedBy))
+                    .col(timestamp(Comments::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(Comments::UpdatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comments_set_id")
+                            .from(Comments::Table, Comments::SetId)
+                            .to(Sets::Table, Sets::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comments_question_id")
+                            .from(Comments::Table, Comments::QuestionId)
+                            .to(Questions::Table, Questions::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comments_parent_id")
+                            .from(Comments::Table, Comments::ParentId)
+                            .to(Comments::Table, Comments::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comments_author_id")
+                            .from(Comments::Table, Comments::AuthorId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comments_resolved_by")
+                            .from(Comments::Table, Comments::ResolvedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(CommentMentions::Table)
+                    .if_not_exists()
+                    .col(uuid(CommentMentions::CommentId))
+                    .col(uuid(CommentMentions::UserId))
+                    .primary_key(
+                        Index::create()
+                            .name("pk_comment_mention")
+                            .col(CommentMentions::CommentId)
+                            .col(CommentMentions::UserId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comment_mentions_comment_id")
+                            .from(CommentMentions::Table, CommentMentions::CommentId)
+                            .to(Comments::Table, Comments::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comment_mentions_user_id")
+                            .from(CommentMentions::Table, CommentMentions::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // danh sách thread theo set/câu hỏi và danh sách được nhắc tới của user
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_comments_set_id_question_id")
+                    .table(Comments::Table)
+                    .col(Comments::SetId)
+                    .col(Comments::QuestionId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_comment_mentions_user_id")
+                    .table(CommentMentions::Table)
+                    .col(CommentMentions::UserId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CommentMentions::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Comments::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Comments {
+    Table,
+    Id,
+    SetId,
+    QuestionId,
+    ParentId,
+    AuthorId,
+    Content,
+    IsSuggestion,
+    ResolvedAt,
+    ResolvedBy,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum CommentMentions {
+    Table,
+    CommentId,
+    UserId,
+}
@@ -0,0 +1,169 @@
+use {
+    crate::{
+        m20250223_061404_create_users_table::Users, m20250223_064318_create_sets_table::Sets,
+        m20250223_065024_create_questions_table::Questions,
+        m20261019_180000_add_content_format_columns::ContentFormat,
+    },
+    sea_orm_migration::{
+        prelude::{extension::postgres::Type, *},
+        schema::*,
+        sea_orm::{EnumIter, Iterable},
+    },
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(ChangeRequestStatusEnum)
+                    .values(ChangeRequestStatus::iter())
+                    .to_owned(),
+            )
+            .await?;
+
+        // đề xuất sửa một câu hỏi, cột null là giữ nguyên, `base_*` là câu hỏi lúc
+        // đề xuất để xem diff và giữ lịch sử sau khi đã xử lý
+        manager
+            .create_table(
+                Table::create()
+                    .table(ChangeRequests::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(ChangeRequests::Id).default(Expr::cust("uuid_generate_v4()")))
+                    .col(uuid(ChangeRequests::QuestionId))
+                    .col(uuid(ChangeRequests::SetId))
+                    .col(uuid(ChangeRequests::ProposerId))
+                    .col(text_null(ChangeRequests::Message))
+                    .col(text_null(ChangeRequests::Content))
+                    .col(enumeration_null(
+                        ChangeRequests::ContentFormat,
+                        ContentFormatEnum,
+                        ContentFormat::iter(),
+                    ))
+                    .col(json_null(ChangeRequests::Answers))
+                    .col(text(ChangeRequests::BaseContent))
+                    .col(enumeration(
+                        ChangeRequests::BaseContentFormat,
+                        ContentFormatEnum,
+                        ContentFormat::iter(),
+                    ))
+                    .col(json(ChangeRequests::BaseAnswers))
+                    .col(
+                        enumeration(
+                            ChangeRequests::Status,
+                            ChangeRequestStatusEnum,
+                            ChangeRequestStatus::iter(),
+                        )
+                        .default("Pending"),
+                    )
+                    .col(uuid_null(ChangeRequests::ReviewerId))
+                    .col(text_null(ChangeRequests::ReviewNote))
+                    .col(timestamp_null(ChangeRequests::ReviewedAt))
+                    .col(timestamp(ChangeRequests::CreatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_change_requests_question_id")
+                            .from(ChangeRequests::Table, ChangeRequests::QuestionId)
+                            .to(Questions::Table, Questions::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_change_requests_set_id")
+                            .from(ChangeRequests::Table, ChangeRequests::SetId)
+                            .to(Sets::Table, Sets::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_change_requests_proposer_id")
+                            .from(ChangeRequests::Table, ChangeRequests::ProposerId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_change_requests_reviewer_id")
+                            .from(ChangeRequests::Table, ChangeRequests::ReviewerId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // hộp đề xuất của set theo trạng thái
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_change_requests_set_id_status")
+                    .table(ChangeRequests::Table)
+                    .col(ChangeRequests::SetId)
+                    .col(ChangeRequests::Status)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_change_requests_question_id")
+                    .table(ChangeRequests::Table)
+                    .col(ChangeRequests::QuestionId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ChangeRequests::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_type(Type::drop().name(ChangeRequestStatusEnum).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ChangeRequests {
+    Table,
+    Id,
+    QuestionId,
+    SetId,
+    ProposerId,
+    Message,
+    Content,
+    ContentFormat,
+    Answers,
+    BaseContent,
+    BaseContentFormat,
+    BaseAnswers,
+    Status,
+    ReviewerId,
+    ReviewNote,
+    ReviewedAt,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+struct ContentFormatEnum;
+
+#[derive(DeriveIden)]
+struct ChangeRequestStatusEnum;
+
+#[derive(Iden, EnumIter)]
+pub enum ChangeRequestStatus {
+    #[iden = "Pending"]
+    Pending,
+    #[iden = "Accepted"]
+    Accepted,
+    #[iden = "Rejected"]
+    Rejected,
+    #[iden = "Withdrawn"]
+    Withdrawn,
+}
@@ -0,0 +1,117 @@
+use {
+    crate::{
+        m20250223_061404_create_users_table::Users,
+        m20250223_065024_create_questions_table::Questions,
+        m20261019_180000_add_content_format_columns::ContentFormat,
+    },
+    sea_orm_migration::{prelude::*, schema::*, sea_orm::Iterable},
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // bản chụp đầy đủ của câu hỏi sau mỗi lần tạo/sửa, `revision` tăng dần theo
+        // từng câu hỏi
+        manager
+            .create_table(
+                Table::create()
+                    .table(QuestionRevisions::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(QuestionRevisions::Id).default(Expr::cust("uuid_generate_v4()")))
+                    .col(uuid(QuestionRevisions::QuestionId))
+                    .col(integer(QuestionRevisions::Revision))
+                    .col(uuid_null(QuestionRevisions::AuthorId))
+                    .col(text(QuestionRevisions::Content))
+                    .col(enumeration(
+                        QuestionRevisions::ContentFormat,
+                        ContentFormatEnum,
+                        ContentFormat::iter(),
+                    ))
+                    .col(json(QuestionRevisions::Answers))
+                    .col(uuid_null(QuestionRevisions::MediaId))
+                    .col(integer_null(QuestionRevisions::RestoredFrom))
+                    .col(timestamp(QuestionRevisions::CreatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_question_revisions_question_id")
+                            .from(QuestionRevisions::Table, QuestionRevisions::QuestionId)
+                            .to(Questions::Table, Questions::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_question_revisions_author_id")
+                            .from(QuestionRevisions::Table, QuestionRevisions::AuthorId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_question_revisions_media_id")
+                            .from(QuestionRevisions::Table, QuestionRevisions::MediaId)
+                            .to(Media::Table, Media::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_question_revisions_question_id_revision")
+                    .table(QuestionRevisions::Table)
+                    .col(QuestionRevisions::QuestionId)
+                    .col(QuestionRevisions::Revision)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // câu hỏi đã có: trạng thái hiện tại là revision 1
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "INSERT INTO question_revisions \
+                 (question_id, revision, author_id, content, content_format, answers, media_id, created_at) \
+                 SELECT id, 1, COALESCE(latest_updater_id, creator_id), content, content_format, \
+                 answers, media_id, updated_at FROM questions",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(QuestionRevisions::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum QuestionRevisions {
+    Table,
+    Id,
+    QuestionId,
+    Revision,
+    AuthorId,
+    Content,
+    ContentFormat,
+    Answers,
+    MediaId,
+    RestoredFrom,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Media {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+struct ContentFormatEnum;
@@ -0,0 +1,84 @@
+use {
+    crate::m20250223_061404_create_users_table::Users,
+    sea_orm_migration::{prelude::*, schema::*},
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // lịch sử chuyển chủ sở hữu của set/quiz, `resource_type` là "set" hoặc
+        // "quiz"
+        manager
+            .create_table(
+                Table::create()
+                    .table(OwnershipTransfers::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(OwnershipTransfers::Id).default(Expr::cust("uuid_generate_v4()")))
+                    .col(string(OwnershipTransfers::ResourceType))
+                    .col(uuid(OwnershipTransfers::ResourceId))
+                    .col(uuid(OwnershipTransfers::FromUserId))
+                    .col(uuid(OwnershipTransfers::ToUserId))
+                    .col(uuid_null(OwnershipTransfers::ActorId))
+                    .col(boolean(OwnershipTransfers::KeepPreviousOwner).default(false))
+                    .col(
+                        timestamp(OwnershipTransfers::CreatedAt).default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_ownership_transfers_from_user_id")
+                            .from(OwnershipTransfers::Table, OwnershipTransfers::FromUserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_ownership_transfers_to_user_id")
+                            .from(OwnershipTransfers::Table, OwnershipTransfers::ToUserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_ownership_transfers_actor_id")
+                            .from(OwnershipTransfers::Table, OwnershipTransfers::ActorId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_ownership_transfers_resource")
+                    .table(OwnershipTransfers::Table)
+                    .col(OwnershipTransfers::ResourceType)
+                    .col(OwnershipTransfers::ResourceId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(OwnershipTransfers::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum OwnershipTransfers {
+    Table,
+    Id,
+    ResourceType,
+    ResourceId,
+    FromUserId,
+    ToUserId,
+    ActorId,
+    KeepPreviousOwner,
+    CreatedAt,
+}
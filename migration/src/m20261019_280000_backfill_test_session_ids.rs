@@ -0,0 +1,31 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// Test đang làm dở từ trước khi có session_id thì không ghi được câu trả lời nào
+// cho tới khi take-over, cấp session cho chúng
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(Tests::Table)
+                    .value(Tests::SessionId, Expr::cust("uuid_generate_v4()"))
+                    .and_where(Expr::col(Tests::SessionId).is_null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Tests {
+    Table,
+    SessionId,
+}
@@ -0,0 +1,64 @@
+use {
+    crate::m20250223_061404_create_users_table::Users,
+    sea_orm_migration::{prelude::*, schema::*},
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column(uuid_null(Avatar::AvatarMediaId))
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_users_avatar_media_id")
+                            .from_tbl(Users::Table)
+                            .from_col(Avatar::AvatarMediaId)
+                            .to_tbl(Avatar::Media)
+                            .to_col(Avatar::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // avatar đã upload đang lưu link ký không hết hạn, đổi sang id của media
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "UPDATE users SET
+                    avatar_media_id = media.id,
+                    avatar_url = NULL
+                FROM media
+                WHERE users.avatar_url LIKE '/api/media/%'
+                    AND media.id::text = substring(users.avatar_url from '^/api/media/([0-9a-f-]{36})/')",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_foreign_key(Alias::new("fk_users_avatar_media_id"))
+                    .drop_column(Avatar::AvatarMediaId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Avatar {
+    Media,
+    Id,
+    AvatarMediaId,
+}
@@ -0,0 +1,61 @@
+use {
+    crate::{
+        m20250223_061404_create_users_table::Users,
+        m20261019_100000_create_role_changes_table::{Role, RoleChanges, RoleEnum},
+    },
+    sea_orm_migration::{prelude::*, schema::*, sea_orm::Iterable},
+};
+
+// Lịch sử đổi role đã nằm trong audit_events
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(RoleChanges::Table)
+                    .if_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RoleChanges::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(RoleChanges::Id).default(Expr::cust("uuid_generate_v4()")))
+                    .col(uuid(RoleChanges::UserId))
+                    .col(uuid_null(RoleChanges::ActorId))
+                    .col(enumeration_null(
+                        RoleChanges::OldRole,
+                        RoleEnum,
+                        Role::iter(),
+                    ))
+                    .col(enumeration(RoleChanges::NewRole, RoleEnum, Role::iter()))
+                    .col(string(RoleChanges::Source))
+                    .col(timestamp(RoleChanges::CreatedAt).default(Expr::current_timestamp()))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_role_changes_user_id")
+                            .from(RoleChanges::Table, RoleChanges::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_role_changes_actor_id")
+                            .from(RoleChanges::Table, RoleChanges::ActorId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+}
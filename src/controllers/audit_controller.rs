@@ -0,0 +1,34 @@
+use {
+    crate::{
+        debug,
+        enums::{error::*, generic::into_ok_response},
+        models::audit::QueryAuditParams,
+        server::AppState,
+        utils::jwt::Claims,
+    },
+    axum::{
+        extract::{Query, State},
+        response::IntoResponse,
+        Extension,
+    },
+    flashcard::only_role,
+    std::sync::Arc,
+};
+
+pub struct AuditController;
+
+impl AuditController {
+    #[only_role("Staff")]
+    pub async fn get_all(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Query(params): Query<QueryAuditParams>,
+    ) -> Result<impl IntoResponse> {
+        debug!("get audit events request: {caller:?} {params:?}");
+
+        let service = Arc::clone(&state.audit_service);
+        let res = service.get_all(params).await?;
+
+        Ok(into_ok_response("success".into(), Some(res)))
+    }
+}
@@ -2,10 +2,17 @@ use {
     crate::{
         debug,
         enums::{error::*, generic::into_ok_response},
-        models::user::{LoginRequest, RegisterUserRequest},
+        models::user::{
+            EmailRequest, LoginRequest, LoginTotpRequest, OidcAuthorizeResponse,
+            OidcCallbackRequest, RegisterUserRequest, ResetPasswordRequest, TotpChallengeRequest,
+            VerifyEmailRequest,
+        },
         r#static::BLACKLIST_TOKEN_VEC,
         server::AppState,
-        utils::jwt::Claims,
+        utils::{
+            jwt::{self, Claims},
+            validator::ValidatedJson,
+        },
     },
     axum::{extract::State, response::IntoResponse, Extension, Json},
     flashcard::only_role,
@@ -17,7 +24,7 @@ pub struct AuthController;
 impl AuthController {
     pub async fn register_user(
         State(state): State<AppState>,
-        Json(payload): Json<RegisterUserRequest>,
+        ValidatedJson(payload): ValidatedJson<RegisterUserRequest>,
     ) -> Result<impl IntoResponse> {
         debug!("register_user: {payload:?}");
 
@@ -32,7 +39,7 @@ impl AuthController {
 
     pub async fn login(
         State(state): State<AppState>,
-        Json(payload): Json<LoginRequest>,
+        ValidatedJson(payload): ValidatedJson<LoginRequest>,
     ) -> Result<impl IntoResponse> {
         debug!("login request: {payload:?}");
 
@@ -42,6 +49,122 @@ impl AuthController {
         Ok(into_ok_response("login successfully".into(), Some(res)))
     }
 
+    pub async fn login_totp(
+        State(state): State<AppState>,
+        ValidatedJson(payload): ValidatedJson<LoginTotpRequest>,
+    ) -> Result<impl IntoResponse> {
+        let LoginTotpRequest {
+            challenge_token,
+            code,
+        } = payload;
+
+        let service = Arc::clone(&state.user_service);
+        let res = service.login_totp(challenge_token, code).await?;
+
+        Ok(into_ok_response("login successfully".into(), Some(res)))
+    }
+
+    pub async fn login_totp_setup(
+        State(state): State<AppState>,
+        ValidatedJson(payload): ValidatedJson<TotpChallengeRequest>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.user_service);
+        let res = service.login_totp_setup(payload.challenge_token).await?;
+
+        Ok(into_ok_response(
+            "Scan the provisioning uri then log in with a code".into(),
+            Some(res),
+        ))
+    }
+
+    pub async fn oidc_authorize(State(state): State<AppState>) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.oidc_service);
+        let authorization_url = service.authorize().await?;
+
+        Ok(into_ok_response(
+            "success".into(),
+            Some(OidcAuthorizeResponse { authorization_url }),
+        ))
+    }
+
+    pub async fn oidc_callback(
+        State(state): State<AppState>,
+        ValidatedJson(payload): ValidatedJson<OidcCallbackRequest>,
+    ) -> Result<impl IntoResponse> {
+        let OidcCallbackRequest {
+            code,
+            state: oidc_state,
+        } = payload;
+
+        let service = Arc::clone(&state.oidc_service);
+        let res = service.callback(code, oidc_state).await?;
+
+        Ok(into_ok_response("login successfully".into(), Some(res)))
+    }
+
+    pub async fn verify_email(
+        State(state): State<AppState>,
+        ValidatedJson(payload): ValidatedJson<VerifyEmailRequest>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.user_service);
+        let res = service.verify_email(payload.token).await?;
+
+        Ok(into_ok_response("Verified successfully".into(), Some(res)))
+    }
+
+    pub async fn resend_verification(
+        State(state): State<AppState>,
+        ValidatedJson(payload): ValidatedJson<EmailRequest>,
+    ) -> Result<impl IntoResponse> {
+        debug!("resend_verification: {payload:?}");
+
+        let service = Arc::clone(&state.user_service);
+        service.resend_verification(payload.email).await?;
+
+        Ok(into_ok_response(
+            "Verification email sent if the account exists".into(),
+            None::<String>,
+        ))
+    }
+
+    pub async fn forgot_password(
+        State(state): State<AppState>,
+        ValidatedJson(payload): ValidatedJson<EmailRequest>,
+    ) -> Result<impl IntoResponse> {
+        debug!("forgot_password: {payload:?}");
+
+        let service = Arc::clone(&state.user_service);
+        service.forgot_password(payload.email).await?;
+
+        Ok(into_ok_response(
+            "Reset password email sent if the account exists".into(),
+            None::<String>,
+        ))
+    }
+
+    pub async fn reset_password(
+        State(state): State<AppState>,
+        ValidatedJson(payload): ValidatedJson<ResetPasswordRequest>,
+    ) -> Result<impl IntoResponse> {
+        let ResetPasswordRequest {
+            token,
+            new_password,
+        } = payload;
+
+        let service = Arc::clone(&state.user_service);
+        service.reset_password(token, new_password).await?;
+
+        Ok(into_ok_response(
+            "Reset password successfully".into(),
+            None::<String>,
+        ))
+    }
+
+    // JWKS để các service khác verify access token
+    pub async fn jwks() -> Result<impl IntoResponse> {
+        Ok(Json(jwt::jwks()?))
+    }
+
     #[only_role("Staff", "User")]
     pub async fn logout(
         Extension(caller): Extension<Claims>,
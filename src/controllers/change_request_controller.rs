@@ -0,0 +1,103 @@
+use {
+    crate::{
+        enums::{error::*, generic::into_ok_response},
+        models::change_request::{
+            CreateChangeRequest, QueryChangeRequestParams, ReviewChangeRequest,
+        },
+        server::AppState,
+        utils::{jwt::Claims, validator::ValidatedJson},
+    },
+    axum::{
+        extract::{Path, Query, State},
+        response::IntoResponse,
+        Extension,
+    },
+    std::sync::Arc,
+    uuid::Uuid,
+};
+
+pub struct ChangeRequestController;
+
+impl ChangeRequestController {
+    pub async fn create(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+        ValidatedJson(payload): ValidatedJson<CreateChangeRequest>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.change_request_service);
+        let res = service.create(caller.id, id, payload).await?;
+
+        Ok(into_ok_response("Created successfully".into(), Some(res)))
+    }
+
+    pub async fn get_all_of_question(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+        Query(params): Query<QueryChangeRequestParams>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.change_request_service);
+        let res = service.get_all_of_question(caller.id, id, params).await?;
+
+        Ok(into_ok_response("success".into(), Some(res)))
+    }
+
+    pub async fn get_all_of_set(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+        Query(params): Query<QueryChangeRequestParams>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.change_request_service);
+        let res = service.get_all_of_set(caller.id, id, params).await?;
+
+        Ok(into_ok_response("success".into(), Some(res)))
+    }
+
+    pub async fn get_by_id(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.change_request_service);
+        let res = service.get_by_id(caller.id, id).await?;
+
+        Ok(into_ok_response("success".into(), Some(res)))
+    }
+
+    pub async fn accept(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+        ValidatedJson(payload): ValidatedJson<ReviewChangeRequest>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.change_request_service);
+        let res = service.accept(caller.id, id, payload.review_note).await?;
+
+        Ok(into_ok_response("Accepted successfully".into(), Some(res)))
+    }
+
+    pub async fn reject(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+        ValidatedJson(payload): ValidatedJson<ReviewChangeRequest>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.change_request_service);
+        let res = service.reject(caller.id, id, payload.review_note).await?;
+
+        Ok(into_ok_response("Rejected successfully".into(), Some(res)))
+    }
+
+    pub async fn withdraw(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.change_request_service);
+        let res = service.withdraw(caller.id, id).await?;
+
+        Ok(into_ok_response("Withdrawn successfully".into(), Some(res)))
+    }
+}
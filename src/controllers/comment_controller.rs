@@ -0,0 +1,127 @@
+use {
+    crate::{
+        enums::{error::*, generic::into_ok_response},
+        models::comment::{
+            CommentTarget, CreateCommentRequest, QueryCommentParams, ResolveCommentRequest,
+            UpdateCommentRequest,
+        },
+        server::AppState,
+        utils::{jwt::Claims, validator::ValidatedJson},
+    },
+    axum::{
+        extract::{Path, Query, State},
+        response::IntoResponse,
+        Extension,
+    },
+    std::sync::Arc,
+    uuid::Uuid,
+};
+
+pub struct CommentController;
+
+impl CommentController {
+    pub async fn get_set_comments(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+        Query(params): Query<QueryCommentParams>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.comment_service);
+        let res = service
+            .get_threads(caller.id, CommentTarget::Set(id), params)
+            .await?;
+
+        Ok(into_ok_response("success".into(), Some(res)))
+    }
+
+    pub async fn create_set_comment(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+        ValidatedJson(payload): ValidatedJson<CreateCommentRequest>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.comment_service);
+        let res = service
+            .create(caller.id, CommentTarget::Set(id), payload)
+            .await?;
+
+        Ok(into_ok_response("Created successfully".into(), Some(res)))
+    }
+
+    pub async fn get_question_comments(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+        Query(params): Query<QueryCommentParams>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.comment_service);
+        let res = service
+            .get_threads(caller.id, CommentTarget::Question(id), params)
+            .await?;
+
+        Ok(into_ok_response("success".into(), Some(res)))
+    }
+
+    pub async fn create_question_comment(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+        ValidatedJson(payload): ValidatedJson<CreateCommentRequest>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.comment_service);
+        let res = service
+            .create(caller.id, CommentTarget::Question(id), payload)
+            .await?;
+
+        Ok(into_ok_response("Created successfully".into(), Some(res)))
+    }
+
+    pub async fn update(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+        ValidatedJson(payload): ValidatedJson<UpdateCommentRequest>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.comment_service);
+        let res = service.update(caller.id, id, payload).await?;
+
+        Ok(into_ok_response("Updated successfully".into(), Some(res)))
+    }
+
+    pub async fn delete(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.comment_service);
+        service.delete(caller.id, id).await?;
+
+        Ok(into_ok_response(
+            "Deleted successfully".into(),
+            None::<String>,
+        ))
+    }
+
+    pub async fn resolve(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+        ValidatedJson(payload): ValidatedJson<ResolveCommentRequest>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.comment_service);
+        let res = service.resolve(caller.id, id, payload.is_resolved).await?;
+
+        Ok(into_ok_response("Updated successfully".into(), Some(res)))
+    }
+
+    pub async fn get_mentions(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Query(params): Query<QueryCommentParams>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.comment_service);
+        let res = service.get_mentions(caller.id, params).await?;
+
+        Ok(into_ok_response("success".into(), Some(res)))
+    }
+}
@@ -0,0 +1,35 @@
+use {
+    crate::{
+        debug,
+        enums::{error::*, generic::into_ok_response},
+        models::content::{RenderContentRequest, RenderedContent},
+        utils::{
+            jwt::Claims,
+            rich_text::{render_html, sanitize},
+            validator::ValidatedJson,
+        },
+    },
+    axum::{response::IntoResponse, Extension},
+    flashcard::only_role,
+};
+
+pub struct ContentController;
+
+impl ContentController {
+    // Cho client không có markdown engine, cũng dùng để xem trước khi lưu
+    #[only_role("Staff", "User")]
+    pub async fn render(
+        Extension(caller): Extension<Claims>,
+        ValidatedJson(payload): ValidatedJson<RenderContentRequest>,
+    ) -> Result<impl IntoResponse> {
+        debug!("render content request: {caller:?} {:?}", payload.format);
+
+        // cùng kiểm tra như khi lưu để lỗi công thức hiện ngay lúc xem trước
+        let content = sanitize(payload.format, "content", &payload.content)?;
+        let res = RenderedContent {
+            html: render_html(payload.format, &content),
+        };
+
+        Ok(into_ok_response("success".into(), Some(res)))
+    }
+}
@@ -0,0 +1,90 @@
+use {
+    crate::{
+        debug,
+        enums::{error::*, generic::into_ok_response},
+        models::media::SignedUrlParams,
+        server::AppState,
+        utils::jwt::Claims,
+    },
+    axum::{
+        body::Bytes,
+        extract::{Path, Query, State},
+        http::header,
+        response::IntoResponse,
+        Extension,
+    },
+    flashcard::only_role,
+    std::sync::Arc,
+    uuid::Uuid,
+};
+
+pub struct MediaController;
+
+impl MediaController {
+    // body là nội dung file, không cần multipart
+    #[only_role("Staff", "User")]
+    pub async fn upload(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        body: Bytes,
+    ) -> Result<impl IntoResponse> {
+        debug!("upload media request: {caller:?} {} bytes", body.len());
+
+        let service = Arc::clone(&state.media_service);
+        let res = service.upload(caller.id, body.to_vec()).await?;
+
+        Ok(into_ok_response("Uploaded successfully".into(), Some(res)))
+    }
+
+    #[only_role("Staff", "User")]
+    pub async fn get(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+    ) -> Result<impl IntoResponse> {
+        debug!("get media request: {caller:?} {id}");
+
+        let service = Arc::clone(&state.media_service);
+        let res = service.get(caller.id, id).await?;
+
+        Ok(into_ok_response("success".into(), Some(res)))
+    }
+
+    #[only_role("Staff", "User")]
+    pub async fn delete(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+    ) -> Result<impl IntoResponse> {
+        debug!("delete media request: {caller:?} {id}");
+
+        let service = Arc::clone(&state.media_service);
+        service.delete(caller.id, id).await?;
+
+        Ok(into_ok_response(
+            "Deleted successfully".into(),
+            None::<String>,
+        ))
+    }
+
+    // Link đã ký thay cho JWT, dùng được trực tiếp trong <img>/<audio>
+    pub async fn download(
+        State(state): State<AppState>,
+        Path(id): Path<Uuid>,
+        Query(params): Query<SignedUrlParams>,
+    ) -> Result<impl IntoResponse> {
+        debug!("download media request: {id}");
+
+        let service = Arc::clone(&state.media_service);
+        let content = service.download(id, params).await?;
+
+        Ok((
+            [
+                (header::CONTENT_TYPE, content.content_type),
+                (header::X_CONTENT_TYPE_OPTIONS, "nosniff".into()),
+                (header::CACHE_CONTROL, "private, max-age=3600".into()),
+            ],
+            content.data,
+        ))
+    }
+}
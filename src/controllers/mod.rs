@@ -1,16 +1,22 @@
-use axum::{
-    http::{StatusCode, Uri},
-    response::IntoResponse,
-};
+use {crate::enums::error::Error, axum::http::Uri};
 
+pub mod audit_controller;
 pub mod auth_controller;
+pub mod change_request_controller;
+pub mod comment_controller;
+pub mod content_controller;
+pub mod media_controller;
+pub mod note_controller;
+pub mod ownership_controller;
 pub mod qna_controller;
 pub mod quiz_controller;
 pub mod quiz_question_controller;
 pub mod set_controller;
+pub mod tag_controller;
 pub mod test_controller;
+pub mod trash_controller;
 pub mod user_controller;
 
-pub async fn fallback(uri: Uri) -> impl IntoResponse {
-    (StatusCode::NOT_FOUND, format!("No route: {uri}"))
+pub async fn fallback(uri: Uri) -> Error {
+    Error::RouteNotFound(uri.to_string())
 }
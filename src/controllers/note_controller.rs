@@ -0,0 +1,112 @@
+use {
+    crate::{
+        enums::{error::*, generic::into_ok_response},
+        models::note::{NoteTarget, QueryNoteParams, SaveNoteRequest},
+        server::AppState,
+        utils::{jwt::Claims, validator::ValidatedJson},
+    },
+    axum::{
+        extract::{Path, Query, State},
+        response::IntoResponse,
+        Extension,
+    },
+    std::sync::Arc,
+    uuid::Uuid,
+};
+
+pub struct NoteController;
+
+impl NoteController {
+    pub async fn get_all(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Query(params): Query<QueryNoteParams>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.note_service);
+        let res = service.get_all(caller.id, params).await?;
+
+        Ok(into_ok_response("success".into(), Some(res)))
+    }
+
+    pub async fn get_question_note(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.note_service);
+        let res = service.get_one(caller.id, NoteTarget::Question(id)).await?;
+
+        Ok(into_ok_response("success".into(), Some(res)))
+    }
+
+    pub async fn save_question_note(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+        ValidatedJson(payload): ValidatedJson<SaveNoteRequest>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.note_service);
+        let res = service
+            .save_question_note(caller.id, id, payload.content)
+            .await?;
+
+        Ok(into_ok_response("Saved successfully".into(), Some(res)))
+    }
+
+    pub async fn delete_question_note(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.note_service);
+        service.delete(caller.id, NoteTarget::Question(id)).await?;
+
+        Ok(into_ok_response(
+            "Deleted successfully".into(),
+            None::<String>,
+        ))
+    }
+
+    pub async fn get_quiz_question_note(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path((_quiz_id, quiz_question_id)): Path<(Uuid, Uuid)>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.note_service);
+        let res = service
+            .get_one(caller.id, NoteTarget::QuizQuestion(quiz_question_id))
+            .await?;
+
+        Ok(into_ok_response("success".into(), Some(res)))
+    }
+
+    pub async fn save_quiz_question_note(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path((quiz_id, quiz_question_id)): Path<(Uuid, Uuid)>,
+        ValidatedJson(payload): ValidatedJson<SaveNoteRequest>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.note_service);
+        let res = service
+            .save_quiz_question_note(caller.id, quiz_id, quiz_question_id, payload.content)
+            .await?;
+
+        Ok(into_ok_response("Saved successfully".into(), Some(res)))
+    }
+
+    pub async fn delete_quiz_question_note(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path((_quiz_id, quiz_question_id)): Path<(Uuid, Uuid)>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.note_service);
+        service
+            .delete(caller.id, NoteTarget::QuizQuestion(quiz_question_id))
+            .await?;
+
+        Ok(into_ok_response(
+            "Deleted successfully".into(),
+            None::<String>,
+        ))
+    }
+}
@@ -0,0 +1,95 @@
+use {
+    crate::{
+        enums::{error::*, generic::into_ok_response},
+        models::{
+            audit::AuditResource,
+            ownership::{QueryTransferParams, TransferOwnershipRequest},
+        },
+        server::AppState,
+        utils::{jwt::Claims, validator::ValidatedJson},
+    },
+    axum::{
+        extract::{Path, Query, State},
+        response::IntoResponse,
+        Extension,
+    },
+    std::sync::Arc,
+    uuid::Uuid,
+};
+
+pub struct OwnershipController;
+
+impl OwnershipController {
+    pub async fn transfer_set(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+        ValidatedJson(payload): ValidatedJson<TransferOwnershipRequest>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.ownership_service);
+        let res = service.transfer_set(caller.id, id, payload).await?;
+
+        Ok(into_ok_response(
+            "Transferred successfully".into(),
+            Some(res),
+        ))
+    }
+
+    pub async fn transfer_quiz(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+        ValidatedJson(payload): ValidatedJson<TransferOwnershipRequest>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.ownership_service);
+        let res = service.transfer_quiz(caller.id, id, payload).await?;
+
+        Ok(into_ok_response(
+            "Transferred successfully".into(),
+            Some(res),
+        ))
+    }
+
+    pub async fn transfer_all(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+        ValidatedJson(payload): ValidatedJson<TransferOwnershipRequest>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.ownership_service);
+        let res = service.transfer_all(caller.id, id, payload).await?;
+
+        Ok(into_ok_response(
+            "Transferred successfully".into(),
+            Some(res),
+        ))
+    }
+
+    pub async fn get_set_history(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+        Query(params): Query<QueryTransferParams>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.ownership_service);
+        let res = service
+            .get_history(caller.id, AuditResource::Set, id, params)
+            .await?;
+
+        Ok(into_ok_response("success".into(), Some(res)))
+    }
+
+    pub async fn get_quiz_history(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+        Query(params): Query<QueryTransferParams>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.ownership_service);
+        let res = service
+            .get_history(caller.id, AuditResource::Quiz, id, params)
+            .await?;
+
+        Ok(into_ok_response("success".into(), Some(res)))
+    }
+}
@@ -2,14 +2,14 @@ use {
     crate::{
         debug,
         enums::{error::*, generic::into_ok_response},
-        models::qna::*,
+        models::{qna::*, tag::SetTagsRequest},
         server::AppState,
-        utils::jwt::Claims,
+        utils::{jwt::Claims, validator::ValidatedJson},
     },
     axum::{
         extract::{Path, Query, State},
         response::IntoResponse,
-        Extension, Json,
+        Extension,
     },
     std::sync::Arc,
     uuid::Uuid,
@@ -21,7 +21,7 @@ impl QnAController {
     pub async fn create(
         State(state): State<AppState>,
         Extension(caller): Extension<Claims>,
-        Json(payload): Json<CreateQnARequest>,
+        ValidatedJson(payload): ValidatedJson<CreateQnARequest>,
     ) -> Result<impl IntoResponse> {
         debug!("create_question: caller: {caller:?}, payload: {payload:?}");
 
@@ -35,7 +35,7 @@ impl QnAController {
         State(state): State<AppState>,
         Extension(caller): Extension<Claims>,
         Path(qna_id): Path<Uuid>,
-        Json(payload): Json<UpdateQuestionRequest>,
+        ValidatedJson(payload): ValidatedJson<UpdateQuestionRequest>,
     ) -> Result<impl IntoResponse> {
         debug!("update_question: caller: {caller:?}, payload: {payload:?}");
 
@@ -82,4 +82,50 @@ impl QnAController {
 
         Ok(into_ok_response("success".into(), Some(res)))
     }
+
+    pub async fn get_tags(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.qna_service);
+        let res = service.get_tags(caller.id, id).await?;
+
+        Ok(into_ok_response("Success".into(), Some(res)))
+    }
+
+    pub async fn set_tags(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+        ValidatedJson(payload): ValidatedJson<SetTagsRequest>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.qna_service);
+        let res = service.set_tags(caller.id, id, payload.tag_ids).await?;
+
+        Ok(into_ok_response("Updated successfully".into(), Some(res)))
+    }
+
+    pub async fn get_revisions(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+        Query(params): Query<QueryRevisionParams>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.qna_service);
+        let res = service.get_revisions(caller.id, id, params).await?;
+
+        Ok(into_ok_response("Success".into(), Some(res)))
+    }
+
+    pub async fn rollback(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path((id, revision_id)): Path<(Uuid, Uuid)>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.qna_service);
+        let res = service.rollback(caller.id, id, revision_id).await?;
+
+        Ok(into_ok_response("Updated successfully".into(), Some(res)))
+    }
 }
@@ -3,7 +3,7 @@ use {
         enums::{error::*, generic::into_ok_response},
         models::quiz::{CreateQuizRequest, FilterQuizParams, UpdateQuizRequest},
         server::AppState,
-        utils::jwt::Claims,
+        utils::{jwt::Claims, validator::ValidatedJson},
     },
     axum::{
         extract::{Path, Query, State},
@@ -20,7 +20,7 @@ impl QuizController {
     pub async fn create(
         State(state): State<AppState>,
         Extension(caller): Extension<Claims>,
-        Json(payload): Json<CreateQuizRequest>,
+        ValidatedJson(payload): ValidatedJson<CreateQuizRequest>,
     ) -> Result<impl IntoResponse> {
         let service = Arc::clone(&state.quiz_service);
         let res = service.create(caller.id, payload).await?;
@@ -32,7 +32,7 @@ impl QuizController {
         State(state): State<AppState>,
         Extension(caller): Extension<Claims>,
         Path(quiz_id): Path<Uuid>,
-        Json(payload): Json<UpdateQuizRequest>,
+        ValidatedJson(payload): ValidatedJson<UpdateQuizRequest>,
     ) -> Result<impl IntoResponse> {
         let service = Arc::clone(&state.quiz_service);
         let res = service.update(caller.id, quiz_id, payload).await?;
@@ -2,15 +2,16 @@ use {
     crate::{
         enums::{error::*, generic::into_ok_response},
         models::quiz_question::{
-            CreateQuizQuestionFromQuestion, CreateQuizQuestionRequest, UpdateQuizQuestionRequest,
+            CreateQuizQuestionFromMistakes, CreateQuizQuestionFromQuestion,
+            CreateQuizQuestionFromTags, CreateQuizQuestionRequest, UpdateQuizQuestionRequest,
         },
         server::AppState,
-        utils::jwt::Claims,
+        utils::{jwt::Claims, validator::ValidatedJson},
     },
     axum::{
         extract::{Path, State},
         response::IntoResponse,
-        Extension, Json,
+        Extension,
     },
     std::sync::Arc,
     uuid::Uuid,
@@ -23,7 +24,7 @@ impl QuizQuestionController {
         State(state): State<AppState>,
         Extension(caller): Extension<Claims>,
         Path(quiz_id): Path<Uuid>,
-        Json(payloads): Json<Vec<CreateQuizQuestionRequest>>,
+        ValidatedJson(payloads): ValidatedJson<Vec<CreateQuizQuestionRequest>>,
     ) -> Result<impl IntoResponse> {
         let service = Arc::clone(&state.quiz_question_service);
         let res = service.create(caller.id, quiz_id, payloads).await?;
@@ -34,7 +35,7 @@ impl QuizQuestionController {
     pub async fn create_from_questions(
         State(state): State<AppState>,
         Extension(caller): Extension<Claims>,
-        Json(payload): Json<CreateQuizQuestionFromQuestion>,
+        ValidatedJson(payload): ValidatedJson<CreateQuizQuestionFromQuestion>,
     ) -> Result<impl IntoResponse> {
         let service = Arc::clone(&state.quiz_question_service);
         let res = service.create_from_question(caller.id, payload).await?;
@@ -42,11 +43,33 @@ impl QuizQuestionController {
         Ok(into_ok_response("Created successfully".into(), Some(res)))
     }
 
+    pub async fn create_from_tags(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        ValidatedJson(payload): ValidatedJson<CreateQuizQuestionFromTags>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.quiz_question_service);
+        let res = service.create_from_tags(caller.id, payload).await?;
+
+        Ok(into_ok_response("Created successfully".into(), Some(res)))
+    }
+
+    pub async fn create_from_mistakes(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        ValidatedJson(payload): ValidatedJson<CreateQuizQuestionFromMistakes>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.quiz_question_service);
+        let res = service.create_from_mistakes(caller.id, payload).await?;
+
+        Ok(into_ok_response("Created successfully".into(), Some(res)))
+    }
+
     pub async fn update(
         State(state): State<AppState>,
         Extension(caller): Extension<Claims>,
         Path(quiz_id): Path<Uuid>,
-        Json(payloads): Json<Vec<UpdateQuizQuestionRequest>>,
+        ValidatedJson(payloads): ValidatedJson<Vec<UpdateQuizQuestionRequest>>,
     ) -> Result<impl IntoResponse> {
         let service = Arc::clone(&state.quiz_question_service);
         let res = service.update(caller.id, quiz_id, payloads).await?;
@@ -57,8 +80,7 @@ impl QuizQuestionController {
     pub async fn delete(
         State(state): State<AppState>,
         Extension(caller): Extension<Claims>,
-        Path(quiz_id): Path<Uuid>,
-        Path(quiz_question_id): Path<Uuid>,
+        Path((quiz_id, quiz_question_id)): Path<(Uuid, Uuid)>,
     ) -> Result<impl IntoResponse> {
         let service = Arc::clone(&state.quiz_question_service);
         service.delete(caller.id, quiz_id, quiz_question_id).await?;
@@ -69,8 +91,7 @@ impl QuizQuestionController {
     pub async fn get_by_id(
         State(state): State<AppState>,
         Extension(caller): Extension<Claims>,
-        Path(quiz_id): Path<Uuid>,
-        Path(quiz_question_id): Path<Uuid>,
+        Path((quiz_id, quiz_question_id)): Path<(Uuid, Uuid)>,
     ) -> Result<impl IntoResponse> {
         let service = Arc::clone(&state.quiz_question_service);
         let res = service
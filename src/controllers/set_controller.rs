@@ -1,14 +1,20 @@
 use {
     crate::{
         enums::{error::*, generic::into_ok_response},
-        models::set::{CreateSetRequest, ShareSetForUser, UpdateSetRequest},
+        models::{
+            set::{
+                AcceptInviteRequest, CreateSetRequest, InviteToSetRequest, ShareSetForUser,
+                UpdateSetRequest,
+            },
+            tag::SetTagsRequest,
+        },
         server::AppState,
-        utils::jwt::Claims,
+        utils::{jwt::Claims, validator::ValidatedJson},
     },
     axum::{
         extract::{Path, State},
         response::IntoResponse,
-        Extension, Json,
+        Extension,
     },
     std::sync::Arc,
     uuid::Uuid,
@@ -20,7 +26,7 @@ impl SetController {
     pub async fn create(
         State(state): State<AppState>,
         Extension(caller): Extension<Claims>,
-        Json(payload): Json<CreateSetRequest>,
+        ValidatedJson(payload): ValidatedJson<CreateSetRequest>,
     ) -> Result<impl IntoResponse> {
         let service = Arc::clone(&state.set_service);
         let res = service.create(caller.id, payload).await?;
@@ -53,7 +59,7 @@ impl SetController {
         State(state): State<AppState>,
         Extension(caller): Extension<Claims>,
         Path(set_id): Path<Uuid>,
-        Json(payload): Json<UpdateSetRequest>,
+        ValidatedJson(payload): ValidatedJson<UpdateSetRequest>,
     ) -> Result<impl IntoResponse> {
         let service = Arc::clone(&state.set_service);
         let res = service.update(caller.id, set_id, payload).await?;
@@ -79,11 +85,60 @@ impl SetController {
         State(state): State<AppState>,
         Extension(caller): Extension<Claims>,
         Path(set_id): Path<Uuid>,
-        Json(payload): Json<Vec<ShareSetForUser>>,
+        ValidatedJson(payload): ValidatedJson<Vec<ShareSetForUser>>,
     ) -> Result<impl IntoResponse> {
         let service = Arc::clone(&state.set_service);
         let res = service.share(caller.id, set_id, payload).await?;
 
         Ok(into_ok_response("Shared successfully".into(), Some(res)))
     }
+
+    pub async fn invite(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(set_id): Path<Uuid>,
+        ValidatedJson(payload): ValidatedJson<InviteToSetRequest>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.set_service);
+        service.invite(caller.id, set_id, payload).await?;
+
+        Ok(into_ok_response(
+            "Invited successfully".into(),
+            None::<String>,
+        ))
+    }
+
+    pub async fn accept_invite(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        ValidatedJson(payload): ValidatedJson<AcceptInviteRequest>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.set_service);
+        let res = service.accept_invite(caller.id, payload.token).await?;
+
+        Ok(into_ok_response("Accepted successfully".into(), Some(res)))
+    }
+
+    pub async fn get_tags(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.set_service);
+        let res = service.get_tags(caller.id, id).await?;
+
+        Ok(into_ok_response("Success".into(), Some(res)))
+    }
+
+    pub async fn set_tags(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+        ValidatedJson(payload): ValidatedJson<SetTagsRequest>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.set_service);
+        let res = service.set_tags(caller.id, id, payload.tag_ids).await?;
+
+        Ok(into_ok_response("Updated successfully".into(), Some(res)))
+    }
 }
@@ -0,0 +1,77 @@
+use {
+    crate::{
+        enums::{error::*, generic::into_ok_response},
+        models::tag::{CreateTagRequest, UpdateTagRequest},
+        server::AppState,
+        utils::{jwt::Claims, validator::ValidatedJson},
+    },
+    axum::{
+        extract::{Path, State},
+        response::IntoResponse,
+        Extension,
+    },
+    flashcard::only_role,
+    std::sync::Arc,
+    uuid::Uuid,
+};
+
+pub struct TagController;
+
+impl TagController {
+    pub async fn get_all(State(state): State<AppState>) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.tag_service);
+        let res = service.get_all().await?;
+
+        Ok(into_ok_response("success".into(), Some(res)))
+    }
+
+    pub async fn create(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        ValidatedJson(payload): ValidatedJson<CreateTagRequest>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.tag_service);
+        let res = service.create(caller.id, payload).await?;
+
+        Ok(into_ok_response("Created successfully".into(), Some(res)))
+    }
+
+    // Tag dùng chung nên chỉ Staff được sửa/xoá
+    #[only_role("Staff")]
+    pub async fn update(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+        ValidatedJson(payload): ValidatedJson<UpdateTagRequest>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.tag_service);
+        let res = service.update(id, payload).await?;
+
+        Ok(into_ok_response("Updated successfully".into(), Some(res)))
+    }
+
+    #[only_role("Staff")]
+    pub async fn delete(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.tag_service);
+        service.delete(id).await?;
+
+        Ok(into_ok_response(
+            "Deleted successfully".into(),
+            None::<String>,
+        ))
+    }
+
+    pub async fn accuracy(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.tag_service);
+        let res = service.accuracy(caller.id).await?;
+
+        Ok(into_ok_response("success".into(), Some(res)))
+    }
+}
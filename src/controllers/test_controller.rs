@@ -1,14 +1,14 @@
 use {
     crate::{
         enums::{error::*, generic::into_ok_response},
-        models::test::{CreateTest, QueryTestParams, ResolveTestRequest},
+        models::test::{CreateTest, FlagQuestionRequest, QueryTestParams, ResolveTestRequest},
         server::AppState,
-        utils::jwt::Claims,
+        utils::{jwt::Claims, validator::ValidatedJson},
     },
     axum::{
         extract::{Path, Query, State},
         response::IntoResponse,
-        Extension, Json,
+        Extension,
     },
     std::sync::Arc,
     uuid::Uuid,
@@ -42,7 +42,7 @@ impl TestController {
     pub async fn create(
         State(state): State<AppState>,
         Extension(caller): Extension<Claims>,
-        Json(payload): Json<CreateTest>,
+        ValidatedJson(payload): ValidatedJson<CreateTest>,
     ) -> Result<impl IntoResponse> {
         let service = Arc::clone(&state.test_service);
         let res = service.create_one(caller.id, payload).await?;
@@ -64,8 +64,7 @@ impl TestController {
     pub async fn get_testing_question(
         State(state): State<AppState>,
         Extension(caller): Extension<Claims>,
-        Path(test_id): Path<Uuid>,
-        Path(quiz_question_id): Path<Uuid>,
+        Path((test_id, quiz_question_id)): Path<(Uuid, Uuid)>,
     ) -> Result<impl IntoResponse> {
         let service = Arc::clone(&state.test_service);
         let res = service
@@ -91,16 +90,27 @@ impl TestController {
     pub async fn resolve_test_question(
         State(state): State<AppState>,
         Extension(caller): Extension<Claims>,
-        Path(test_id): Path<Uuid>,
-        Path(question_id): Path<Uuid>,
-        Json(payload): Json<ResolveTestRequest>,
+        Path((test_id, question_id)): Path<(Uuid, Uuid)>,
+        ValidatedJson(payload): ValidatedJson<ResolveTestRequest>,
     ) -> Result<impl IntoResponse> {
         let service = Arc::clone(&state.test_service);
         let res = service
             .resolve_testing_question(caller.id, test_id, question_id, payload)
             .await?;
 
-        Ok(into_ok_response("Resolved successfully".into(), res))
+        Ok(into_ok_response("Resolved successfully".into(), Some(res)))
+    }
+
+    // Tiếp tục làm test trên thiết bị khác => vô hiệu hoá session cũ
+    pub async fn take_over(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(test_id): Path<Uuid>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.test_service);
+        let res = service.take_over_session(caller.id, test_id).await?;
+
+        Ok(into_ok_response("Took over successfully".into(), Some(res)))
     }
 
     // Nhấn nút submit => chương trình chấm điểm các test result
@@ -133,8 +143,7 @@ impl TestController {
     pub async fn review_solution(
         State(state): State<AppState>,
         Extension(caller): Extension<Claims>,
-        Path(test_id): Path<Uuid>,
-        Path(quiz_question_id): Path<Uuid>,
+        Path((test_id, quiz_question_id)): Path<(Uuid, Uuid)>,
     ) -> Result<impl IntoResponse> {
         let service = Arc::clone(&state.test_service);
         let res = service
@@ -143,4 +152,29 @@ impl TestController {
 
         Ok(into_ok_response("Success".into(), Some(res)))
     }
+
+    pub async fn get_adaptive_path(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(test_id): Path<Uuid>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.test_service);
+        let res = service.get_adaptive_path(caller.id, test_id).await?;
+
+        Ok(into_ok_response("Success".into(), Some(res)))
+    }
+
+    pub async fn flag_question(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path((test_id, quiz_question_id)): Path<(Uuid, Uuid)>,
+        ValidatedJson(payload): ValidatedJson<FlagQuestionRequest>,
+    ) -> Result<impl IntoResponse> {
+        let service = Arc::clone(&state.test_service);
+        let res = service
+            .flag_question(caller.id, test_id, quiz_question_id, payload.is_flagged)
+            .await?;
+
+        Ok(into_ok_response("Updated successfully".into(), Some(res)))
+    }
 }
@@ -0,0 +1,75 @@
+use {
+    crate::{
+        debug,
+        enums::{error::*, generic::into_ok_response},
+        server::AppState,
+        utils::jwt::Claims,
+    },
+    axum::{
+        extract::{Path, State},
+        response::IntoResponse,
+        Extension,
+    },
+    flashcard::only_role,
+    std::sync::Arc,
+    uuid::Uuid,
+};
+
+pub struct TrashController;
+
+impl TrashController {
+    #[only_role("Staff", "User")]
+    pub async fn get_all(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+    ) -> Result<impl IntoResponse> {
+        debug!("get trash request: {caller:?}");
+
+        let service = Arc::clone(&state.trash_service);
+        let res = service.get_all(caller.id).await?;
+
+        Ok(into_ok_response("success".into(), Some(res)))
+    }
+
+    #[only_role("Staff", "User")]
+    pub async fn restore_set(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+    ) -> Result<impl IntoResponse> {
+        debug!("restore set request: {caller:?} {id}");
+
+        let service = Arc::clone(&state.trash_service);
+        let res = service.restore_set(caller.id, id).await?;
+
+        Ok(into_ok_response("Restored successfully".into(), Some(res)))
+    }
+
+    #[only_role("Staff", "User")]
+    pub async fn restore_question(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+    ) -> Result<impl IntoResponse> {
+        debug!("restore question request: {caller:?} {id}");
+
+        let service = Arc::clone(&state.trash_service);
+        let res = service.restore_question(caller.id, id).await?;
+
+        Ok(into_ok_response("Restored successfully".into(), Some(res)))
+    }
+
+    #[only_role("Staff", "User")]
+    pub async fn restore_quiz(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+    ) -> Result<impl IntoResponse> {
+        debug!("restore quiz request: {caller:?} {id}");
+
+        let service = Arc::clone(&state.trash_service);
+        let res = service.restore_quiz(caller.id, id).await?;
+
+        Ok(into_ok_response("Restored successfully".into(), Some(res)))
+    }
+}
@@ -2,13 +2,25 @@ use {
     crate::{
         debug,
         enums::{error::Result, generic::into_ok_response},
-        models::user::{DeleteRequest, UpdateUserPassword, UpdateUserRequest, UpdateUserRole},
+        models::{
+            api_token::CreateApiTokenRequest,
+            user::{
+                AcceptStaffInviteRequest, DeleteRequest, InviteStaffRequest, ResetTotpRequest,
+                TotpCodeRequest, UpdateUserPassword, UpdateUserRequest, UpdateUserRole,
+            },
+        },
         server::AppState,
-        utils::jwt::Claims,
+        utils::{jwt::Claims, validator::ValidatedJson},
+    },
+    axum::{
+        body::Bytes,
+        extract::{Path, State},
+        response::IntoResponse,
+        Extension,
     },
-    axum::{extract::State, response::IntoResponse, Extension, Json},
     flashcard::only_role,
     std::sync::Arc,
+    uuid::Uuid,
 };
 
 pub struct UserController;
@@ -31,7 +43,7 @@ impl UserController {
     pub async fn update_self(
         State(state): State<AppState>,
         Extension(caller): Extension<Claims>,
-        Json(payload): Json<UpdateUserRequest>,
+        ValidatedJson(payload): ValidatedJson<UpdateUserRequest>,
     ) -> Result<impl IntoResponse> {
         debug!("update request: {caller:?} {payload:?}");
 
@@ -41,11 +53,26 @@ impl UserController {
         Ok(into_ok_response("Updated successfully".into(), res))
     }
 
+    // body là nội dung ảnh
+    #[only_role("Staff", "User")]
+    pub async fn upload_avatar(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        body: Bytes,
+    ) -> Result<impl IntoResponse> {
+        debug!("upload avatar request: {caller:?} {} bytes", body.len());
+
+        let service = Arc::clone(&state.media_service);
+        let res = service.upload_avatar(caller.id, body.to_vec()).await?;
+
+        Ok(into_ok_response("Updated successfully".into(), res))
+    }
+
     #[only_role("Staff", "User")]
     pub async fn update_password(
         State(state): State<AppState>,
         Extension(caller): Extension<Claims>,
-        Json(payload): Json<UpdateUserPassword>,
+        ValidatedJson(payload): ValidatedJson<UpdateUserPassword>,
     ) -> Result<impl IntoResponse> {
         debug!("update request: {caller:?} {payload:?}");
 
@@ -66,7 +93,7 @@ impl UserController {
     pub async fn update_role(
         State(state): State<AppState>,
         Extension(caller): Extension<Claims>,
-        Json(payload): Json<UpdateUserRole>,
+        ValidatedJson(payload): ValidatedJson<UpdateUserRole>,
     ) -> Result<impl IntoResponse> {
         debug!("update request: {caller:?} {payload:?}");
 
@@ -78,16 +105,160 @@ impl UserController {
         Ok(into_ok_response("Updated successfully".into(), res))
     }
 
+    #[only_role("Staff")]
+    pub async fn invite_staff(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        ValidatedJson(payload): ValidatedJson<InviteStaffRequest>,
+    ) -> Result<impl IntoResponse> {
+        debug!("invite staff request: {caller:?} {payload:?}");
+
+        let service = Arc::clone(&state.user_service);
+        service.invite_staff(caller.id, payload.email).await?;
+
+        Ok(into_ok_response(
+            "Invited successfully".into(),
+            None::<String>,
+        ))
+    }
+
+    #[only_role("Staff", "User")]
+    pub async fn accept_staff_invite(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        ValidatedJson(payload): ValidatedJson<AcceptStaffInviteRequest>,
+    ) -> Result<impl IntoResponse> {
+        debug!("accept staff invite request: {caller:?}");
+
+        let service = Arc::clone(&state.user_service);
+        let res = service
+            .accept_staff_invite(caller.id, payload.token)
+            .await?;
+
+        Ok(into_ok_response("Accepted successfully".into(), Some(res)))
+    }
+
+    #[only_role("Staff", "User")]
+    pub async fn setup_totp(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+    ) -> Result<impl IntoResponse> {
+        debug!("setup totp request: {caller:?}");
+
+        let service = Arc::clone(&state.user_service);
+        let res = service.setup_totp(caller.id).await?;
+
+        Ok(into_ok_response(
+            "Scan the provisioning uri then activate with a code".into(),
+            Some(res),
+        ))
+    }
+
+    #[only_role("Staff", "User")]
+    pub async fn activate_totp(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        ValidatedJson(payload): ValidatedJson<TotpCodeRequest>,
+    ) -> Result<impl IntoResponse> {
+        debug!("activate totp request: {caller:?}");
+
+        let service = Arc::clone(&state.user_service);
+        let recovery_codes = service.activate_totp(caller.id, payload.code).await?;
+
+        Ok(into_ok_response(
+            "Two-factor authentication enabled, keep the recovery codes safe".into(),
+            Some(recovery_codes),
+        ))
+    }
+
+    #[only_role("Staff", "User")]
+    pub async fn disable_totp(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        ValidatedJson(payload): ValidatedJson<TotpCodeRequest>,
+    ) -> Result<impl IntoResponse> {
+        debug!("disable totp request: {caller:?}");
+
+        let service = Arc::clone(&state.user_service);
+        service.disable_totp(caller.id, payload.code).await?;
+
+        Ok(into_ok_response(
+            "Two-factor authentication disabled".into(),
+            None::<String>,
+        ))
+    }
+
+    #[only_role("Staff")]
+    pub async fn reset_totp(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        ValidatedJson(payload): ValidatedJson<ResetTotpRequest>,
+    ) -> Result<impl IntoResponse> {
+        debug!("reset totp request: {caller:?} {payload:?}");
+
+        let service = Arc::clone(&state.user_service);
+        service.reset_totp(caller.id, payload.user_id).await?;
+
+        Ok(into_ok_response(
+            "Reset successfully".into(),
+            None::<String>,
+        ))
+    }
+
+    #[only_role("Staff", "User")]
+    pub async fn get_all_tokens(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+    ) -> Result<impl IntoResponse> {
+        debug!("get api tokens request: {caller:?}");
+
+        let service = Arc::clone(&state.api_token_service);
+        let tokens = service.get_all_of_user(caller.id).await?;
+
+        Ok(into_ok_response("success".into(), Some(tokens)))
+    }
+
+    #[only_role("Staff", "User")]
+    pub async fn create_token(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        ValidatedJson(payload): ValidatedJson<CreateApiTokenRequest>,
+    ) -> Result<impl IntoResponse> {
+        debug!("create api token request: {caller:?} {payload:?}");
+
+        let service = Arc::clone(&state.api_token_service);
+        let created = service.create(caller.id, payload).await?;
+
+        Ok(into_ok_response(
+            "Token created, it will not be shown again".into(),
+            Some(created),
+        ))
+    }
+
+    #[only_role("Staff", "User")]
+    pub async fn revoke_token(
+        State(state): State<AppState>,
+        Extension(caller): Extension<Claims>,
+        Path(id): Path<Uuid>,
+    ) -> Result<impl IntoResponse> {
+        debug!("revoke api token request: {caller:?} {id}");
+
+        let service = Arc::clone(&state.api_token_service);
+        service.revoke(caller.id, id).await?;
+
+        Ok(into_ok_response("Token revoked".into(), None::<String>))
+    }
+
     #[only_role("Staff")]
     pub async fn delete(
         State(state): State<AppState>,
         Extension(caller): Extension<Claims>,
-        Json(payload): Json<DeleteRequest>,
+        ValidatedJson(payload): ValidatedJson<DeleteRequest>,
     ) -> Result<impl IntoResponse> {
         debug!("delete request: {payload:?}");
 
         let service = Arc::clone(&state.user_service);
-        service.delete(payload.user_id).await?;
+        service.delete(caller.id, payload.user_id).await?;
 
         Ok(into_ok_response(
             "Deleted successfully".into(),
@@ -2,15 +2,34 @@
 
 pub mod prelude;
 
+pub mod api_tokens;
+pub mod audit_events;
+pub mod change_requests;
+pub mod comment_mentions;
+pub mod comments;
+pub mod login_failures;
+pub mod media;
+pub mod notes;
+pub mod oidc_states;
+pub mod ownership_transfers;
+pub mod question_revisions;
+pub mod question_tags;
 pub mod questions;
 pub mod quiz_question_answers;
 pub mod quiz_questions;
 pub mod quizes;
+pub mod rate_limit_buckets;
+pub mod recovery_codes;
 pub mod sea_orm_active_enums;
+pub mod set_tags;
 pub mod sets;
 pub mod shared_quizes;
 pub mod shared_sets;
+pub mod tags;
+pub mod test_adaptive_steps;
 pub mod test_answers;
 pub mod test_question_results;
 pub mod tests;
+pub mod user_identities;
+pub mod user_tokens;
 pub mod users;
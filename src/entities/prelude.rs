@@ -1,9 +1,19 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.0
 
 pub use super::{
+    api_tokens::Entity as ApiTokens, audit_events::Entity as AuditEvents,
+    change_requests::Entity as ChangeRequests, comment_mentions::Entity as CommentMentions,
+    comments::Entity as Comments, login_failures::Entity as LoginFailures, media::Entity as Media,
+    notes::Entity as Notes, oidc_states::Entity as OidcStates,
+    ownership_transfers::Entity as OwnershipTransfers,
+    question_revisions::Entity as QuestionRevisions, question_tags::Entity as QuestionTags,
     questions::Entity as Questions, quiz_question_answers::Entity as QuizQuestionAnswers,
-    quiz_questions::Entity as QuizQuestions, quizes::Entity as Quizes, sets::Entity as Sets,
-    shared_quizes::Entity as SharedQuizes, shared_sets::Entity as SharedSets,
-    test_answers::Entity as TestAnswers, test_question_results::Entity as TestQuestionResults,
-    tests::Entity as Tests, users::Entity as Users,
+    quiz_questions::Entity as QuizQuestions, quizes::Entity as Quizes,
+    rate_limit_buckets::Entity as RateLimitBuckets, recovery_codes::Entity as RecoveryCodes,
+    set_tags::Entity as SetTags, sets::Entity as Sets, shared_quizes::Entity as SharedQuizes,
+    shared_sets::Entity as SharedSets, tags::Entity as Tags,
+    test_adaptive_steps::Entity as TestAdaptiveSteps, test_answers::Entity as TestAnswers,
+    test_question_results::Entity as TestQuestionResults, tests::Entity as Tests,
+    user_identities::Entity as UserIdentities, user_tokens::Entity as UserTokens,
+    users::Entity as Users,
 };
@@ -1,18 +1,22 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.0
 
 use {
-    super::sea_orm_active_enums::QuestionTypeEnum,
+    super::sea_orm_active_enums::{ContentFormatEnum, QuestionTypeEnum},
     sea_orm::entity::prelude::*,
     serde::{Deserialize, Serialize},
+    utoipa::ToSchema,
 };
 
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize, ToSchema)]
+#[schema(as = Question)]
 #[sea_orm(table_name = "questions")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
     pub id: Uuid,
     pub r#type: QuestionTypeEnum,
     pub content: String,
+    pub content_format: ContentFormatEnum,
+    #[schema(value_type = Object)]
     pub answers: Json,
     pub set_id: Uuid,
     pub creator_id: Uuid,
@@ -20,10 +24,35 @@ pub struct Model {
     pub created_at: DateTime,
     pub updated_at: DateTime,
     pub is_deleted: bool,
+    pub deleted_at: Option<DateTime>,
+    #[sea_orm(column_type = "Double", nullable)]
+    pub difficulty: Option<f64>,
+    #[sea_orm(column_type = "Double", nullable)]
+    pub difficulty_confidence: Option<f64>,
+    pub difficulty_attempts: i32,
+    pub media_id: Option<Uuid>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::change_requests::Entity")]
+    ChangeRequests,
+    #[sea_orm(has_many = "super::comments::Entity")]
+    Comments,
+    #[sea_orm(
+        belongs_to = "super::media::Entity",
+        from = "Column::MediaId",
+        to = "super::media::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    Media,
+    #[sea_orm(has_many = "super::notes::Entity")]
+    Notes,
+    #[sea_orm(has_many = "super::question_revisions::Entity")]
+    QuestionRevisions,
+    #[sea_orm(has_many = "super::question_tags::Entity")]
+    QuestionTags,
     #[sea_orm(has_many = "super::quiz_questions::Entity")]
     QuizQuestions,
     #[sea_orm(
@@ -52,6 +81,51 @@ pub enum Relation {
     Users1,
 }
 
+impl Related<super::change_requests::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ChangeRequests.def()
+    }
+}
+
+impl Related<super::comments::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Comments.def()
+    }
+}
+
+impl Related<super::media::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Media.def()
+    }
+}
+
+impl Related<super::notes::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Notes.def()
+    }
+}
+
+impl Related<super::question_revisions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::QuestionRevisions.def()
+    }
+}
+
+impl Related<super::question_tags::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::QuestionTags.def()
+    }
+}
+
+impl Related<super::tags::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::question_tags::Relation::Tags.def()
+    }
+    fn via() -> Option<RelationDef> {
+        Some(super::question_tags::Relation::Questions.def().rev())
+    }
+}
+
 impl Related<super::quiz_questions::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::QuizQuestions.def()
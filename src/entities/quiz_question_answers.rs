@@ -3,9 +3,11 @@
 use {
     sea_orm::entity::prelude::*,
     serde::{Deserialize, Serialize},
+    utoipa::ToSchema,
 };
 
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize, ToSchema)]
+#[schema(as = QuizQuestionAnswer)]
 #[sea_orm(table_name = "quiz_question_answers")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
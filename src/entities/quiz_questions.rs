@@ -1,12 +1,14 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.0
 
 use {
-    super::sea_orm_active_enums::QuestionTypeEnum,
+    super::sea_orm_active_enums::{ContentFormatEnum, QuestionTypeEnum},
     sea_orm::entity::prelude::*,
     serde::{Deserialize, Serialize},
+    utoipa::ToSchema,
 };
 
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize, ToSchema)]
+#[schema(as = QuizQuestion)]
 #[sea_orm(table_name = "quiz_questions")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
@@ -15,6 +17,7 @@ pub struct Model {
     pub sample_id: Option<Uuid>,
     #[sea_orm(column_type = "Text")]
     pub question_content: String,
+    pub content_format: ContentFormatEnum,
     pub r#type: QuestionTypeEnum,
     pub index: i32,
     pub point: i32,
@@ -23,10 +26,18 @@ pub struct Model {
     pub created_at: DateTime,
     pub updated_at: DateTime,
     pub is_deleted: bool,
+    pub deleted_at: Option<DateTime>,
+    #[sea_orm(column_type = "Double", nullable)]
+    pub difficulty: Option<f64>,
+    #[sea_orm(column_type = "Double", nullable)]
+    pub difficulty_confidence: Option<f64>,
+    pub difficulty_attempts: i32,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::notes::Entity")]
+    Notes,
     #[sea_orm(
         belongs_to = "super::questions::Entity",
         from = "Column::SampleId",
@@ -45,6 +56,8 @@ pub enum Relation {
         on_delete = "Restrict"
     )]
     Quizes,
+    #[sea_orm(has_many = "super::test_adaptive_steps::Entity")]
+    TestAdaptiveSteps,
     #[sea_orm(has_many = "super::test_answers::Entity")]
     TestAnswers,
     #[sea_orm(has_many = "super::test_question_results::Entity")]
@@ -53,6 +66,12 @@ pub enum Relation {
     Tests,
 }
 
+impl Related<super::notes::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Notes.def()
+    }
+}
+
 impl Related<super::questions::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Questions.def()
@@ -71,6 +90,12 @@ impl Related<super::quizes::Entity> for Entity {
     }
 }
 
+impl Related<super::test_adaptive_steps::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TestAdaptiveSteps.def()
+    }
+}
+
 impl Related<super::test_answers::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::TestAnswers.def()
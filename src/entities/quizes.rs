@@ -3,9 +3,11 @@
 use {
     sea_orm::entity::prelude::*,
     serde::{Deserialize, Serialize},
+    utoipa::ToSchema,
 };
 
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize, ToSchema)]
+#[schema(as = Quiz)]
 #[sea_orm(table_name = "quizes")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
@@ -13,6 +15,7 @@ pub struct Model {
     pub creator_id: Uuid,
     pub name: String,
     pub is_public: bool,
+    #[schema(value_type = Object)]
     pub question_counts: Json,
     pub is_published: bool,
     pub total_point: i32,
@@ -21,6 +24,7 @@ pub struct Model {
     pub updated_at: DateTime,
     pub publish_at: Option<DateTime>,
     pub is_deleted: bool,
+    pub deleted_at: Option<DateTime>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -3,9 +3,57 @@
 use {
     sea_orm::entity::prelude::*,
     serde::{Deserialize, Serialize},
+    utoipa::ToSchema,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[derive(
+    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema,
+)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "change_request_status_enum"
+)]
+pub enum ChangeRequestStatusEnum {
+    #[sea_orm(string_value = "Pending")]
+    Pending,
+    #[sea_orm(string_value = "Accepted")]
+    Accepted,
+    #[sea_orm(string_value = "Rejected")]
+    Rejected,
+    #[sea_orm(string_value = "Withdrawn")]
+    Withdrawn,
+}
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Serialize,
+    Deserialize,
+    ToSchema,
+)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "content_format_enum"
+)]
+pub enum ContentFormatEnum {
+    #[default]
+    #[sea_orm(string_value = "Plain")]
+    Plain,
+    #[sea_orm(string_value = "Markdown")]
+    Markdown,
+    #[sea_orm(string_value = "Html")]
+    Html,
+}
+#[derive(
+    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema,
+)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "question_type_enum")]
 pub enum QuestionTypeEnum {
     #[sea_orm(string_value = "MultipleChoice")]
@@ -15,7 +63,9 @@ pub enum QuestionTypeEnum {
     #[sea_orm(string_value = "TextFill")]
     TextFill,
 }
-#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[derive(
+    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema,
+)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "role_enum")]
 pub enum RoleEnum {
     #[sea_orm(string_value = "Staff")]
@@ -23,7 +73,9 @@ pub enum RoleEnum {
     #[sea_orm(string_value = "User")]
     User,
 }
-#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[derive(
+    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema,
+)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "status_enum")]
 pub enum StatusEnum {
     #[sea_orm(string_value = "NotStart")]
@@ -35,3 +87,17 @@ pub enum StatusEnum {
     #[sea_orm(string_value = "Abandoned")]
     Abandoned,
 }
+#[derive(
+    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "token_purpose_enum")]
+pub enum TokenPurposeEnum {
+    #[sea_orm(string_value = "VerifyEmail")]
+    VerifyEmail,
+    #[sea_orm(string_value = "ResetPassword")]
+    ResetPassword,
+    #[sea_orm(string_value = "InviteToSet")]
+    InviteToSet,
+    #[sea_orm(string_value = "InviteStaff")]
+    InviteStaff,
+}
@@ -3,9 +3,11 @@
 use {
     sea_orm::entity::prelude::*,
     serde::{Deserialize, Serialize},
+    utoipa::ToSchema,
 };
 
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize, ToSchema)]
+#[schema(as = Set)]
 #[sea_orm(table_name = "sets")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
@@ -19,12 +21,19 @@ pub struct Model {
     pub created_at: DateTime,
     pub updated_at: DateTime,
     pub is_deleted: bool,
+    pub deleted_at: Option<DateTime>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::change_requests::Entity")]
+    ChangeRequests,
+    #[sea_orm(has_many = "super::comments::Entity")]
+    Comments,
     #[sea_orm(has_many = "super::questions::Entity")]
     Questions,
+    #[sea_orm(has_many = "super::set_tags::Entity")]
+    SetTags,
     #[sea_orm(has_many = "super::shared_sets::Entity")]
     SharedSets,
     #[sea_orm(
@@ -45,12 +54,39 @@ pub enum Relation {
     Users1,
 }
 
+impl Related<super::change_requests::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ChangeRequests.def()
+    }
+}
+
+impl Related<super::comments::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Comments.def()
+    }
+}
+
 impl Related<super::questions::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Questions.def()
     }
 }
 
+impl Related<super::set_tags::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SetTags.def()
+    }
+}
+
+impl Related<super::tags::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::set_tags::Relation::Tags.def()
+    }
+    fn via() -> Option<RelationDef> {
+        Some(super::set_tags::Relation::Sets.def().rev())
+    }
+}
+
 impl Related<super::shared_sets::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::SharedSets.def()
@@ -3,9 +3,11 @@
 use {
     sea_orm::entity::prelude::*,
     serde::{Deserialize, Serialize},
+    utoipa::ToSchema,
 };
 
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize, ToSchema)]
+#[schema(as = SharedQuiz)]
 #[sea_orm(table_name = "shared_quizes")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
@@ -4,9 +4,11 @@ use {
     super::sea_orm_active_enums::StatusEnum,
     sea_orm::entity::prelude::*,
     serde::{Deserialize, Serialize},
+    utoipa::ToSchema,
 };
 
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize, ToSchema)]
+#[schema(as = Test)]
 #[sea_orm(table_name = "tests")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
@@ -23,6 +25,14 @@ pub struct Model {
     pub total_question: i32,
     pub status: StatusEnum,
     pub created_at: DateTime,
+    pub revision: i32,
+    pub session_id: Option<Uuid>,
+    pub is_adaptive: bool,
+    #[sea_orm(column_type = "Double", nullable)]
+    pub ability: Option<f64>,
+    #[sea_orm(column_type = "Double", nullable)]
+    pub ability_se: Option<f64>,
+    pub is_practice: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -43,6 +53,8 @@ pub enum Relation {
         on_delete = "Restrict"
     )]
     Quizes,
+    #[sea_orm(has_many = "super::test_adaptive_steps::Entity")]
+    TestAdaptiveSteps,
     #[sea_orm(has_many = "super::test_answers::Entity")]
     TestAnswers,
     #[sea_orm(has_many = "super::test_question_results::Entity")]
@@ -69,6 +81,12 @@ impl Related<super::quizes::Entity> for Entity {
     }
 }
 
+impl Related<super::test_adaptive_steps::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TestAdaptiveSteps.def()
+    }
+}
+
 impl Related<super::test_answers::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::TestAnswers.def()
@@ -1,8 +1,16 @@
 use {
     super::generic::{DataResponse, GenericResponse},
-    axum::{http::StatusCode, response::IntoResponse, Json},
+    crate::{error, middleware::request_id::current_request_id},
+    axum::{
+        http::{header::RETRY_AFTER, HeaderValue, StatusCode},
+        response::IntoResponse,
+        Json,
+    },
     sea_orm::DbErr,
+    serde::Serialize,
     thiserror::Error,
+    utoipa::ToSchema,
+    validator::{ValidationErrors, ValidationErrorsKind},
 };
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -26,6 +34,8 @@ pub enum Error {
     UpdateFailed(#[source] DbErr),
     #[error("Record not found")]
     RecordNotFound,
+    #[error("No route: {0}")]
+    RouteNotFound(String),
     #[error("Delete failed: {0}")]
     DeleteFailed(#[source] DbErr),
     #[error("User already exists")]
@@ -44,6 +54,8 @@ pub enum Error {
     CreateFileFailed,
     #[error("File type invalid")]
     FileTypeInvalid,
+    #[error("File too large, max {0} bytes")]
+    FileTooLarge(usize),
     #[error("Field not found: {0}")]
     FieldNotFound(String),
 
@@ -56,6 +68,28 @@ pub enum Error {
     VerifyPasswordFailed,
     #[error("Invalid credentials")]
     InvalidCredentials,
+    #[error("Email has not been verified")]
+    EmailNotVerified,
+    #[error("Token is invalid or has expired")]
+    InvalidToken,
+    #[error("Invalid two-factor code")]
+    InvalidTotpCode,
+    #[error("Two-factor authentication is already enabled")]
+    TotpAlreadyEnabled,
+    #[error("Two-factor authentication is not enabled")]
+    TotpNotEnabled,
+    #[error("Password login is disabled, please sign in with your identity provider")]
+    PasswordLoginDisabled,
+    #[error("OpenID Connect login is not configured")]
+    OidcNotConfigured,
+    #[error("OpenID Connect login failed: {0}")]
+    OidcFailed(String),
+    #[error("Too many requests, retry after {0} seconds")]
+    TooManyRequests(u64),
+
+    // Mail errors
+    #[error("Send mail failed: {0}")]
+    SendMailFailed(String),
 
     // JWT errors
     #[error("JWT decode failed: {0}")]
@@ -68,6 +102,12 @@ pub enum Error {
     Published,
     #[error("Invalid Answer")]
     InvalidAnswer,
+    #[error("Restore the containing set or quiz first")]
+    ParentDeleted,
+    #[error("Validation failed")]
+    Validation(Vec<FieldError>),
+    #[error("Tag already exists")]
+    TagAlreadyExists,
 
     // anyhow error
     #[error(transparent)]
@@ -86,29 +126,276 @@ pub enum Error {
     TestEnded,
     #[error("Test not end")]
     TestNotEnd,
+    #[error("Test was changed by another session")]
+    TestConflict,
+
+    // Change request errors
+    #[error("Change request was already closed")]
+    ChangeRequestClosed,
+}
+
+// Lỗi của một field trong request, trả về khi `Error::Validation`
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct FieldError {
+    pub field: String,
+    pub code: String,
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(
+        field: impl Into<String>,
+        code: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            field: field.into(),
+            code: code.into(),
+            message: message.into(),
+        }
+    }
+}
+
+// Trải phẳng lỗi lồng nhau thành `answers[0].content`, lỗi cấp struct lấy tên
+// cha
+fn flatten_validation_errors(prefix: &str, errors: &ValidationErrors, out: &mut Vec<FieldError>) {
+    for (field, kind) in errors.errors() {
+        let path = match (prefix, field.as_ref()) {
+            ("", "__all__") => "body".to_string(),
+            (prefix, "__all__") => prefix.to_string(),
+            ("", field) => field.to_string(),
+            (prefix, field) => format!("{prefix}.{field}"),
+        };
+
+        match kind {
+            ValidationErrorsKind::Field(errors) => {
+                out.extend(errors.iter().map(|e| {
+                    let message = e
+                        .message
+                        .as_ref()
+                        .map(ToString::to_string)
+                        .unwrap_or_else(|| format!("{path} is invalid ({})", e.code));
+                    FieldError::new(path.clone(), e.code.to_string(), message)
+                }));
+            }
+            ValidationErrorsKind::Struct(errors) => flatten_validation_errors(&path, errors, out),
+            ValidationErrorsKind::List(items) => {
+                for (idx, errors) in items {
+                    flatten_validation_errors(&format!("{path}[{idx}]"), errors, out);
+                }
+            }
+        }
+    }
+}
+
+impl From<ValidationErrors> for Error {
+    fn from(errors: ValidationErrors) -> Self {
+        let mut fields = vec![];
+        flatten_validation_errors("", &errors, &mut fields);
+        fields.sort_by(|a, b| a.field.cmp(&b.field));
+
+        Error::Validation(fields)
+    }
+}
+
+// Nằm trong `result.data` của response lỗi
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ErrorBody {
+    pub code: &'static str,
+    pub request_id: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<FieldError>,
+}
+
+impl Error {
+    // Mã lỗi cố định cho client, không đổi khi đổi message
+    pub fn code(&self) -> &'static str {
+        match self {
+            Error::DatabaseConnectionFailed(_) => "database_connection_failed",
+            Error::DatabaseMigrationFailed => "database_migration_failed",
+            Error::BeginTransactionFailed(_) => "begin_transaction_failed",
+            Error::CommitTransactionFailed(_) => "commit_transaction_failed",
+            Error::InsertFailed(_) => "insert_failed",
+            Error::QueryFailed(_) => "query_failed",
+            Error::UpdateFailed(_) => "update_failed",
+            Error::RecordNotFound => "record_not_found",
+            Error::RouteNotFound(_) => "route_not_found",
+            Error::DeleteFailed(_) => "delete_failed",
+            Error::UserAlreadyExists => "user_already_exists",
+            Error::LoginFailed => "login_failed",
+            Error::PermissionDenied => "permission_denied",
+            Error::DbError(_) => "database_error",
+            Error::IntoModelError(_) => "into_model_error",
+            Error::CreateFileFailed => "create_file_failed",
+            Error::FileTypeInvalid => "file_type_invalid",
+            Error::FileTooLarge(_) => "file_too_large",
+            Error::FieldNotFound(_) => "field_not_found",
+            Error::TokenNotFound => "token_not_found",
+            Error::HashingFailed => "hashing_failed",
+            Error::VerifyPasswordFailed => "verify_password_failed",
+            Error::InvalidCredentials => "invalid_credentials",
+            Error::EmailNotVerified => "email_not_verified",
+            Error::InvalidToken => "invalid_token",
+            Error::InvalidTotpCode => "invalid_totp_code",
+            Error::TotpAlreadyEnabled => "totp_already_enabled",
+            Error::TotpNotEnabled => "totp_not_enabled",
+            Error::PasswordLoginDisabled => "password_login_disabled",
+            Error::OidcNotConfigured => "oidc_not_configured",
+            Error::OidcFailed(_) => "oidc_failed",
+            Error::TooManyRequests(_) => "too_many_requests",
+            Error::SendMailFailed(_) => "send_mail_failed",
+            Error::DecodeJwtFailed(_) => "decode_jwt_failed",
+            Error::EncodeJwtFailed(_) => "encode_jwt_failed",
+            Error::Published => "published",
+            Error::InvalidAnswer => "invalid_answer",
+            Error::ParentDeleted => "parent_deleted",
+            Error::Validation(_) => "validation_failed",
+            Error::TagAlreadyExists => "tag_already_exists",
+            Error::Anyhow(_) => "internal_error",
+            Error::AccessDenied => "access_denied",
+            Error::EnvVarNotFound(_) => "env_var_not_found",
+            Error::TestEnded => "test_ended",
+            Error::TestNotEnd => "test_not_end",
+            Error::TestConflict => "test_conflict",
+            Error::ChangeRequestClosed => "change_request_closed",
+        }
+    }
+
+    pub fn status(&self) -> StatusCode {
+        match self {
+            Error::RecordNotFound | Error::RouteNotFound(_) | Error::OidcNotConfigured => {
+                StatusCode::NOT_FOUND
+            }
+            Error::UserAlreadyExists
+            | Error::TotpAlreadyEnabled
+            | Error::Published
+            | Error::ParentDeleted
+            | Error::TagAlreadyExists
+            | Error::TestEnded
+            | Error::TestNotEnd
+            | Error::TestConflict
+            | Error::ChangeRequestClosed => StatusCode::CONFLICT,
+            Error::AccessDenied
+            | Error::PermissionDenied
+            | Error::EmailNotVerified
+            | Error::PasswordLoginDisabled => StatusCode::FORBIDDEN,
+            Error::TokenNotFound
+            | Error::LoginFailed
+            | Error::InvalidCredentials
+            | Error::InvalidTotpCode
+            | Error::OidcFailed(_)
+            | Error::DecodeJwtFailed(_) => StatusCode::UNAUTHORIZED,
+            Error::InvalidToken | Error::TotpNotEnabled | Error::FileTypeInvalid => {
+                StatusCode::BAD_REQUEST
+            }
+            Error::InvalidAnswer | Error::Validation(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            Error::FileTooLarge(_) => StatusCode::PAYLOAD_TOO_LARGE,
+            Error::TooManyRequests(_) => StatusCode::TOO_MANY_REQUESTS,
+            Error::DatabaseConnectionFailed(_)
+            | Error::DatabaseMigrationFailed
+            | Error::BeginTransactionFailed(_)
+            | Error::CommitTransactionFailed(_)
+            | Error::InsertFailed(_)
+            | Error::QueryFailed(_)
+            | Error::UpdateFailed(_)
+            | Error::DeleteFailed(_)
+            | Error::DbError(_)
+            | Error::IntoModelError(_)
+            | Error::CreateFileFailed
+            | Error::FieldNotFound(_)
+            | Error::HashingFailed
+            | Error::VerifyPasswordFailed
+            | Error::SendMailFailed(_)
+            | Error::EncodeJwtFailed(_)
+            | Error::Anyhow(_)
+            | Error::EnvVarNotFound(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
 }
 
 impl IntoResponse for Error {
     fn into_response(self) -> axum::response::Response {
-        let status = match &self {
-            Error::RecordNotFound => StatusCode::NOT_FOUND,
-            Error::UserAlreadyExists => StatusCode::CONFLICT,
-            Error::AccessDenied | Error::PermissionDenied => StatusCode::FORBIDDEN,
-            Error::TestEnded | Error::Published | Error::InvalidAnswer => {
-                StatusCode::NOT_ACCEPTABLE
-            }
-            Error::InvalidCredentials => StatusCode::UNAUTHORIZED,
-            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        let status = self.status();
+        let request_id = current_request_id();
+
+        // lỗi nội bộ chỉ ghi log, không trả chi tiết (câu query, lỗi db) cho client
+        let msg = if status.is_server_error() {
+            error!("request {request_id:?} failed: {self:?}");
+            "Internal server error".to_string()
+        } else {
+            self.to_string()
+        };
+
+        let fields = match &self {
+            Error::Validation(fields) => fields.clone(),
+            _ => vec![],
         };
 
         let body = GenericResponse {
             status,
-            result: DataResponse::<String> {
-                msg: self.to_string(),
-                data: None,
+            result: DataResponse {
+                msg,
+                data: Some(ErrorBody {
+                    code: self.code(),
+                    request_id,
+                    fields,
+                }),
             },
         };
 
-        (status, Json(body)).into_response()
+        let mut response = (status, Json(body)).into_response();
+        if let Error::TooManyRequests(retry_after) = self {
+            response
+                .headers_mut()
+                .insert(RETRY_AFTER, HeaderValue::from(retry_after));
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, axum::body::to_bytes, serde_json::Value};
+
+    async fn body_of(error: Error) -> (StatusCode, Value) {
+        let response = error.into_response();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn internal_error_is_redacted() {
+        let (status, body) = body_of(Error::QueryFailed(DbErr::Custom(
+            "relation \"users\" does not exist".into(),
+        )))
+        .await;
+
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(body["result"]["msg"], "Internal server error");
+        assert_eq!(body["result"]["data"]["code"], "query_failed");
+        assert!(!body.to_string().contains("users"));
+    }
+
+    #[tokio::test]
+    async fn validation_error_lists_fields() {
+        let (status, body) = body_of(Error::Validation(vec![FieldError::new(
+            "email",
+            "invalid_email",
+            "Email is invalid",
+        )]))
+        .await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["result"]["data"]["code"], "validation_failed");
+        assert_eq!(body["result"]["data"]["fields"][0]["field"], "email");
+    }
+
+    #[test]
+    fn client_errors_are_not_500() {
+        assert_eq!(Error::TokenNotFound.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(Error::LoginFailed.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(Error::TestNotEnd.status(), StatusCode::CONFLICT);
     }
 }
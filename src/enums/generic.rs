@@ -1,18 +1,21 @@
 use {
     axum::{http::StatusCode, Json},
     serde::{Serialize, Serializer},
+    utoipa::ToSchema,
 };
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct DataResponse<T> {
     pub msg: String,
     pub data: Option<T>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct GenericResponse<T> {
     #[serde(serialize_with = "serialize_status_code")]
+    #[schema(value_type = String, example = "200 OK")]
     pub status: StatusCode,
+    #[schema(inline)]
     pub result: DataResponse<T>,
 }
 
@@ -35,7 +38,7 @@ pub fn into_ok_response<T>(msg: String, data: Option<T>) -> (StatusCode, Json<Ge
     (status, Json(body))
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct PaginatedResponse<T> {
     pub total_pages: u64,
     pub current_page: u64,
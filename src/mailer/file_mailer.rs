@@ -0,0 +1,74 @@
+use {
+    super::{Mail, Mailer},
+    crate::{debug, enums::error::*},
+    async_trait::async_trait,
+    chrono::Utc,
+    std::path::PathBuf,
+    tokio::fs,
+};
+
+// Dev mailer: ghi mỗi mail ra một file trong `dir` thay vì gửi thật
+pub struct FileMailer {
+    dir: PathBuf,
+}
+
+impl FileMailer {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+}
+
+#[async_trait]
+impl Mailer for FileMailer {
+    async fn send(&self, mail: Mail) -> Result<()> {
+        fs::create_dir_all(&self.dir)
+            .await
+            .map_err(|_| Error::CreateFileFailed)?;
+
+        let file_name = format!(
+            "{}_{}.eml",
+            Utc::now().format("%Y%m%d%H%M%S%f"),
+            mail.to.replace(['@', '/', '\\'], "_")
+        );
+        let path = self.dir.join(file_name);
+        let contents = format!(
+            "To: {}\nSubject: {}\n\n{}\n",
+            mail.to, mail.subject, mail.body
+        );
+
+        fs::write(&path, contents)
+            .await
+            .map_err(|_| Error::CreateFileFailed)?;
+
+        debug!("mail to {} written to {path:?}", mail.to);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_file_mailer_writes_mail() {
+        let dir = std::env::temp_dir().join(format!("flashcard_mails_{}", uuid::Uuid::new_v4()));
+        let mailer = FileMailer::new(dir.clone());
+
+        mailer
+            .send(Mail {
+                to: "user@example.com".into(),
+                subject: "Hello".into(),
+                body: "World".into(),
+            })
+            .await
+            .unwrap();
+
+        let mut entries = std::fs::read_dir(&dir).unwrap();
+        let written = std::fs::read_to_string(entries.next().unwrap().unwrap().path()).unwrap();
+        assert!(written.contains("To: user@example.com"));
+        assert!(written.contains("Subject: Hello"));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
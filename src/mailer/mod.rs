@@ -0,0 +1,39 @@
+use {
+    crate::{
+        config::{MailerConfig, MailerKind},
+        enums::error::*,
+    },
+    async_trait::async_trait,
+    file_mailer::FileMailer,
+    smtp_mailer::SmtpMailer,
+    std::sync::Arc,
+};
+
+pub mod file_mailer;
+pub mod smtp_mailer;
+pub mod templates;
+
+#[derive(Debug, Clone)]
+pub struct Mail {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, mail: Mail) -> Result<()>;
+}
+
+pub fn init_mailer(cfg: &MailerConfig) -> Result<Arc<dyn Mailer>> {
+    match cfg.kind {
+        MailerKind::File => Ok(Arc::new(FileMailer::new(cfg.dir.clone()))),
+        MailerKind::Smtp => {
+            let smtp = cfg
+                .smtp
+                .as_ref()
+                .ok_or(Error::FieldNotFound("mailer.smtp".into()))?;
+            Ok(Arc::new(SmtpMailer::try_new(smtp, &cfg.from)?))
+        }
+    }
+}
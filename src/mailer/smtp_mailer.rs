@@ -0,0 +1,55 @@
+use {
+    super::{Mail, Mailer},
+    crate::{config::SmtpConfig, enums::error::*},
+    async_trait::async_trait,
+    lettre::{
+        message::{header::ContentType, Mailbox},
+        transport::smtp::authentication::Credentials,
+        AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+    },
+};
+
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpMailer {
+    pub fn try_new(cfg: &SmtpConfig, from: &str) -> Result<Self> {
+        let transport = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&cfg.host)
+            .map_err(|e| Error::SendMailFailed(e.to_string()))?
+            .port(cfg.port)
+            .credentials(Credentials::new(cfg.username.clone(), cfg.password.clone()))
+            .build();
+        let from = from
+            .parse::<Mailbox>()
+            .map_err(|e| Error::SendMailFailed(e.to_string()))?;
+
+        Ok(Self { transport, from })
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, mail: Mail) -> Result<()> {
+        let to = mail
+            .to
+            .parse::<Mailbox>()
+            .map_err(|e| Error::SendMailFailed(e.to_string()))?;
+
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(to)
+            .subject(mail.subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(mail.body)
+            .map_err(|e| Error::SendMailFailed(e.to_string()))?;
+
+        self.transport
+            .send(message)
+            .await
+            .map_err(|e| Error::SendMailFailed(e.to_string()))?;
+
+        Ok(())
+    }
+}
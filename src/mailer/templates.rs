@@ -0,0 +1,58 @@
+use super::Mail;
+
+pub enum MailTemplate<'a> {
+    VerifyEmail { name: &'a str, link: &'a str },
+    ResetPassword { name: &'a str, link: &'a str },
+    InviteToSet { set_name: &'a str, link: &'a str },
+    InviteStaff { link: &'a str },
+}
+
+impl MailTemplate<'_> {
+    pub fn subject(&self) -> String {
+        match self {
+            Self::VerifyEmail { .. } => "Verify your Flashcard account".into(),
+            Self::ResetPassword { .. } => "Reset your Flashcard password".into(),
+            Self::InviteToSet { set_name, .. } => format!("You are invited to \"{set_name}\""),
+            Self::InviteStaff { .. } => "You are invited to join Flashcard staff".into(),
+        }
+    }
+
+    pub fn body(&self) -> String {
+        match self {
+            Self::VerifyEmail { name, link } => {
+                format!(
+                    "Hi {name},\n\nPlease confirm your email address by opening the link \
+                     below:\n\n{link}\n\nIf you did not create an account, you can ignore this \
+                     email."
+                )
+            }
+            Self::ResetPassword { name, link } => {
+                format!(
+                    "Hi {name},\n\nWe received a request to reset your password. Open the link \
+                     below to choose a new one:\n\n{link}\n\nIf you did not request this, you can \
+                     ignore this email."
+                )
+            }
+            Self::InviteToSet { set_name, link } => {
+                format!(
+                    "Hi,\n\nYou have been invited to collaborate on the set \"{set_name}\". Open \
+                     the link below to accept the invitation:\n\n{link}"
+                )
+            }
+            Self::InviteStaff { link } => {
+                format!(
+                    "Hi,\n\nYou have been invited to become a staff member. Sign in with this \
+                     email address and open the link below to accept:\n\n{link}"
+                )
+            }
+        }
+    }
+
+    pub fn into_mail(self, to: String) -> Mail {
+        Mail {
+            to,
+            subject: self.subject(),
+            body: self.body(),
+        }
+    }
+}
@@ -1,10 +1,16 @@
 use {
     anyhow::Result,
     clap::Parser,
-    config::{Cli, Config},
+    config::{Cli, Command, Config},
+    models::user::RegisterUserRequest,
     r#static::{init_blacklist_jwt, write_blacklist_jwt},
-    server::run_server,
-    std::fs,
+    server::{run_server, AppState},
+    std::{
+        env, fs,
+        io::{self, BufRead, Write},
+    },
+    utils::{jwt::init_jwt_keys, media_url::init_media_url, validator::init_password_policy},
+    validator::Validate,
 };
 
 pub mod config;
@@ -13,13 +19,18 @@ pub mod db;
 pub mod entities;
 pub mod enums;
 pub mod logger;
+pub mod mailer;
 pub mod middleware;
 pub mod models;
+pub mod oidc;
+pub mod openapi;
+pub mod rate_limit;
 pub mod repositories;
 pub mod routes;
 pub mod server;
 pub mod services;
 pub mod r#static;
+pub mod storage;
 pub mod tests;
 pub mod utils;
 
@@ -34,6 +45,25 @@ async fn main() -> Result<()> {
     let toml_file = fs::read_to_string(args.cfg)?;
     let cfg = Config::from_cfg(&toml_file)?;
 
+    init_jwt_keys(cfg.jwt.as_ref())?;
+    init_media_url(&cfg.storage)?;
+    init_password_policy(&cfg.auth.password_policy);
+
+    if let Some(Command::CreateStaff { email, name }) = args.command {
+        let payload = RegisterUserRequest {
+            email,
+            password: read_staff_password()?,
+            name,
+            avatar_url: None,
+        };
+        payload.validate()?;
+
+        let state = AppState::init(cfg).await?;
+        let user = state.user_service.create_staff(payload).await?;
+        debug!("created staff account: {}", user.email);
+        return Ok(());
+    }
+
     init_blacklist_jwt(&cfg.jwt_blacklist.path)?;
 
     tokio::select! {
@@ -43,3 +73,17 @@ async fn main() -> Result<()> {
 
     Ok(())
 }
+
+// Mật khẩu không nhận qua tham số để không lộ trong shell history và `ps`
+fn read_staff_password() -> Result<String> {
+    if let Ok(password) = env::var("STAFF_PASSWORD") {
+        return Ok(password);
+    }
+
+    print!("Password: ");
+    io::stdout().flush()?;
+    let mut password = String::new();
+    io::stdin().lock().read_line(&mut password)?;
+
+    Ok(password.trim_end_matches(['\r', '\n']).to_string())
+}
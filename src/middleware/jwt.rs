@@ -1,19 +1,40 @@
 use {
     crate::{
         enums::error::{Error, Result},
+        models::api_token::ApiTokenScope,
         r#static::BLACKLIST_TOKEN_VEC,
+        server::AppState,
         utils::jwt::decode_jwt,
     },
     axum::{
-        extract::Request,
-        http::{header::AUTHORIZATION, HeaderMap},
+        extract::{OriginalUri, Request, State},
+        http::{header::AUTHORIZATION, HeaderMap, Method},
         middleware::Next,
         response::IntoResponse,
     },
 };
 
+// Scope cần có để gọi request bằng API token, None là không cho phép
+fn required_scope(method: &Method, path: &str) -> Option<ApiTokenScope> {
+    if matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS) {
+        return Some(ApiTokenScope::Read);
+    }
+
+    // quản lý tài khoản và token chỉ làm qua session đăng nhập
+    if path.starts_with("/api/users") || path.starts_with("/api/auth") {
+        return None;
+    }
+
+    if path.starts_with("/api/test") {
+        return Some(ApiTokenScope::TestTake);
+    }
+
+    Some(ApiTokenScope::ContentWrite)
+}
+
 #[axum::debug_middleware]
 pub async fn check_jwt(
+    State(state): State<AppState>,
     headers: HeaderMap,
     mut request: Request,
     next: Next,
@@ -43,6 +64,52 @@ pub async fn check_jwt(
                 Err(_) => return Err(Error::InvalidCredentials),
             }
         }
+
+        // Personal API token: `Authorization: Token fcp_...`
+        if let Some(token) = auth.strip_prefix("Token ") {
+            let (claims, scopes) = state
+                .api_token_service
+                .authenticate(token.to_string())
+                .await?;
+
+            let path = request
+                .extensions()
+                .get::<OriginalUri>()
+                .map(|OriginalUri(uri)| uri.path().to_string())
+                .unwrap_or_else(|| request.uri().path().to_string());
+
+            match required_scope(request.method(), &path) {
+                Some(scope) if ApiTokenScope::allows(&scopes, scope) => {}
+                _ => return Err(Error::PermissionDenied),
+            }
+
+            request.extensions_mut().insert(claims);
+            request.extensions_mut().insert(token.to_string());
+            return Ok(next.run(request).await);
+        }
+    }
+    Err(Error::TokenNotFound)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn api_token_scope_by_route() {
+        assert_eq!(
+            required_scope(&Method::GET, "/api/users"),
+            Some(ApiTokenScope::Read)
+        );
+        assert_eq!(required_scope(&Method::POST, "/api/users/tokens"), None);
+        assert_eq!(required_scope(&Method::POST, "/api/auth/logout"), None);
+        assert_eq!(
+            required_scope(&Method::POST, "/api/test/1/submit"),
+            Some(ApiTokenScope::TestTake)
+        );
+        assert_eq!(
+            required_scope(&Method::PATCH, "/api/sets/1"),
+            Some(ApiTokenScope::ContentWrite)
+        );
     }
-    Err(Error::InvalidCredentials)
 }
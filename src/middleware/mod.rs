@@ -1 +1,3 @@
 pub mod jwt;
+pub mod rate_limit;
+pub mod request_id;
@@ -0,0 +1,51 @@
+use {
+    crate::{enums::error::Result, server::AppState},
+    axum::{
+        extract::{ConnectInfo, Request, State},
+        http::Method,
+        middleware::Next,
+        response::IntoResponse,
+    },
+    std::net::{IpAddr, Ipv4Addr, SocketAddr},
+};
+
+fn client_ip(request: &Request) -> IpAddr {
+    request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip())
+        .unwrap_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED))
+}
+
+// Giới hạn theo IP cho các endpoint auth (login, register, quên mật khẩu...)
+pub async fn limit_auth(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Result<impl IntoResponse> {
+    state
+        .rate_limiter
+        .check_auth_ip(client_ip(&request))
+        .await?;
+
+    Ok(next.run(request).await)
+}
+
+// Giới hạn theo IP cho các request ghi dữ liệu
+pub async fn limit_write(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Result<impl IntoResponse> {
+    if !matches!(
+        *request.method(),
+        Method::GET | Method::HEAD | Method::OPTIONS
+    ) {
+        state
+            .rate_limiter
+            .check_write_ip(client_ip(&request))
+            .await?;
+    }
+
+    Ok(next.run(request).await)
+}
@@ -0,0 +1,70 @@
+use {
+    crate::entities::api_tokens,
+    chrono::NaiveDateTime,
+    serde::{Deserialize, Serialize},
+    utoipa::ToSchema,
+    uuid::Uuid,
+    validator::Validate,
+};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiTokenScope {
+    // chỉ đọc
+    Read,
+    // tạo/sửa set, câu hỏi, quiz
+    ContentWrite,
+    // làm bài test
+    TestTake,
+}
+
+impl ApiTokenScope {
+    // mọi scope đều được đọc
+    pub fn allows(scopes: &[ApiTokenScope], required: ApiTokenScope) -> bool {
+        required == ApiTokenScope::Read || scopes.contains(&required)
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct CreateApiTokenRequest {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    #[validate(length(min = 1))]
+    pub scopes: Vec<ApiTokenScope>,
+    // không có thì token không hết hạn
+    #[validate(range(min = 1, max = 3650))]
+    pub expires_in_days: Option<i64>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ApiTokenModel {
+    pub id: Uuid,
+    pub name: String,
+    // vài ký tự đầu để user nhận ra token
+    pub prefix: String,
+    pub scopes: Vec<ApiTokenScope>,
+    pub last_used_at: Option<NaiveDateTime>,
+    pub expires_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+impl From<api_tokens::Model> for ApiTokenModel {
+    fn from(value: api_tokens::Model) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            prefix: value.prefix,
+            scopes: serde_json::from_value(value.scopes).unwrap_or_default(),
+            last_used_at: value.last_used_at,
+            expires_at: value.expires_at,
+            created_at: value.created_at,
+        }
+    }
+}
+
+// token chỉ trả về một lần lúc tạo
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CreatedApiTokenResponse {
+    pub token: String,
+    pub api_token: ApiTokenModel,
+}
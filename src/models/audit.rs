@@ -0,0 +1,111 @@
+use {
+    chrono::NaiveDateTime,
+    serde::{Deserialize, Serialize},
+    serde_json::Value,
+    utoipa::{IntoParams, ToSchema},
+    uuid::Uuid,
+};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+    Share,
+    Publish,
+    RoleChange,
+    Restore,
+    Purge,
+    Transfer,
+}
+
+impl AuditAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Create => "create",
+            Self::Update => "update",
+            Self::Delete => "delete",
+            Self::Share => "share",
+            Self::Publish => "publish",
+            Self::RoleChange => "role_change",
+            Self::Restore => "restore",
+            Self::Purge => "purge",
+            Self::Transfer => "transfer",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditResource {
+    Set,
+    Question,
+    Quiz,
+    QuizQuestion,
+    User,
+}
+
+impl AuditResource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Set => "set",
+            Self::Question => "question",
+            Self::Quiz => "quiz",
+            Self::QuizQuestion => "quiz_question",
+            Self::User => "user",
+        }
+    }
+}
+
+// Một thay đổi cần ghi lại, before/after là snapshot JSON của resource
+#[derive(Debug)]
+pub struct AuditEvent {
+    pub actor_id: Option<Uuid>,
+    pub action: AuditAction,
+    pub resource_type: AuditResource,
+    pub resource_id: Option<Uuid>,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+impl AuditEvent {
+    pub fn new(
+        actor_id: Option<Uuid>,
+        action: AuditAction,
+        resource_type: AuditResource,
+        resource_id: Option<Uuid>,
+    ) -> Self {
+        Self {
+            actor_id,
+            action,
+            resource_type,
+            resource_id,
+            before: None,
+            after: None,
+        }
+    }
+
+    pub fn before<T: Serialize>(mut self, value: &T) -> Self {
+        self.before = serde_json::to_value(value).ok();
+        self
+    }
+
+    pub fn after<T: Serialize>(mut self, value: &T) -> Self {
+        self.after = serde_json::to_value(value).ok();
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct QueryAuditParams {
+    pub actor_id: Option<Uuid>,
+    pub action: Option<AuditAction>,
+    pub resource_type: Option<AuditResource>,
+    pub resource_id: Option<Uuid>,
+    pub from: Option<NaiveDateTime>,
+    pub to: Option<NaiveDateTime>,
+    pub page: Option<u64>,
+    pub page_size: Option<u64>,
+}
@@ -0,0 +1,48 @@
+use {
+    crate::{
+        entities::{
+            change_requests,
+            sea_orm_active_enums::{ChangeRequestStatusEnum, ContentFormatEnum},
+        },
+        models::qna::{AnswerDTO, QuestionDiff},
+    },
+    serde::{Deserialize, Serialize},
+    utoipa::{IntoParams, ToSchema},
+    validator::Validate,
+};
+
+// Đề xuất sửa câu hỏi, field không gửi là giữ nguyên
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct CreateChangeRequest {
+    #[validate(length(min = 1, max = 5000))]
+    pub content: Option<String>,
+    pub content_format: Option<ContentFormatEnum>,
+    #[validate(length(min = 1), nested)]
+    pub answers: Option<Vec<AnswerDTO>>,
+    // lý do đề xuất
+    #[validate(length(max = 1000))]
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct ReviewChangeRequest {
+    #[validate(length(max = 1000))]
+    pub review_note: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct QueryChangeRequestParams {
+    pub status: Option<ChangeRequestStatusEnum>,
+    pub page: Option<u64>,
+    pub page_size: Option<u64>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ChangeRequestResponse {
+    #[serde(flatten)]
+    pub change_request: change_requests::Model,
+    pub diff: QuestionDiff,
+    // câu hỏi đã bị sửa sau khi đề xuất, chấp nhận sẽ ghi đè các thay đổi đó
+    pub is_outdated: bool,
+}
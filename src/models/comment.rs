@@ -0,0 +1,64 @@
+use {
+    crate::entities::comments,
+    serde::{Deserialize, Serialize},
+    utoipa::{IntoParams, ToSchema},
+    uuid::Uuid,
+    validator::Validate,
+};
+
+// Bình luận trên cả set hoặc trên một câu hỏi của set
+#[derive(Debug, Clone, Copy)]
+pub enum CommentTarget {
+    Set(Uuid),
+    Question(Uuid),
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct CreateCommentRequest {
+    #[validate(length(min = 1, max = 5000))]
+    pub content: String,
+    // trả lời một bình luận, trả lời của trả lời được gom vào bình luận gốc
+    pub parent_id: Option<Uuid>,
+    // góp ý sửa nội dung, có thể đánh dấu đã xử lý
+    #[serde(default)]
+    pub is_suggestion: bool,
+    // người được nhắc tới phải xem được set
+    #[serde(default)]
+    #[validate(length(max = 20))]
+    pub mention_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct UpdateCommentRequest {
+    #[validate(length(min = 1, max = 5000))]
+    pub content: Option<String>,
+    #[validate(length(max = 20))]
+    pub mention_ids: Option<Vec<Uuid>>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct ResolveCommentRequest {
+    pub is_resolved: bool,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct QueryCommentParams {
+    pub page: Option<u64>,
+    pub page_size: Option<u64>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CommentResponse {
+    #[serde(flatten)]
+    pub comment: comments::Model,
+    pub mention_ids: Vec<Uuid>,
+}
+
+// Bình luận gốc kèm các trả lời theo thứ tự thời gian
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CommentThread {
+    #[serde(flatten)]
+    pub comment: CommentResponse,
+    pub replies: Vec<CommentResponse>,
+}
@@ -0,0 +1,21 @@
+use {
+    crate::entities::sea_orm_active_enums::ContentFormatEnum,
+    serde::{Deserialize, Serialize},
+    utoipa::ToSchema,
+    validator::Validate,
+};
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct RenderContentRequest {
+    #[serde(default)]
+    pub format: ContentFormatEnum,
+    #[validate(length(max = 20000))]
+    pub content: String,
+}
+
+// HTML đã sanitize, công thức nằm trong `span.math` với delimiter `\(..\)` /
+// `\[..\]`
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RenderedContent {
+    pub html: String,
+}
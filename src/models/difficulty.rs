@@ -0,0 +1,16 @@
+use {serde::Serialize, utoipa::ToSchema};
+
+// Khoảng độ khó khi chọn câu hỏi, câu chưa được ước lượng bị loại nếu có cận
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DifficultyRange {
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+}
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct CalibrationReport {
+    // số câu trả lời đã chấm được dùng để ước lượng
+    pub attempts: u64,
+    pub questions: u64,
+    pub quiz_questions: u64,
+}
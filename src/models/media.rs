@@ -0,0 +1,56 @@
+use {
+    serde::{Deserialize, Serialize},
+    utoipa::{IntoParams, ToSchema},
+    uuid::Uuid,
+};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MediaKind {
+    Image,
+    Audio,
+}
+
+impl MediaKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Image => "image",
+            Self::Audio => "audio",
+        }
+    }
+
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        if content_type.starts_with("image/") {
+            Some(Self::Image)
+        } else if content_type.starts_with("audio/") {
+            Some(Self::Audio)
+        } else {
+            None
+        }
+    }
+}
+
+// Metadata của file kèm link tải đã ký
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MediaResponse {
+    pub id: Uuid,
+    pub kind: String,
+    pub content_type: String,
+    pub size: i64,
+    pub url: String,
+    // unix timestamp, 0 là link không hết hạn (avatar)
+    pub expires: i64,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SignedUrlParams {
+    pub expires: i64,
+    pub signature: String,
+}
+
+// Nội dung file trả về cho link tải
+pub struct MediaContent {
+    pub content_type: String,
+    pub data: Vec<u8>,
+}
@@ -1,6 +1,17 @@
+pub mod api_token;
+pub mod audit;
+pub mod change_request;
+pub mod comment;
+pub mod content;
+pub mod difficulty;
+pub mod media;
+pub mod note;
+pub mod ownership;
 pub mod qna;
 pub mod quiz;
 pub mod quiz_question;
 pub mod set;
+pub mod tag;
 pub mod test;
+pub mod trash;
 pub mod user;
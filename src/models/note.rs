@@ -0,0 +1,28 @@
+use {
+    serde::Deserialize,
+    utoipa::{IntoParams, ToSchema},
+    uuid::Uuid,
+    validator::Validate,
+};
+
+// Ghi chú gắn với câu hỏi trong set hoặc với câu hỏi của quiz
+#[derive(Debug, Clone, Copy)]
+pub enum NoteTarget {
+    Question(Uuid),
+    QuizQuestion(Uuid),
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct SaveNoteRequest {
+    #[validate(length(min = 1, max = 10000))]
+    pub content: String,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct QueryNoteParams {
+    // tìm trong nội dung ghi chú, không phân biệt hoa thường
+    pub q: Option<String>,
+    pub page: Option<u64>,
+    pub page_size: Option<u64>,
+}
@@ -0,0 +1,27 @@
+use {
+    serde::{Deserialize, Serialize},
+    utoipa::{IntoParams, ToSchema},
+    uuid::Uuid,
+    validator::Validate,
+};
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct TransferOwnershipRequest {
+    pub to_user_id: Uuid,
+    // chủ cũ được share lại với quyền Edit (quiz chỉ có một mức share)
+    #[serde(default)]
+    pub keep_previous_owner: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TransferAllResponse {
+    pub set_ids: Vec<Uuid>,
+    pub quiz_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct QueryTransferParams {
+    pub page: Option<u64>,
+    pub page_size: Option<u64>,
+}
@@ -1,42 +1,71 @@
 use {
-    crate::entities::sea_orm_active_enums::QuestionTypeEnum,
+    crate::{
+        entities::{
+            question_revisions,
+            sea_orm_active_enums::{ContentFormatEnum, QuestionTypeEnum},
+        },
+        utils::validator::validate_qna_answers,
+    },
     serde::{Deserialize, Serialize},
+    utoipa::{IntoParams, ToSchema},
     uuid::Uuid,
+    validator::Validate,
 };
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ToSchema, Validate)]
 pub struct AnswerDTO {
+    #[validate(length(min = 1, max = 2000))]
     pub content: String,
     pub is_answer: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub media_id: Option<Uuid>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+#[validate(schema(function = "validate_qna_answers"))]
 pub struct CreateQnARequest {
+    #[validate(length(min = 1, max = 5000))]
     pub content: String,
+    // mặc định là văn bản thường
+    #[serde(default)]
+    pub content_format: ContentFormatEnum,
     pub r#type: QuestionTypeEnum,
+    #[validate(length(min = 1), nested)]
     pub answers: Vec<AnswerDTO>,
     pub set_id: Uuid,
+    // ảnh/audio đã upload qua /api/media
+    pub media_id: Option<Uuid>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 pub struct UpdateQuestionRequest {
+    #[validate(length(min = 1, max = 5000))]
     pub content: Option<String>,
+    pub content_format: Option<ContentFormatEnum>,
+    #[validate(length(min = 1), nested)]
     pub answers: Option<Vec<AnswerDTO>>,
+    pub media_id: Option<Uuid>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct QueryQuestionParams {
     pub content: Option<String>,
     pub r#type: Option<String>,
     pub set_id: Uuid, // force
     pub creator_id: Option<Uuid>,
+    // id tag cách nhau bởi dấu phẩy, khớp cả tag con và tag của set
+    pub tag_ids: Option<String>,
+    // thang logit, 0 là trung bình, câu chưa được ước lượng bị loại
+    pub min_difficulty: Option<f64>,
+    pub max_difficulty: Option<f64>,
     pub sort_by: Option<String>,
     pub sort_direction: Option<String>,
     pub page: Option<u64>,
     pub page_size: Option<u64>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct QnAResponse {
     pub id: Uuid,
     pub content: String,
@@ -44,3 +73,55 @@ pub struct QnAResponse {
     pub answers: Vec<AnswerDTO>,
     pub set_id: Uuid,
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+pub enum AnswerChangeKind {
+    Added,
+    Removed,
+    Updated,
+    Unchanged,
+}
+
+// Đáp án được ghép theo nội dung, `Updated` là cùng nội dung nhưng đổi đúng/sai
+// hoặc media
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct AnswerChange {
+    pub kind: AnswerChangeKind,
+    pub before: Option<AnswerDTO>,
+    pub after: Option<AnswerDTO>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TextChange {
+    pub before: String,
+    pub after: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ContentFormatChange {
+    pub before: ContentFormatEnum,
+    pub after: ContentFormatEnum,
+}
+
+// Thay đổi giữa hai bản của câu hỏi, field null là không đổi
+#[derive(Debug, Serialize, ToSchema)]
+pub struct QuestionDiff {
+    pub content: Option<TextChange>,
+    pub content_format: Option<ContentFormatChange>,
+    pub answers: Option<Vec<AnswerChange>>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct QueryRevisionParams {
+    pub page: Option<u64>,
+    pub page_size: Option<u64>,
+}
+
+// Revision kèm diff với revision liền trước, revision đầu tiên không có diff
+#[derive(Debug, Serialize, ToSchema)]
+pub struct QuestionRevisionResponse {
+    #[serde(flatten)]
+    pub revision: question_revisions::Model,
+    pub diff: Option<QuestionDiff>,
+}
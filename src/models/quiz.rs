@@ -1,30 +1,41 @@
 use {
     crate::entities::quizes,
     serde::{Deserialize, Serialize},
+    utoipa::{IntoParams, ToSchema},
     uuid::Uuid,
+    validator::Validate,
 };
 
-#[derive(Debug, Deserialize, Serialize, Default)]
+#[derive(Debug, Deserialize, Serialize, Default, ToSchema, Validate)]
 pub struct QuestionCounts {
+    #[validate(range(min = 0))]
     pub multiple_choices: i32,
+    #[validate(range(min = 0))]
     pub check_boxes: i32,
+    #[validate(range(min = 0))]
     pub text_fill: i32,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 pub struct CreateQuizRequest {
+    #[validate(length(min = 1, max = 255))]
     pub name: Option<String>,
     pub is_public: bool,
+    #[validate(range(min = 1))]
     pub duration: i32,
 }
 
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Deserialize, Default, ToSchema, Validate)]
 pub struct UpdateQuizRequest {
+    #[validate(length(min = 1, max = 255))]
     pub name: Option<String>,
     pub is_public: Option<bool>,
     pub is_publish: Option<bool>,
+    #[validate(nested)]
     pub question_counts: Option<QuestionCounts>,
+    #[validate(range(min = 0))]
     pub total_point: Option<i32>,
+    #[validate(range(min = 1))]
     pub duration: Option<i32>,
 }
 
@@ -36,18 +47,21 @@ pub struct UpdateQuizRequest {
 //     pub shared: Option<()>,
 // }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct FilterQuizParams {
     pub name: Option<String>,
     pub creator_id: Option<Uuid>,
     pub visibility: Option<Vec<String>>,
+    // quiz có câu hỏi lấy từ câu hỏi gắn một trong các tag (cách nhau bởi dấu phẩy)
+    pub tag_ids: Option<String>,
     pub sort_by: Option<String>,
     pub sort_direction: Option<String>,
     pub page: Option<u64>,
     pub page_size: Option<u64>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct QuizWithVisibility {
     pub quiz: quizes::Model,
     pub visibility: Vec<String>,
@@ -1,13 +1,18 @@
 use {
     crate::entities::{
-        questions, quiz_question_answers, quiz_questions, sea_orm_active_enums::QuestionTypeEnum,
+        questions, quiz_question_answers, quiz_questions,
+        sea_orm_active_enums::{ContentFormatEnum, QuestionTypeEnum},
     },
+    chrono::NaiveDateTime,
     serde::{Deserialize, Serialize},
+    utoipa::ToSchema,
     uuid::Uuid,
+    validator::Validate,
 };
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema, Validate)]
 pub struct CreateQuizQuestionAnswer {
+    #[validate(length(min = 1, max = 2000))]
     pub content: String,
     pub is_answer: bool,
 }
@@ -21,14 +26,22 @@ impl From<quiz_question_answers::Model> for CreateQuizQuestionAnswer {
     }
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, ToSchema, Validate)]
 pub struct CreateQuizQuestionRequest {
+    #[validate(length(min = 1, max = 5000))]
     pub question_content: String,
+    // áp dụng cho cả question_content và explaination
+    #[serde(default)]
+    pub content_format: ContentFormatEnum,
+    #[validate(length(min = 1), nested)]
     pub answers: Vec<CreateQuizQuestionAnswer>,
     pub r#type: QuestionTypeEnum,
     pub sample_id: Option<Uuid>,
+    #[validate(range(min = 0))]
     pub point: i32,
+    #[validate(range(min = -1))]
     pub index: i32,
+    #[validate(length(max = 5000))]
     pub explaination: Option<String>,
 }
 
@@ -39,6 +52,7 @@ impl From<questions::Model> for CreateQuizQuestionRequest {
 
         Self {
             question_content: value.content,
+            content_format: value.content_format,
             answers,
             r#type: value.r#type,
             sample_id: Some(value.id),
@@ -49,17 +63,47 @@ impl From<questions::Model> for CreateQuizQuestionRequest {
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 pub struct CreateQuizQuestionFromQuestion {
     pub quiz_id: Uuid,
+    #[validate(length(min = 1))]
     pub question_ids: Vec<Uuid>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+// Lấy ngẫu nhiên `count` câu hỏi gắn tag trong các set xem được
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct CreateQuizQuestionFromTags {
+    pub quiz_id: Uuid,
+    #[validate(length(min = 1, max = 20))]
+    pub tag_ids: Vec<Uuid>,
+    #[validate(range(min = 1, max = 100))]
+    pub count: u64,
+    pub r#type: Option<QuestionTypeEnum>,
+    // độ khó ước lượng từ lịch sử làm bài, câu chưa có ước lượng bị loại
+    pub min_difficulty: Option<f64>,
+    pub max_difficulty: Option<f64>,
+}
+
+// Các câu người gọi từng làm sai, mỗi câu hỏi gốc chỉ lấy một lần
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct CreateQuizQuestionFromMistakes {
+    pub quiz_id: Uuid,
+    // chỉ các câu có câu hỏi gốc thuộc set này
+    pub set_id: Option<Uuid>,
+    // lần làm sai nằm trong khoảng thời gian này
+    pub from: Option<NaiveDateTime>,
+    pub to: Option<NaiveDateTime>,
+    // mặc định lấy hết, câu sai gần đây trước
+    #[validate(range(min = 1, max = 100))]
+    pub count: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
 pub struct UpdateQuizQuestionAnswer {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<Uuid>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 1, max = 2000))]
     pub content: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_answer: Option<bool>,
@@ -67,24 +111,37 @@ pub struct UpdateQuizQuestionAnswer {
     pub quiz_question_id: Option<Uuid>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
 pub struct UpdateQuizQuestionContent {
+    #[validate(length(min = 1, max = 5000))]
     pub question_content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_format: Option<ContentFormatEnum>,
     #[serde(skip_serializing)]
+    #[validate(nested)]
     pub answers: Option<Vec<UpdateQuizQuestionAnswer>>,
     pub r#type: Option<QuestionTypeEnum>,
+    #[validate(range(min = 0))]
     pub point: Option<i32>,
+    #[validate(range(min = 0))]
     pub index: Option<i32>,
+    // khi update, payload được map thẳng vào cột `explanation`
+    #[serde(
+        rename(serialize = "explanation"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[validate(length(max = 5000))]
     pub explaination: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 pub struct UpdateQuizQuestionRequest {
     pub question_id: Uuid,
+    #[validate(nested)]
     pub content: UpdateQuizQuestionContent,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct QuizQuestionResponse {
     pub question: quiz_questions::Model,
     pub answers: Vec<quiz_question_answers::Model>,
@@ -1,10 +1,12 @@
 use {
     crate::entities::sets,
     serde::{Deserialize, Serialize},
+    utoipa::ToSchema,
     uuid::Uuid,
+    validator::Validate,
 };
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Default, ToSchema)]
 pub enum SharedPermission {
     #[default]
     View = 0,
@@ -23,33 +25,57 @@ impl From<i32> for SharedPermission {
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 pub struct CreateSetRequest {
+    #[validate(length(min = 1, max = 255))]
     pub name: String,
+    #[validate(length(max = 2000))]
     pub description: Option<String>,
     pub public_or_not: Option<bool>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 pub struct UpdateSetRequest {
+    #[validate(length(min = 1, max = 255))]
     pub name: Option<String>,
+    #[validate(length(max = 2000))]
     pub description: Option<String>,
     pub public_or_not: Option<bool>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 pub struct ShareSetForUser {
     pub user_id: Uuid,
     pub permission: Option<SharedPermission>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct InviteToSetRequest {
+    #[validate(email)]
+    pub email: String,
+    pub permission: Option<SharedPermission>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct AcceptInviteRequest {
+    #[validate(length(min = 1))]
+    pub token: String,
+}
+
+// Lưu trong user_tokens.payload của token invite
+#[derive(Debug, Deserialize, Serialize)]
+pub struct InvitePayload {
+    pub set_id: Uuid,
+    pub permission: i32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
 pub struct SharedSetsWithPermission {
     pub set: sets::Model,
     pub permission: SharedPermission,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct AllSetsOfUserResponse {
     pub own_sets: Vec<sets::Model>,
     pub shared_sets: Vec<SharedSetsWithPermission>,
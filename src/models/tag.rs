@@ -0,0 +1,49 @@
+use {
+    serde::{Deserialize, Deserializer, Serialize},
+    utoipa::ToSchema,
+    uuid::Uuid,
+    validator::Validate,
+};
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct CreateTagRequest {
+    #[validate(length(min = 1, max = 50))]
+    pub name: String,
+    // tag cha trong cây chủ đề
+    pub parent_id: Option<Uuid>,
+}
+
+// Phân biệt không gửi `parent_id` (giữ nguyên) với `parent_id: null` (thành tag
+// gốc)
+fn some_or_null<'de, D>(deserializer: D) -> Result<Option<Option<Uuid>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<Uuid>::deserialize(deserializer).map(Some)
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct UpdateTagRequest {
+    #[validate(length(min = 1, max = 50))]
+    pub name: Option<String>,
+    #[serde(default, deserialize_with = "some_or_null")]
+    #[schema(value_type = Option<Uuid>)]
+    pub parent_id: Option<Option<Uuid>>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct SetTagsRequest {
+    #[validate(length(max = 20))]
+    pub tag_ids: Vec<Uuid>,
+}
+
+// Tỉ lệ đúng của user trên các câu hỏi thuộc tag, tính cả tag con
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TagAccuracy {
+    pub tag_id: Uuid,
+    pub name: String,
+    pub parent_id: Option<Uuid>,
+    pub answered: u64,
+    pub correct: u64,
+    pub accuracy: f64,
+}
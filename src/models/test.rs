@@ -2,20 +2,29 @@ use {
     super::quiz_question::QuizQuestionResponse,
     crate::entities::{
         quiz_question_answers,
-        sea_orm_active_enums::{QuestionTypeEnum, StatusEnum},
+        sea_orm_active_enums::{ContentFormatEnum, QuestionTypeEnum, StatusEnum},
         test_answers, test_question_results, tests,
     },
     chrono::NaiveDateTime,
     serde::{Deserialize, Serialize},
+    utoipa::{IntoParams, ToSchema},
     uuid::Uuid,
+    validator::Validate,
 };
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 pub struct CreateTest {
     pub quiz_id: Uuid,
+    // câu tiếp theo được chọn theo năng lực ước lượng sau mỗi câu trả lời
+    #[serde(default)]
+    pub is_adaptive: bool,
+    // trả lời xong biết ngay đúng sai kèm lời giải, được làm lại, không tính vào
+    // thống kê
+    #[serde(default)]
+    pub is_practice: bool,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct CreateTestResponse {
     pub id: Uuid,
     pub max_duration: i32,
@@ -24,7 +33,7 @@ pub struct CreateTestResponse {
     pub created_at: NaiveDateTime,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct TestingQuiz {
     pub id: Uuid,
     pub name: String,
@@ -32,14 +41,14 @@ pub struct TestingQuiz {
     pub set_name: String,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct CurrentTestState {
     pub current_question_id: Uuid,
     pub completed_questions: i32,
     pub spent_time_in_second: i32,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct TestResponse {
     pub id: Uuid,
     pub quiz: TestingQuiz,
@@ -53,7 +62,8 @@ pub struct TestResponse {
     pub current_state: Option<CurrentTestState>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct QueryTestParams {
     pub sort_by: Option<String>,
     pub sort_order: Option<String>,
@@ -69,24 +79,41 @@ pub struct UpdateTest {
     pub resolved_count: Option<i32>,
     pub remaining_time: Option<i32>,
     pub score: Option<i32>,
+    pub session_id: Option<Uuid>,
+    pub ability: Option<f64>,
+    pub ability_se: Option<f64>,
 }
 
-#[derive(Debug, Serialize)]
+// Một bước của bài test thích ứng, ghi cùng transaction với câu trả lời
+#[derive(Debug)]
+pub struct AdaptiveStep {
+    pub step: i32,
+    pub quiz_question_id: Uuid,
+    pub difficulty: f64,
+    pub is_correct: bool,
+    pub ability: f64,
+    pub ability_se: f64,
+    // câu được chọn tiếp theo, None khi bài test dừng
+    pub next_quiz_question_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
 pub struct TestingAnswer {
     pub id: Uuid,
     pub content: String,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct TestingQuestion {
     pub id: Uuid,
     pub content: String,
+    pub content_format: ContentFormatEnum,
     pub r#type: QuestionTypeEnum,
     pub answers: Vec<TestingAnswer>,
     pub user_answers: Vec<test_answers::Model>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct StartTestResponse {
     pub id: Uuid,
     pub started_at: NaiveDateTime,
@@ -110,6 +137,7 @@ impl From<QuizQuestionResponse> for TestingQuestion {
         Self {
             id: question.id,
             content: question.question_content,
+            content_format: question.content_format,
             r#type: question.r#type,
             answers: answers.into_iter().map(Into::into).collect(),
             user_answers: vec![],
@@ -117,28 +145,51 @@ impl From<QuizQuestionResponse> for TestingQuestion {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
 pub struct SaveTestAnswer {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<Uuid>,
     pub selected_answer_id: Option<Uuid>,
+    #[validate(length(max = 5000))]
     pub text_answer: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 0))]
     pub spent_time_in_second: Option<i32>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
 pub struct ResolveTestRequest {
+    #[validate(nested)]
     pub save_test_answers: Vec<SaveTestAnswer>,
+    #[validate(range(min = 0))]
     pub remaining_time: i32,
+    // revision and session the client last saw, a mismatch means another
+    // device has written since
+    pub revision: i32,
+    pub session_id: Uuid,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ResolveResponse {
     pub next_question_id: Option<Uuid>,
 }
 
-#[derive(Debug, Serialize)]
+// Đánh dấu câu để quay lại sau
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct FlagQuestionRequest {
+    pub is_flagged: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ResolveTestResponse {
+    #[serde(flatten)]
+    pub test: tests::Model,
+    // chỉ có với bài luyện tập
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feedback: Option<SolutionResponse>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
 pub struct SolutionResponse {
     pub solution: QuizQuestionResponse,
     pub text_answer: Option<String>,
@@ -147,7 +198,7 @@ pub struct SolutionResponse {
     pub spent_time: i32,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ResultResponse {
     pub test: tests::Model,
     pub result: Vec<test_question_results::Model>,
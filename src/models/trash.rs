@@ -0,0 +1,23 @@
+use {
+    crate::entities::{questions, quizes, sets},
+    serde::Serialize,
+    utoipa::ToSchema,
+};
+
+// Nội dung đã xoá mềm của user, bị xoá hẳn sau `retention_days` ngày
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TrashResponse {
+    pub retention_days: i64,
+    pub sets: Vec<sets::Model>,
+    // chỉ những câu hỏi bị xoá riêng lẻ, câu hỏi xoá theo set nằm trong set
+    pub questions: Vec<questions::Model>,
+    pub quizzes: Vec<quizes::Model>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct PurgeReport {
+    pub sets: u64,
+    pub questions: u64,
+    pub quizzes: u64,
+    pub quiz_questions: u64,
+}
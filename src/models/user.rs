@@ -1,60 +1,180 @@
 use {
-    crate::entities::{sea_orm_active_enums::RoleEnum, users},
+    crate::{
+        entities::{sea_orm_active_enums::RoleEnum, users},
+        utils::{media_url::signed_url, validator::validate_password},
+    },
     serde::{Deserialize, Serialize},
+    utoipa::ToSchema,
     uuid::Uuid,
+    validator::Validate,
 };
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 pub struct RegisterUserRequest {
+    #[validate(email)]
     pub email: String,
+    #[validate(custom(function = "validate_password"))]
     pub password: String,
+    #[validate(length(min = 1, max = 100))]
     pub name: String,
-    pub role: RoleEnum,
+    #[validate(url)]
     pub avatar_url: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 pub struct LoginRequest {
+    #[validate(email)]
     pub email: String,
+    #[validate(length(min = 1))]
     pub password: String,
 }
 
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Deserialize, Default, ToSchema, Validate)]
 pub struct UpdateUserRequest {
     // for update info self
+    #[validate(length(min = 1, max = 100))]
     pub name: Option<String>,
+    #[validate(email)]
     pub email: Option<String>,
+    #[validate(url)]
     pub avatar_url: Option<String>,
 
+    #[validate(custom(function = "validate_password"))]
     pub password: Option<String>,
     // only staff update role
     pub role: Option<RoleEnum>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 pub struct UpdateUserPassword {
+    #[validate(length(min = 1))]
     pub old_password: String,
+    #[validate(custom(function = "validate_password"))]
     pub new_password: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 pub struct UpdateUserRole {
     pub user_id: Uuid,
     pub new_role: RoleEnum,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct VerifyEmailRequest {
+    #[validate(length(min = 1))]
+    pub token: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct EmailRequest {
+    #[validate(email)]
+    pub email: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct ResetPasswordRequest {
+    #[validate(length(min = 1))]
+    pub token: String,
+    #[validate(custom(function = "validate_password"))]
+    pub new_password: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct InviteStaffRequest {
+    #[validate(email)]
+    pub email: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct AcceptStaffInviteRequest {
+    #[validate(length(min = 1))]
+    pub token: String,
+}
+
+// Lưu trong user_tokens.payload của token invite staff
+#[derive(Debug, Deserialize, Serialize)]
+pub struct StaffInvitePayload {
+    pub inviter_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct TotpCodeRequest {
+    #[validate(length(min = 1, max = 32))]
+    pub code: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct TotpChallengeRequest {
+    #[validate(length(min = 1))]
+    pub challenge_token: String,
+}
+
+// code là mã TOTP hoặc một recovery code
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct LoginTotpRequest {
+    #[validate(length(min = 1))]
+    pub challenge_token: String,
+    #[validate(length(min = 1, max = 32))]
+    pub code: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct ResetTotpRequest {
+    pub user_id: Uuid,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TotpSetupResponse {
+    pub secret: String,
+    // otpauth:// uri, client render thành QR code
+    pub provisioning_uri: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum LoginResponse {
+    Authenticated {
+        token: String,
+        // chỉ có khi vừa bật TOTP trong lúc login
+        #[serde(skip_serializing_if = "Option::is_none")]
+        recovery_codes: Option<Vec<String>>,
+    },
+    TotpRequired {
+        challenge_token: String,
+    },
+    // role bắt buộc 2FA nhưng user chưa bật
+    TotpSetupRequired {
+        challenge_token: String,
+    },
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+pub struct OidcCallbackRequest {
+    #[validate(length(min = 1))]
+    pub code: String,
+    #[validate(length(min = 1))]
+    pub state: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OidcAuthorizeResponse {
+    pub authorization_url: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
 pub struct DeleteRequest {
     pub user_id: Uuid,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct UserModel {
     pub id: Uuid,
     pub email: String,
     pub name: String,
     pub role: RoleEnum,
     pub avatar_url: Option<String>,
+    pub is_verified: bool,
+    pub totp_enabled: bool,
 }
 
 impl From<users::Model> for UserModel {
@@ -64,7 +184,14 @@ impl From<users::Model> for UserModel {
             email: value.email,
             name: value.name,
             role: value.role,
-            avatar_url: value.avatar_url,
+            // link avatar upload có hạn nên ký lại mỗi lần đọc
+            avatar_url: value
+                .avatar_media_id
+                .and_then(|id| signed_url(id).ok())
+                .map(|(url, _)| url)
+                .or(value.avatar_url),
+            is_verified: value.is_verified,
+            totp_enabled: value.totp_enabled,
         }
     }
 }
@@ -0,0 +1,139 @@
+use {
+    crate::{
+        config::OidcConfig,
+        enums::error::{Error, Result},
+    },
+    openidconnect::{
+        core::{CoreAuthenticationFlow, CoreClient, CoreProviderMetadata},
+        reqwest, AuthorizationCode, ClientId, ClientSecret, CsrfToken, IssuerUrl, Nonce,
+        PkceCodeChallenge, PkceCodeVerifier, RedirectUrl, Scope, TokenResponse,
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+// Dữ liệu cần lưu lại giữa lúc redirect sang IdP và lúc callback
+#[derive(Debug)]
+pub struct AuthorizeRequest {
+    pub url: String,
+    pub state: String,
+    pub nonce: String,
+    pub pkce_verifier: String,
+}
+
+// Danh tính user do IdP xác nhận trong ID token
+#[derive(Debug)]
+pub struct ExternalIdentity {
+    pub issuer: String,
+    pub subject: String,
+    pub email: Option<String>,
+    pub email_verified: bool,
+    pub name: Option<String>,
+}
+
+pub struct OidcClient {
+    cfg: OidcConfig,
+    http_client: reqwest::Client,
+}
+
+fn oidc_error(e: impl std::fmt::Display) -> Error {
+    Error::OidcFailed(e.to_string())
+}
+
+impl OidcClient {
+    pub fn try_new(cfg: OidcConfig) -> Result<Self> {
+        // không follow redirect để tránh SSRF
+        let http_client = reqwest::ClientBuilder::new()
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .map_err(oidc_error)?;
+
+        Ok(Self { cfg, http_client })
+    }
+
+    // Discovery mỗi lần gọi để luôn có JWKS mới nhất khi IdP xoay key
+    async fn client(
+        &self,
+    ) -> Result<
+        CoreClient<
+            openidconnect::EndpointSet,
+            openidconnect::EndpointNotSet,
+            openidconnect::EndpointNotSet,
+            openidconnect::EndpointNotSet,
+            openidconnect::EndpointMaybeSet,
+            openidconnect::EndpointMaybeSet,
+        >,
+    > {
+        let issuer_url = IssuerUrl::new(self.cfg.issuer_url.clone()).map_err(oidc_error)?;
+        let metadata = CoreProviderMetadata::discover_async(issuer_url, &self.http_client)
+            .await
+            .map_err(oidc_error)?;
+
+        Ok(CoreClient::from_provider_metadata(
+            metadata,
+            ClientId::new(self.cfg.client_id.clone()),
+            self.cfg.client_secret.clone().map(ClientSecret::new),
+        )
+        .set_redirect_uri(RedirectUrl::new(self.cfg.redirect_url.clone()).map_err(oidc_error)?))
+    }
+
+    pub async fn authorize(&self) -> Result<AuthorizeRequest> {
+        let client = self.client().await?;
+        let (pkce_challenge, pkce_verifier) = PkceCodeChallenge::new_random_sha256();
+
+        let mut request = client
+            .authorize_url(
+                CoreAuthenticationFlow::AuthorizationCode,
+                CsrfToken::new_random,
+                Nonce::new_random,
+            )
+            .set_pkce_challenge(pkce_challenge);
+        for scope in &self.cfg.scopes {
+            request = request.add_scope(Scope::new(scope.clone()));
+        }
+        let (url, state, nonce) = request.url();
+
+        Ok(AuthorizeRequest {
+            url: url.to_string(),
+            state: state.secret().clone(),
+            nonce: nonce.secret().clone(),
+            pkce_verifier: pkce_verifier.secret().clone(),
+        })
+    }
+
+    pub async fn exchange(
+        &self,
+        code: String,
+        pkce_verifier: String,
+        nonce: String,
+    ) -> Result<ExternalIdentity> {
+        let client = self.client().await?;
+
+        let token_response = client
+            .exchange_code(AuthorizationCode::new(code))
+            .map_err(oidc_error)?
+            .set_pkce_verifier(PkceCodeVerifier::new(pkce_verifier))
+            .request_async(&self.http_client)
+            .await
+            .map_err(oidc_error)?;
+
+        let id_token = token_response
+            .id_token()
+            .ok_or(Error::OidcFailed("missing id token".into()))?;
+        let claims = id_token
+            .claims(&client.id_token_verifier(), &Nonce::new(nonce))
+            .map_err(oidc_error)?;
+
+        Ok(ExternalIdentity {
+            issuer: claims.issuer().to_string(),
+            subject: claims.subject().to_string(),
+            email: claims.email().map(|email| email.to_string()),
+            email_verified: claims.email_verified().unwrap_or(false),
+            name: claims
+                .name()
+                .and_then(|name| name.get(None))
+                .map(|name| name.to_string()),
+        })
+    }
+}
@@ -0,0 +1,180 @@
+// Chạy flow authorization code + PKCE với một IdP giả lập chạy local
+use {
+    super::*,
+    axum::{
+        extract::State,
+        routing::{get, post},
+        Form, Json, Router,
+    },
+    chrono::Utc,
+    jsonwebtoken::{encode, Algorithm, EncodingKey, Header},
+    openidconnect::{PkceCodeChallenge, PkceCodeVerifier},
+    parking_lot::Mutex,
+    serde_json::{json, Value},
+    std::{collections::HashMap, sync::Arc},
+    tokio::net::TcpListener,
+};
+
+const TEST_KEY: &str = include_str!("test_key.pem");
+// modulus của TEST_KEY (base64url), exponent là 65537
+const TEST_KEY_N: &str = "igpAzyoFPOcxpkMoaTh1vygh92xpL1fxR927dWPKW9QKTLzdl0pJPADwJlUQP-SgtNlFiiiSlydZtxMI7WRH7BxRRzUW_V49AlUAkuqH2GBQ45wiB5T_hAH_u-GK3g7bXWMMqIxeq35vOZtwQYoVKQtJfbS84HN03wMYbG2_cFXwsBywFrvI6PKXfV-OdhG8IqZdF8Gowxj7H-C63GqVDk6ZLTE9lNyZXO8yHTBqggHpNmO6fXmt8mosPQ5QOLBW1O3QsodG-XleM8AKNHoLpvvNP5fC0f0UmwXYtys5EYaeLNiuk9jpHLesgIOJzjG9zWGjFUqw6X59OKSkbeq3cw";
+const CLIENT_ID: &str = "flashcard";
+const CODE: &str = "mock-code";
+
+#[derive(Clone, Default)]
+struct MockIdp {
+    issuer: String,
+    // nonce và code_challenge lấy từ authorization url
+    nonce: Arc<Mutex<String>>,
+    code_challenge: Arc<Mutex<String>>,
+}
+
+async fn discovery(State(idp): State<MockIdp>) -> Json<Value> {
+    Json(json!({
+        "issuer": idp.issuer,
+        "authorization_endpoint": format!("{}/authorize", idp.issuer),
+        "token_endpoint": format!("{}/token", idp.issuer),
+        "jwks_uri": format!("{}/jwks", idp.issuer),
+        "response_types_supported": ["code"],
+        "subject_types_supported": ["public"],
+        "id_token_signing_alg_values_supported": ["RS256"],
+    }))
+}
+
+async fn jwks() -> Json<Value> {
+    Json(json!({
+        "keys": [{
+            "kty": "RSA",
+            "use": "sig",
+            "alg": "RS256",
+            "kid": "test",
+            "n": TEST_KEY_N,
+            "e": "AQAB",
+        }]
+    }))
+}
+
+async fn token(
+    State(idp): State<MockIdp>,
+    Form(form): Form<HashMap<String, String>>,
+) -> (axum::http::StatusCode, Json<Value>) {
+    let verifier = PkceCodeVerifier::new(form.get("code_verifier").cloned().unwrap_or_default());
+    let challenge = PkceCodeChallenge::from_code_verifier_sha256(&verifier);
+    if form.get("code").map(String::as_str) != Some(CODE)
+        || challenge.as_str() != idp.code_challenge.lock().as_str()
+    {
+        return (
+            axum::http::StatusCode::BAD_REQUEST,
+            Json(json!({ "error": "invalid_grant" })),
+        );
+    }
+
+    let now = Utc::now().timestamp();
+    let claims = json!({
+        "iss": idp.issuer,
+        "sub": "idp-user-1",
+        "aud": CLIENT_ID,
+        "exp": now + 300,
+        "iat": now,
+        "nonce": idp.nonce.lock().clone(),
+        "email": "teacher@school.edu",
+        "email_verified": true,
+        "name": "Teacher",
+    });
+    let mut header = Header::new(Algorithm::RS256);
+    header.kid = Some("test".into());
+    let id_token = encode(
+        &header,
+        &claims,
+        &EncodingKey::from_rsa_pem(TEST_KEY.as_bytes()).unwrap(),
+    )
+    .unwrap();
+
+    (
+        axum::http::StatusCode::OK,
+        Json(json!({
+            "access_token": "mock-access-token",
+            "token_type": "Bearer",
+            "expires_in": 300,
+            "id_token": id_token,
+        })),
+    )
+}
+
+async fn start_mock_idp() -> MockIdp {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let idp = MockIdp {
+        issuer: format!("http://{}", listener.local_addr().unwrap()),
+        ..Default::default()
+    };
+
+    let app = Router::new()
+        .route("/.well-known/openid-configuration", get(discovery))
+        .route("/jwks", get(jwks))
+        .route("/token", post(token))
+        .with_state(idp.clone());
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    idp
+}
+
+fn query_param(url: &str, key: &str) -> String {
+    url.split_once('?')
+        .unwrap()
+        .1
+        .split('&')
+        .find_map(|pair| pair.strip_prefix(&format!("{key}=")))
+        .unwrap()
+        .to_string()
+}
+
+fn oidc_client(idp: &MockIdp) -> OidcClient {
+    OidcClient::try_new(OidcConfig {
+        issuer_url: idp.issuer.clone(),
+        client_id: CLIENT_ID.into(),
+        client_secret: None,
+        redirect_url: "http://localhost:3000/oidc/callback".into(),
+        scopes: vec!["email".into(), "profile".into()],
+    })
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_authorization_code_flow_with_pkce() {
+    let idp = start_mock_idp().await;
+    let client = oidc_client(&idp);
+
+    let request = client.authorize().await.unwrap();
+    assert!(request
+        .url
+        .starts_with(&format!("{}/authorize", idp.issuer)));
+    assert_eq!(query_param(&request.url, "state"), request.state);
+    assert_eq!(query_param(&request.url, "code_challenge_method"), "S256");
+    *idp.nonce.lock() = request.nonce.clone();
+    *idp.code_challenge.lock() = query_param(&request.url, "code_challenge");
+
+    let identity = client
+        .exchange(CODE.into(), request.pkce_verifier, request.nonce)
+        .await
+        .unwrap();
+    assert_eq!(identity.issuer, idp.issuer);
+    assert_eq!(identity.subject, "idp-user-1");
+    assert_eq!(identity.email.as_deref(), Some("teacher@school.edu"));
+    assert!(identity.email_verified);
+    assert_eq!(identity.name.as_deref(), Some("Teacher"));
+}
+
+#[tokio::test]
+async fn test_exchange_rejects_wrong_nonce() {
+    let idp = start_mock_idp().await;
+    let client = oidc_client(&idp);
+
+    let request = client.authorize().await.unwrap();
+    *idp.nonce.lock() = "another-nonce".into();
+    *idp.code_challenge.lock() = query_param(&request.url, "code_challenge");
+
+    let res = client
+        .exchange(CODE.into(), request.pkce_verifier, request.nonce)
+        .await;
+    assert!(matches!(res, Err(Error::OidcFailed(_))));
+}
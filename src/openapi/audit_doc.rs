@@ -0,0 +1,20 @@
+use crate::{
+    entities::audit_events,
+    enums::{
+        error::ErrorBody,
+        generic::{GenericResponse, PaginatedResponse},
+    },
+    models::audit::QueryAuditParams,
+};
+
+#[utoipa::path(
+    get,
+    path = "/api/audit-events",
+    tag = "audit",
+    params(QueryAuditParams),
+    responses(
+        (status = 200, body = GenericResponse<PaginatedResponse<audit_events::Model>>),
+        (status = 403, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn get_all() {}
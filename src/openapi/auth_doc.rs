@@ -0,0 +1,158 @@
+use crate::{
+    enums::{error::ErrorBody, generic::GenericResponse},
+    models::user::{
+        EmailRequest, LoginRequest, LoginResponse, LoginTotpRequest, OidcAuthorizeResponse,
+        OidcCallbackRequest, RegisterUserRequest, ResetPasswordRequest, TotpChallengeRequest,
+        TotpSetupResponse, UserModel, VerifyEmailRequest,
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/",
+    tag = "health",
+    security(()),
+    responses((status = 200, description = "Server is up", body = String))
+)]
+fn root() {}
+
+#[utoipa::path(
+    get,
+    path = "/.well-known/jwks.json",
+    tag = "auth",
+    security(()),
+    responses((status = 200, description = "Public keys to verify access tokens", body = Object))
+)]
+fn jwks() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/logout",
+    tag = "auth",
+    responses((status = 200, body = GenericResponse<String>))
+)]
+fn logout() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/register",
+    tag = "auth",
+    security(()),
+    request_body = RegisterUserRequest,
+    responses(
+        (status = 200, body = GenericResponse<UserModel>),
+        (status = 409, description = "Email already registered", body = GenericResponse<ErrorBody>)
+    )
+)]
+fn register() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/login",
+    tag = "auth",
+    security(()),
+    request_body = LoginRequest,
+    responses(
+        (status = 200, body = GenericResponse<LoginResponse>),
+        (status = 401, body = GenericResponse<ErrorBody>),
+        (status = 429, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn login() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/login/totp",
+    tag = "auth",
+    security(()),
+    request_body = LoginTotpRequest,
+    responses(
+        (status = 200, body = GenericResponse<LoginResponse>),
+        (status = 401, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn login_totp() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/login/totp/setup",
+    tag = "auth",
+    security(()),
+    request_body = TotpChallengeRequest,
+    responses(
+        (status = 200, body = GenericResponse<TotpSetupResponse>),
+        (status = 401, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn login_totp_setup() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/auth/oidc/authorize",
+    tag = "auth",
+    security(()),
+    responses(
+        (status = 200, body = GenericResponse<OidcAuthorizeResponse>),
+        (status = 404, description = "OIDC is not configured", body = GenericResponse<ErrorBody>)
+    )
+)]
+fn oidc_authorize() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/oidc/callback",
+    tag = "auth",
+    security(()),
+    request_body = OidcCallbackRequest,
+    responses(
+        (status = 200, body = GenericResponse<LoginResponse>),
+        (status = 401, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn oidc_callback() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/verify-email",
+    tag = "auth",
+    security(()),
+    request_body = VerifyEmailRequest,
+    responses(
+        (status = 200, body = GenericResponse<UserModel>),
+        (status = 400, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn verify_email() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/resend-verification",
+    tag = "auth",
+    security(()),
+    request_body = EmailRequest,
+    responses((status = 200, body = GenericResponse<String>))
+)]
+fn resend_verification() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/forgot-password",
+    tag = "auth",
+    security(()),
+    request_body = EmailRequest,
+    responses((status = 200, body = GenericResponse<String>))
+)]
+fn forgot_password() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/reset-password",
+    tag = "auth",
+    security(()),
+    request_body = ResetPasswordRequest,
+    responses(
+        (status = 200, body = GenericResponse<String>),
+        (status = 400, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn reset_password() {}
@@ -0,0 +1,101 @@
+use crate::{
+    entities::change_requests,
+    enums::{
+        error::ErrorBody,
+        generic::{GenericResponse, PaginatedResponse},
+    },
+    models::change_request::{
+        ChangeRequestResponse, CreateChangeRequest, QueryChangeRequestParams, ReviewChangeRequest,
+    },
+};
+
+#[utoipa::path(
+    post,
+    path = "/api/questions/{id}/change-requests",
+    tag = "change-requests",
+    params(("id" = Uuid, Path, description = "Question id")),
+    request_body = CreateChangeRequest,
+    responses(
+        (status = 200, body = GenericResponse<change_requests::Model>),
+        (status = 403, description = "Caller is not the set creator and the set is not shared with them", body = GenericResponse<ErrorBody>),
+        (status = 422, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn create() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/questions/{id}/change-requests",
+    tag = "change-requests",
+    params(("id" = Uuid, Path, description = "Question id"), QueryChangeRequestParams),
+    responses(
+        (status = 200, description = "Change requests of the question, newest first", body = GenericResponse<PaginatedResponse<change_requests::Model>>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn get_all_of_question() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/sets/{id}/change-requests",
+    tag = "change-requests",
+    params(("id" = Uuid, Path, description = "Set id"), QueryChangeRequestParams),
+    responses(
+        (status = 200, description = "Change requests of all questions in the set, newest first", body = GenericResponse<PaginatedResponse<change_requests::Model>>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn get_all_of_set() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/change-requests/{id}",
+    tag = "change-requests",
+    params(("id" = Uuid, Path, description = "Change request id")),
+    responses(
+        (status = 200, description = "Change request with the diff against the question it was proposed on", body = GenericResponse<ChangeRequestResponse>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn get_by_id() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/change-requests/{id}/accept",
+    tag = "change-requests",
+    params(("id" = Uuid, Path, description = "Change request id")),
+    request_body = ReviewChangeRequest,
+    responses(
+        (status = 200, description = "Applied to the question", body = GenericResponse<change_requests::Model>),
+        (status = 403, description = "Caller cannot edit the question", body = GenericResponse<ErrorBody>),
+        (status = 409, description = "Already accepted, rejected or withdrawn", body = GenericResponse<ErrorBody>)
+    )
+)]
+fn accept() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/change-requests/{id}/reject",
+    tag = "change-requests",
+    params(("id" = Uuid, Path, description = "Change request id")),
+    request_body = ReviewChangeRequest,
+    responses(
+        (status = 200, body = GenericResponse<change_requests::Model>),
+        (status = 403, description = "Caller cannot edit the question", body = GenericResponse<ErrorBody>),
+        (status = 409, description = "Already accepted, rejected or withdrawn", body = GenericResponse<ErrorBody>)
+    )
+)]
+fn reject() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/change-requests/{id}/withdraw",
+    tag = "change-requests",
+    params(("id" = Uuid, Path, description = "Change request id")),
+    responses(
+        (status = 200, body = GenericResponse<change_requests::Model>),
+        (status = 403, description = "Only the proposer can withdraw", body = GenericResponse<ErrorBody>),
+        (status = 409, description = "Already accepted, rejected or withdrawn", body = GenericResponse<ErrorBody>)
+    )
+)]
+fn withdraw() {}
@@ -0,0 +1,115 @@
+use crate::{
+    enums::{
+        error::ErrorBody,
+        generic::{GenericResponse, PaginatedResponse},
+    },
+    models::comment::{
+        CommentResponse, CommentThread, CreateCommentRequest, QueryCommentParams,
+        ResolveCommentRequest, UpdateCommentRequest,
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/api/sets/{id}/comments",
+    tag = "comments",
+    params(("id" = Uuid, Path, description = "Set id"), QueryCommentParams),
+    responses(
+        (status = 200, description = "Root comments newest first, each with its replies", body = GenericResponse<PaginatedResponse<CommentThread>>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn get_set_comments() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/sets/{id}/comments",
+    tag = "comments",
+    params(("id" = Uuid, Path, description = "Set id")),
+    request_body = CreateCommentRequest,
+    responses(
+        (status = 200, body = GenericResponse<CommentResponse>),
+        (status = 403, description = "Caller is not the creator and has no Comment or Edit share", body = GenericResponse<ErrorBody>),
+        (status = 422, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn create_set_comment() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/questions/{id}/comments",
+    tag = "comments",
+    params(("id" = Uuid, Path, description = "Question id"), QueryCommentParams),
+    responses(
+        (status = 200, description = "Root comments newest first, each with its replies", body = GenericResponse<PaginatedResponse<CommentThread>>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn get_question_comments() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/questions/{id}/comments",
+    tag = "comments",
+    params(("id" = Uuid, Path, description = "Question id")),
+    request_body = CreateCommentRequest,
+    responses(
+        (status = 200, body = GenericResponse<CommentResponse>),
+        (status = 403, description = "Caller is not the creator and has no Comment or Edit share", body = GenericResponse<ErrorBody>),
+        (status = 422, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn create_question_comment() {}
+
+#[utoipa::path(
+    patch,
+    path = "/api/comments/{id}",
+    tag = "comments",
+    params(("id" = Uuid, Path, description = "Comment id")),
+    request_body = UpdateCommentRequest,
+    responses(
+        (status = 200, body = GenericResponse<CommentResponse>),
+        (status = 403, description = "Only the author can edit", body = GenericResponse<ErrorBody>),
+        (status = 404, body = GenericResponse<ErrorBody>),
+        (status = 422, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn update() {}
+
+#[utoipa::path(
+    delete,
+    path = "/api/comments/{id}",
+    tag = "comments",
+    params(("id" = Uuid, Path, description = "Comment id")),
+    responses(
+        (status = 200, description = "Deleted together with its replies", body = GenericResponse<String>),
+        (status = 403, description = "Only the author can delete", body = GenericResponse<ErrorBody>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn delete() {}
+
+#[utoipa::path(
+    patch,
+    path = "/api/comments/{id}/resolve",
+    tag = "comments",
+    params(("id" = Uuid, Path, description = "Comment id")),
+    request_body = ResolveCommentRequest,
+    responses(
+        (status = 200, body = GenericResponse<CommentResponse>),
+        (status = 403, description = "Caller is not the author, the set creator or an editor", body = GenericResponse<ErrorBody>),
+        (status = 422, description = "The comment is not a suggestion", body = GenericResponse<ErrorBody>)
+    )
+)]
+fn resolve() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/comments/mentions",
+    tag = "comments",
+    params(QueryCommentParams),
+    responses(
+        (status = 200, description = "Comments mentioning the caller, newest first", body = GenericResponse<PaginatedResponse<CommentResponse>>)
+    )
+)]
+fn get_mentions() {}
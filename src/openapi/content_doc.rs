@@ -0,0 +1,16 @@
+use crate::{
+    enums::{error::ErrorBody, generic::GenericResponse},
+    models::content::{RenderContentRequest, RenderedContent},
+};
+
+#[utoipa::path(
+    post,
+    path = "/api/content/render",
+    tag = "content",
+    request_body = RenderContentRequest,
+    responses(
+        (status = 200, body = GenericResponse<RenderedContent>),
+        (status = 422, description = "Invalid math expression", body = GenericResponse<ErrorBody>)
+    )
+)]
+fn render() {}
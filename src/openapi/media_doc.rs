@@ -0,0 +1,57 @@
+use crate::{
+    enums::{error::ErrorBody, generic::GenericResponse},
+    models::media::{MediaResponse, SignedUrlParams},
+};
+
+#[utoipa::path(
+    post,
+    path = "/api/media",
+    tag = "media",
+    request_body(content = [u8], description = "Image (png, jpeg, gif, webp) or audio (mp3, ogg, wav, m4a) file", content_type = "application/octet-stream"),
+    responses(
+        (status = 200, body = GenericResponse<MediaResponse>),
+        (status = 400, description = "Unsupported file type", body = GenericResponse<ErrorBody>),
+        (status = 413, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn upload() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/media/{id}",
+    tag = "media",
+    params(("id" = Uuid, Path, description = "Media id")),
+    responses(
+        (status = 200, description = "Metadata with a fresh signed download url", body = GenericResponse<MediaResponse>),
+        (status = 403, body = GenericResponse<ErrorBody>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn get() {}
+
+#[utoipa::path(
+    delete,
+    path = "/api/media/{id}",
+    tag = "media",
+    params(("id" = Uuid, Path, description = "Media id")),
+    responses(
+        (status = 200, body = GenericResponse<String>),
+        (status = 403, body = GenericResponse<ErrorBody>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn delete() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/media/{id}/content",
+    tag = "media",
+    security(()),
+    params(("id" = Uuid, Path, description = "Media id"), SignedUrlParams),
+    responses(
+        (status = 200, description = "File content", content_type = "application/octet-stream", body = [u8]),
+        (status = 403, description = "Invalid or expired signature", body = GenericResponse<ErrorBody>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn download() {}
@@ -0,0 +1,365 @@
+use utoipa::{
+    openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme},
+    Modify, OpenApi,
+};
+
+// Các hàm trong *_doc chỉ để gắn #[utoipa::path], không được gọi
+#[allow(dead_code)]
+mod audit_doc;
+#[allow(dead_code)]
+mod auth_doc;
+#[allow(dead_code)]
+mod change_request_doc;
+#[allow(dead_code)]
+mod comment_doc;
+#[allow(dead_code)]
+mod content_doc;
+#[allow(dead_code)]
+mod media_doc;
+#[allow(dead_code)]
+mod note_doc;
+#[allow(dead_code)]
+mod ownership_doc;
+#[allow(dead_code)]
+mod qna_doc;
+#[allow(dead_code)]
+mod quiz_doc;
+#[allow(dead_code)]
+mod quiz_question_doc;
+#[allow(dead_code)]
+mod set_doc;
+#[allow(dead_code)]
+mod tag_doc;
+#[allow(dead_code)]
+mod test_doc;
+#[allow(dead_code)]
+mod trash_doc;
+#[allow(dead_code)]
+mod user_doc;
+
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Flashcard API"),
+    paths(
+        auth_doc::root,
+        auth_doc::jwks,
+        auth_doc::logout,
+        auth_doc::register,
+        auth_doc::login,
+        auth_doc::login_totp,
+        auth_doc::login_totp_setup,
+        auth_doc::oidc_authorize,
+        auth_doc::oidc_callback,
+        auth_doc::verify_email,
+        auth_doc::resend_verification,
+        auth_doc::forgot_password,
+        auth_doc::reset_password,
+        user_doc::get_all_users,
+        user_doc::delete,
+        user_doc::update_self,
+        user_doc::upload_avatar,
+        user_doc::update_password,
+        user_doc::update_role,
+        user_doc::reset_totp,
+        user_doc::setup_totp,
+        user_doc::activate_totp,
+        user_doc::disable_totp,
+        user_doc::invite_staff,
+        user_doc::accept_staff_invite,
+        user_doc::get_all_tokens,
+        user_doc::create_token,
+        user_doc::revoke_token,
+        set_doc::get_all,
+        set_doc::create,
+        set_doc::get_by_id,
+        set_doc::update,
+        set_doc::delete,
+        set_doc::share,
+        set_doc::invite,
+        set_doc::accept_invite,
+        set_doc::get_tags,
+        set_doc::set_tags,
+        qna_doc::get_all,
+        qna_doc::create,
+        qna_doc::get_by_id,
+        qna_doc::update,
+        qna_doc::delete,
+        qna_doc::get_tags,
+        qna_doc::set_tags,
+        qna_doc::get_revisions,
+        qna_doc::rollback,
+        quiz_doc::get_all,
+        quiz_doc::get_one,
+        quiz_doc::create,
+        quiz_doc::update,
+        quiz_doc::delete,
+        quiz_doc::share,
+        quiz_doc::get_all_shared_users_of_quiz,
+        quiz_question_doc::create,
+        quiz_question_doc::get_all,
+        quiz_question_doc::update,
+        quiz_question_doc::get_by_id,
+        quiz_question_doc::delete,
+        quiz_question_doc::create_from_questions,
+        quiz_question_doc::create_from_tags,
+        quiz_question_doc::create_from_mistakes,
+        test_doc::get_all,
+        test_doc::create,
+        test_doc::get_by_id,
+        test_doc::start,
+        test_doc::get_all_testing_question_statuses,
+        test_doc::get_testing_question,
+        test_doc::resolve_test_question,
+        test_doc::flag_question,
+        test_doc::take_over,
+        test_doc::submit,
+        test_doc::result,
+        test_doc::review_solution,
+        test_doc::get_adaptive_path,
+        trash_doc::get_all,
+        trash_doc::restore_set,
+        trash_doc::restore_question,
+        trash_doc::restore_quiz,
+        audit_doc::get_all,
+        media_doc::upload,
+        media_doc::get,
+        media_doc::delete,
+        media_doc::download,
+        content_doc::render,
+        tag_doc::get_all,
+        tag_doc::create,
+        tag_doc::update,
+        tag_doc::delete,
+        tag_doc::accuracy,
+        note_doc::get_all,
+        note_doc::get_question_note,
+        note_doc::save_question_note,
+        note_doc::delete_question_note,
+        note_doc::get_quiz_question_note,
+        note_doc::save_quiz_question_note,
+        note_doc::delete_quiz_question_note,
+        comment_doc::get_set_comments,
+        comment_doc::create_set_comment,
+        comment_doc::get_question_comments,
+        comment_doc::create_question_comment,
+        comment_doc::update,
+        comment_doc::delete,
+        comment_doc::resolve,
+        comment_doc::get_mentions,
+        change_request_doc::create,
+        change_request_doc::get_all_of_question,
+        change_request_doc::get_all_of_set,
+        change_request_doc::get_by_id,
+        change_request_doc::accept,
+        change_request_doc::reject,
+        change_request_doc::withdraw,
+        ownership_doc::transfer_set,
+        ownership_doc::get_set_history,
+        ownership_doc::transfer_quiz,
+        ownership_doc::get_quiz_history,
+        ownership_doc::transfer_all,
+    ),
+    modifiers(&SecurityAddon),
+    security(("bearer" = []), ("api_token" = []))
+)]
+pub struct ApiDoc;
+
+// JWT qua `Bearer`, personal API token qua `Token`
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "bearer",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+        components.add_security_scheme(
+            "api_token",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::with_description(
+                "Authorization",
+                "Personal API token: `Token fcp_...`",
+            ))),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::collections::BTreeSet};
+
+    const METHODS: [&str; 5] = ["get", "post", "put", "patch", "delete"];
+
+    // (tên file, nội dung) của routes/, đọc lúc compile để test không cần chạy
+    // server
+    const ROUTE_FILES: [(&str, &str); 17] = [
+        ("mod", include_str!("../routes/mod.rs")),
+        ("audit_route", include_str!("../routes/audit_route.rs")),
+        ("auth_route", include_str!("../routes/auth_route.rs")),
+        (
+            "change_request_route",
+            include_str!("../routes/change_request_route.rs"),
+        ),
+        ("comment_route", include_str!("../routes/comment_route.rs")),
+        ("content_route", include_str!("../routes/content_route.rs")),
+        ("docs_route", include_str!("../routes/docs_route.rs")),
+        ("media_route", include_str!("../routes/media_route.rs")),
+        ("note_route", include_str!("../routes/note_route.rs")),
+        ("qna_route", include_str!("../routes/qna_route.rs")),
+        (
+            "quiz_question_route",
+            include_str!("../routes/quiz_question_route.rs"),
+        ),
+        ("quiz_route", include_str!("../routes/quiz_route.rs")),
+        ("set_route", include_str!("../routes/set_route.rs")),
+        ("tag_route", include_str!("../routes/tag_route.rs")),
+        ("test_route", include_str!("../routes/test_route.rs")),
+        ("trash_route", include_str!("../routes/trash_route.rs")),
+        ("user_route", include_str!("../routes/user_route.rs")),
+    ];
+
+    fn strip_comments(src: &str) -> String {
+        src.lines()
+            .filter(|line| !line.trim_start().starts_with("//"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    // Nội dung trong ngoặc của lời gọi bắt đầu ở `start` (ngay sau dấu `(`)
+    fn call_args(src: &str, start: usize) -> &str {
+        let mut depth = 1;
+        for (i, c) in src[start..].char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return &src[start..start + i];
+                    }
+                }
+                _ => {}
+            }
+        }
+        &src[start..]
+    }
+
+    fn first_str_literal(src: &str) -> Option<&str> {
+        let start = src.find('"')? + 1;
+        let len = src[start..].find('"')?;
+        Some(&src[start..start + len])
+    }
+
+    // Prefix của router: `.nest("/x", fn_name(` trong routes/mod.rs, router được
+    // `.merge(fn_name(` vào router khác thì dùng prefix của router đó
+    fn router_prefix(fn_name: &str) -> String {
+        let (_, root) = ROUTE_FILES[0];
+        let call = format!("{fn_name}(");
+        for chunk in root.split(".nest(").skip(1) {
+            if call_args(chunk, 0).contains(&call) {
+                let prefix = first_str_literal(chunk).unwrap();
+                return format!("/api{prefix}");
+            }
+        }
+
+        let merge = format!(".merge({call}");
+        let (parent, parent_src) = ROUTE_FILES
+            .iter()
+            .map(|(name, src)| (*name, strip_comments(src)))
+            .find(|(_, src)| src.contains(&merge))
+            .unwrap_or_else(|| panic!("router {fn_name} is not mounted"));
+
+        match parent {
+            "mod" => String::new(),
+            _ => router_prefix(&router_fn_name(&parent_src).unwrap()),
+        }
+    }
+
+    fn router_fn_name(src: &str) -> Option<String> {
+        let start = src.find("pub fn ")? + "pub fn ".len();
+        let len = src[start..].find('(')?;
+        Some(src[start..start + len].to_string())
+    }
+
+    // Tất cả (method, path) khai báo bằng `.route(...)`
+    fn declared_routes() -> BTreeSet<(String, String)> {
+        let mut routes = BTreeSet::new();
+
+        for (name, src) in ROUTE_FILES {
+            let src = strip_comments(src);
+            let prefix = match name {
+                "mod" => String::new(),
+                _ => {
+                    match router_fn_name(&src) {
+                        Some(fn_name) => router_prefix(&fn_name),
+                        None => continue,
+                    }
+                }
+            };
+
+            for (idx, _) in src.match_indices(".route(") {
+                let args = call_args(&src, idx + ".route(".len());
+                let path = first_str_literal(args).unwrap();
+                let full_path = match (prefix.as_str(), path) {
+                    ("", path) => path.to_string(),
+                    (prefix, "/") => prefix.to_string(),
+                    (prefix, path) => format!("{prefix}{path}"),
+                };
+
+                for method in METHODS {
+                    let is_call = args.match_indices(&format!("{method}(")).any(|(i, _)| {
+                        i == 0 || !args[..i].ends_with(|c: char| c.is_alphanumeric() || c == '_')
+                    });
+                    if is_call {
+                        routes.insert((method.to_string(), full_path.clone()));
+                    }
+                }
+            }
+        }
+
+        routes
+    }
+
+    fn documented_routes() -> BTreeSet<(String, String)> {
+        let spec = serde_json::to_value(ApiDoc::openapi()).unwrap();
+        let mut routes = BTreeSet::new();
+
+        for (path, item) in spec["paths"].as_object().unwrap() {
+            for method in METHODS {
+                if item.get(method).is_some() {
+                    routes.insert((method.to_string(), path.clone()));
+                }
+            }
+        }
+
+        routes
+    }
+
+    #[test]
+    fn every_route_is_documented() {
+        let declared = declared_routes();
+        let documented = documented_routes();
+
+        assert!(declared.contains(&("get".into(), "/api/sets".into())));
+        assert!(declared.contains(&(
+            "delete".into(),
+            "/api/quizzes/{quiz_id}/questions/{quiz_question_id}".into()
+        )));
+
+        let undocumented: Vec<_> = declared.difference(&documented).collect();
+        assert!(
+            undocumented.is_empty(),
+            "undocumented routes: {undocumented:?}"
+        );
+
+        let stale: Vec<_> = documented.difference(&declared).collect();
+        assert!(
+            stale.is_empty(),
+            "documented routes that do not exist: {stale:?}"
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{
+    entities::notes,
+    enums::{
+        error::ErrorBody,
+        generic::{GenericResponse, PaginatedResponse},
+    },
+    models::note::{QueryNoteParams, SaveNoteRequest},
+};
+
+#[utoipa::path(
+    get,
+    path = "/api/notes",
+    tag = "notes",
+    params(QueryNoteParams),
+    responses(
+        (status = 200, description = "Caller's own notes, most recently updated first", body = GenericResponse<PaginatedResponse<notes::Model>>)
+    )
+)]
+fn get_all() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/questions/{id}/note",
+    tag = "notes",
+    params(("id" = Uuid, Path, description = "Question id")),
+    responses(
+        (status = 200, body = GenericResponse<notes::Model>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn get_question_note() {}
+
+#[utoipa::path(
+    put,
+    path = "/api/questions/{id}/note",
+    tag = "notes",
+    params(("id" = Uuid, Path, description = "Question id")),
+    request_body = SaveNoteRequest,
+    responses(
+        (status = 200, description = "Created or replaced", body = GenericResponse<notes::Model>),
+        (status = 403, body = GenericResponse<ErrorBody>),
+        (status = 422, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn save_question_note() {}
+
+#[utoipa::path(
+    delete,
+    path = "/api/questions/{id}/note",
+    tag = "notes",
+    params(("id" = Uuid, Path, description = "Question id")),
+    responses(
+        (status = 200, body = GenericResponse<String>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn delete_question_note() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/quizzes/{quiz_id}/questions/{quiz_question_id}/note",
+    tag = "notes",
+    params(
+        ("quiz_id" = Uuid, Path, description = "Quiz id"),
+        ("quiz_question_id" = Uuid, Path, description = "Quiz question id")
+    ),
+    responses(
+        (status = 200, body = GenericResponse<notes::Model>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn get_quiz_question_note() {}
+
+#[utoipa::path(
+    put,
+    path = "/api/quizzes/{quiz_id}/questions/{quiz_question_id}/note",
+    tag = "notes",
+    params(
+        ("quiz_id" = Uuid, Path, description = "Quiz id"),
+        ("quiz_question_id" = Uuid, Path, description = "Quiz question id")
+    ),
+    request_body = SaveNoteRequest,
+    responses(
+        (status = 200, description = "Created or replaced", body = GenericResponse<notes::Model>),
+        (status = 403, body = GenericResponse<ErrorBody>),
+        (status = 422, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn save_quiz_question_note() {}
+
+#[utoipa::path(
+    delete,
+    path = "/api/quizzes/{quiz_id}/questions/{quiz_question_id}/note",
+    tag = "notes",
+    params(
+        ("quiz_id" = Uuid, Path, description = "Quiz id"),
+        ("quiz_question_id" = Uuid, Path, description = "Quiz question id")
+    ),
+    responses(
+        (status = 200, body = GenericResponse<String>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn delete_quiz_question_note() {}
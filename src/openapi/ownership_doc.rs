@@ -0,0 +1,78 @@
+use crate::{
+    entities::ownership_transfers,
+    enums::{
+        error::ErrorBody,
+        generic::{GenericResponse, PaginatedResponse},
+    },
+    models::ownership::{QueryTransferParams, TransferAllResponse, TransferOwnershipRequest},
+};
+
+#[utoipa::path(
+    post,
+    path = "/api/sets/{id}/transfer",
+    tag = "ownership",
+    params(("id" = Uuid, Path, description = "Set id")),
+    request_body = TransferOwnershipRequest,
+    responses(
+        (status = 200, description = "Set and the questions the previous owner created in it now belong to `to_user_id`", body = GenericResponse<ownership_transfers::Model>),
+        (status = 403, description = "Caller is neither the owner nor Staff", body = GenericResponse<ErrorBody>),
+        (status = 404, body = GenericResponse<ErrorBody>),
+        (status = 422, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn transfer_set() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/sets/{id}/transfers",
+    tag = "ownership",
+    params(("id" = Uuid, Path, description = "Set id"), QueryTransferParams),
+    responses(
+        (status = 200, description = "Ownership transfers of the set, newest first", body = GenericResponse<PaginatedResponse<ownership_transfers::Model>>),
+        (status = 403, body = GenericResponse<ErrorBody>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn get_set_history() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/quizzes/{id}/transfer",
+    tag = "ownership",
+    params(("id" = Uuid, Path, description = "Quiz id")),
+    request_body = TransferOwnershipRequest,
+    responses(
+        (status = 200, body = GenericResponse<ownership_transfers::Model>),
+        (status = 403, description = "Caller is neither the creator nor Staff", body = GenericResponse<ErrorBody>),
+        (status = 404, body = GenericResponse<ErrorBody>),
+        (status = 422, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn transfer_quiz() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/quizzes/{id}/transfers",
+    tag = "ownership",
+    params(("id" = Uuid, Path, description = "Quiz id"), QueryTransferParams),
+    responses(
+        (status = 200, description = "Ownership transfers of the quiz, newest first", body = GenericResponse<PaginatedResponse<ownership_transfers::Model>>),
+        (status = 403, body = GenericResponse<ErrorBody>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn get_quiz_history() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/users/{id}/transfer-content",
+    tag = "ownership",
+    params(("id" = Uuid, Path, description = "Id of the user whose sets and quizzes are transferred")),
+    request_body = TransferOwnershipRequest,
+    responses(
+        (status = 200, description = "All sets and quizzes of the user, trashed ones included, were transferred", body = GenericResponse<TransferAllResponse>),
+        (status = 403, description = "Caller is neither that user nor Staff", body = GenericResponse<ErrorBody>),
+        (status = 422, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn transfer_all() {}
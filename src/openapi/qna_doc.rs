@@ -0,0 +1,134 @@
+use crate::{
+    entities::{questions, tags},
+    enums::{
+        error::ErrorBody,
+        generic::{GenericResponse, PaginatedResponse},
+    },
+    models::{
+        qna::{
+            CreateQnARequest, QueryQuestionParams, QueryRevisionParams, QuestionRevisionResponse,
+            UpdateQuestionRequest,
+        },
+        tag::SetTagsRequest,
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/api/questions",
+    tag = "questions",
+    params(QueryQuestionParams),
+    responses(
+        (status = 200, body = GenericResponse<PaginatedResponse<questions::Model>>),
+        (status = 403, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn get_all() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/questions",
+    tag = "questions",
+    request_body = CreateQnARequest,
+    responses(
+        (status = 200, body = GenericResponse<questions::Model>),
+        (status = 403, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn create() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/questions/{id}",
+    tag = "questions",
+    params(("id" = Uuid, Path, description = "Question id")),
+    responses(
+        (status = 200, body = GenericResponse<questions::Model>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn get_by_id() {}
+
+#[utoipa::path(
+    patch,
+    path = "/api/questions/{id}",
+    tag = "questions",
+    params(("id" = Uuid, Path, description = "Question id")),
+    request_body = UpdateQuestionRequest,
+    responses(
+        (status = 200, body = GenericResponse<questions::Model>),
+        (status = 403, body = GenericResponse<ErrorBody>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn update() {}
+
+#[utoipa::path(
+    delete,
+    path = "/api/questions/{id}",
+    tag = "questions",
+    params(("id" = Uuid, Path, description = "Question id")),
+    responses(
+        (status = 200, description = "Moved to trash", body = GenericResponse<String>),
+        (status = 403, body = GenericResponse<ErrorBody>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn delete() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/questions/{id}/tags",
+    tag = "questions",
+    params(("id" = Uuid, Path, description = "Question id")),
+    responses(
+        (status = 200, body = GenericResponse<Vec<tags::Model>>),
+        (status = 403, body = GenericResponse<ErrorBody>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn get_tags() {}
+
+#[utoipa::path(
+    put,
+    path = "/api/questions/{id}/tags",
+    tag = "questions",
+    params(("id" = Uuid, Path, description = "Question id")),
+    request_body = SetTagsRequest,
+    responses(
+        (status = 200, description = "Replaces all tags, needs permission to update the question", body = GenericResponse<Vec<tags::Model>>),
+        (status = 403, body = GenericResponse<ErrorBody>),
+        (status = 404, description = "Unknown tag id", body = GenericResponse<ErrorBody>)
+    )
+)]
+fn set_tags() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/questions/{id}/revisions",
+    tag = "questions",
+    params(("id" = Uuid, Path, description = "Question id"), QueryRevisionParams),
+    responses(
+        (status = 200, description = "Revisions newest first, each with the diff from the revision before it", body = GenericResponse<PaginatedResponse<QuestionRevisionResponse>>),
+        (status = 403, body = GenericResponse<ErrorBody>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn get_revisions() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/questions/{id}/revisions/{revision_id}/rollback",
+    tag = "questions",
+    params(
+        ("id" = Uuid, Path, description = "Question id"),
+        ("revision_id" = Uuid, Path, description = "Revision to restore")
+    ),
+    responses(
+        (status = 200, description = "Restored as a new revision, needs permission to update the question", body = GenericResponse<questions::Model>),
+        (status = 403, body = GenericResponse<ErrorBody>),
+        (status = 404, body = GenericResponse<ErrorBody>),
+        (status = 422, description = "The question already matches the revision", body = GenericResponse<ErrorBody>)
+    )
+)]
+fn rollback() {}
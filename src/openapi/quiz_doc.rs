@@ -0,0 +1,95 @@
+use crate::{
+    entities::{quizes, shared_quizes},
+    enums::{
+        error::ErrorBody,
+        generic::{GenericResponse, PaginatedResponse},
+    },
+    models::{
+        quiz::{CreateQuizRequest, FilterQuizParams, QuizWithVisibility, UpdateQuizRequest},
+        user::UserModel,
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/api/quizzes",
+    tag = "quizzes",
+    params(FilterQuizParams),
+    responses((status = 200, body = GenericResponse<PaginatedResponse<QuizWithVisibility>>))
+)]
+fn get_all() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/quizzes/{id}",
+    tag = "quizzes",
+    params(("id" = Uuid, Path, description = "Quiz id")),
+    responses(
+        (status = 200, body = GenericResponse<quizes::Model>),
+        (status = 403, body = GenericResponse<ErrorBody>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn get_one() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/quizzes/{id}",
+    tag = "quizzes",
+    params(("id" = Uuid, Path, description = "Unused, the quiz id is generated")),
+    request_body = CreateQuizRequest,
+    responses((status = 200, body = GenericResponse<quizes::Model>))
+)]
+fn create() {}
+
+#[utoipa::path(
+    patch,
+    path = "/api/quizzes/{id}",
+    tag = "quizzes",
+    params(("id" = Uuid, Path, description = "Quiz id")),
+    request_body = UpdateQuizRequest,
+    responses(
+        (status = 200, body = GenericResponse<quizes::Model>),
+        (status = 403, body = GenericResponse<ErrorBody>),
+        (status = 409, description = "Quiz is published", body = GenericResponse<ErrorBody>)
+    )
+)]
+fn update() {}
+
+#[utoipa::path(
+    delete,
+    path = "/api/quizzes/{id}",
+    tag = "quizzes",
+    params(("id" = Uuid, Path, description = "Quiz id")),
+    responses(
+        (status = 200, description = "Moved to trash", body = GenericResponse<String>),
+        (status = 403, body = GenericResponse<ErrorBody>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn delete() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/quizzes/{id}/share",
+    tag = "quizzes",
+    params(("id" = Uuid, Path, description = "Quiz id")),
+    request_body(content = Vec<Uuid>, description = "Ids of the users to share with"),
+    responses(
+        (status = 200, body = GenericResponse<Vec<shared_quizes::Model>>),
+        (status = 403, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn share() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/quizzes/{id}/shared_users",
+    tag = "quizzes",
+    params(("id" = Uuid, Path, description = "Quiz id")),
+    responses(
+        (status = 200, body = GenericResponse<Vec<UserModel>>),
+        (status = 403, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn get_all_shared_users_of_quiz() {}
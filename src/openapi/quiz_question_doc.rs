@@ -0,0 +1,116 @@
+use crate::{
+    enums::{error::ErrorBody, generic::GenericResponse},
+    models::quiz_question::{
+        CreateQuizQuestionFromMistakes, CreateQuizQuestionFromQuestion, CreateQuizQuestionFromTags,
+        CreateQuizQuestionRequest, QuizQuestionResponse, UpdateQuizQuestionRequest,
+    },
+};
+
+#[utoipa::path(
+    post,
+    path = "/api/quizzes/{quiz_id}/questions",
+    tag = "quiz-questions",
+    params(("quiz_id" = Uuid, Path, description = "Quiz id")),
+    request_body = Vec<CreateQuizQuestionRequest>,
+    responses(
+        (status = 200, body = GenericResponse<Vec<QuizQuestionResponse>>),
+        (status = 403, body = GenericResponse<ErrorBody>),
+        (status = 409, description = "Quiz is published", body = GenericResponse<ErrorBody>)
+    )
+)]
+fn create() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/quizzes/{quiz_id}/questions",
+    tag = "quiz-questions",
+    params(("quiz_id" = Uuid, Path, description = "Quiz id")),
+    responses(
+        (status = 200, body = GenericResponse<Vec<QuizQuestionResponse>>),
+        (status = 403, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn get_all() {}
+
+#[utoipa::path(
+    patch,
+    path = "/api/quizzes/{quiz_id}/questions",
+    tag = "quiz-questions",
+    params(("quiz_id" = Uuid, Path, description = "Quiz id")),
+    request_body = Vec<UpdateQuizQuestionRequest>,
+    responses(
+        (status = 200, body = GenericResponse<Vec<QuizQuestionResponse>>),
+        (status = 403, body = GenericResponse<ErrorBody>),
+        (status = 409, description = "Quiz is published", body = GenericResponse<ErrorBody>)
+    )
+)]
+fn update() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/quizzes/{quiz_id}/questions/{quiz_question_id}",
+    tag = "quiz-questions",
+    params(
+        ("quiz_id" = Uuid, Path, description = "Quiz id"),
+        ("quiz_question_id" = Uuid, Path, description = "Quiz question id")
+    ),
+    responses(
+        (status = 200, body = GenericResponse<QuizQuestionResponse>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn get_by_id() {}
+
+#[utoipa::path(
+    delete,
+    path = "/api/quizzes/{quiz_id}/questions/{quiz_question_id}",
+    tag = "quiz-questions",
+    params(
+        ("quiz_id" = Uuid, Path, description = "Quiz id"),
+        ("quiz_question_id" = Uuid, Path, description = "Quiz question id")
+    ),
+    responses(
+        (status = 200, description = "Deleted, empty body"),
+        (status = 403, body = GenericResponse<ErrorBody>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn delete() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/quizzes/create-from-questions",
+    tag = "quiz-questions",
+    request_body = CreateQuizQuestionFromQuestion,
+    responses(
+        (status = 200, body = GenericResponse<Vec<QuizQuestionResponse>>),
+        (status = 403, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn create_from_questions() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/quizzes/create-from-tags",
+    tag = "quiz-questions",
+    request_body = CreateQuizQuestionFromTags,
+    responses(
+        (status = 200, description = "Random questions tagged with any of the tags (or their sub-topics) from sets the caller can view", body = GenericResponse<Vec<QuizQuestionResponse>>),
+        (status = 403, body = GenericResponse<ErrorBody>),
+        (status = 404, description = "No matching question", body = GenericResponse<ErrorBody>)
+    )
+)]
+fn create_from_tags() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/quizzes/create-from-mistakes",
+    tag = "quiz-questions",
+    request_body = CreateQuizQuestionFromMistakes,
+    responses(
+        (status = 200, description = "Copies of questions the caller answered wrong, one per source question, not yet answered right enough times in a row", body = GenericResponse<Vec<QuizQuestionResponse>>),
+        (status = 403, body = GenericResponse<ErrorBody>),
+        (status = 404, description = "No mistake to review", body = GenericResponse<ErrorBody>)
+    )
+)]
+fn create_from_mistakes() {}
@@ -0,0 +1,133 @@
+use crate::{
+    entities::{sets, shared_sets, tags},
+    enums::{error::ErrorBody, generic::GenericResponse},
+    models::{
+        set::{
+            AcceptInviteRequest, AllSetsOfUserResponse, CreateSetRequest, InviteToSetRequest,
+            ShareSetForUser, UpdateSetRequest,
+        },
+        tag::SetTagsRequest,
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/api/sets",
+    tag = "sets",
+    responses((status = 200, body = GenericResponse<AllSetsOfUserResponse>))
+)]
+fn get_all() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/sets",
+    tag = "sets",
+    request_body = CreateSetRequest,
+    responses((status = 200, body = GenericResponse<sets::Model>))
+)]
+fn create() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/sets/{id}",
+    tag = "sets",
+    params(("id" = Uuid, Path, description = "Set id")),
+    responses(
+        (status = 200, body = GenericResponse<sets::Model>),
+        (status = 403, body = GenericResponse<ErrorBody>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn get_by_id() {}
+
+#[utoipa::path(
+    patch,
+    path = "/api/sets/{id}",
+    tag = "sets",
+    params(("id" = Uuid, Path, description = "Set id")),
+    request_body = UpdateSetRequest,
+    responses(
+        (status = 200, body = GenericResponse<sets::Model>),
+        (status = 403, body = GenericResponse<ErrorBody>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn update() {}
+
+#[utoipa::path(
+    delete,
+    path = "/api/sets/{id}",
+    tag = "sets",
+    params(("id" = Uuid, Path, description = "Set id")),
+    responses(
+        (status = 200, description = "Moved to trash", body = GenericResponse<String>),
+        (status = 403, body = GenericResponse<ErrorBody>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn delete() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/sets/{id}/share",
+    tag = "sets",
+    params(("id" = Uuid, Path, description = "Set id")),
+    request_body = Vec<ShareSetForUser>,
+    responses(
+        (status = 200, body = GenericResponse<Vec<shared_sets::Model>>),
+        (status = 403, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn share() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/sets/{id}/invite",
+    tag = "sets",
+    params(("id" = Uuid, Path, description = "Set id")),
+    request_body = InviteToSetRequest,
+    responses(
+        (status = 200, body = GenericResponse<String>),
+        (status = 403, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn invite() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/sets/accept-invite",
+    tag = "sets",
+    request_body = AcceptInviteRequest,
+    responses(
+        (status = 200, body = GenericResponse<shared_sets::Model>),
+        (status = 400, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn accept_invite() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/sets/{id}/tags",
+    tag = "sets",
+    params(("id" = Uuid, Path, description = "Set id")),
+    responses(
+        (status = 200, body = GenericResponse<Vec<tags::Model>>),
+        (status = 403, body = GenericResponse<ErrorBody>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn get_tags() {}
+
+#[utoipa::path(
+    put,
+    path = "/api/sets/{id}/tags",
+    tag = "sets",
+    params(("id" = Uuid, Path, description = "Set id")),
+    request_body = SetTagsRequest,
+    responses(
+        (status = 200, description = "Replaces all tags, needs creator or shared edit permission", body = GenericResponse<Vec<tags::Model>>),
+        (status = 403, body = GenericResponse<ErrorBody>),
+        (status = 404, description = "Unknown tag id", body = GenericResponse<ErrorBody>)
+    )
+)]
+fn set_tags() {}
@@ -0,0 +1,68 @@
+use crate::{
+    entities::tags,
+    enums::{error::ErrorBody, generic::GenericResponse},
+    models::tag::{CreateTagRequest, TagAccuracy, UpdateTagRequest},
+};
+
+#[utoipa::path(
+    get,
+    path = "/api/tags",
+    tag = "tags",
+    responses(
+        (status = 200, description = "All tags, the topic tree is built from parent_id", body = GenericResponse<Vec<tags::Model>>)
+    )
+)]
+fn get_all() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/tags",
+    tag = "tags",
+    request_body = CreateTagRequest,
+    responses(
+        (status = 200, body = GenericResponse<tags::Model>),
+        (status = 404, description = "Parent tag not found", body = GenericResponse<ErrorBody>),
+        (status = 409, description = "Same name under the same parent", body = GenericResponse<ErrorBody>),
+        (status = 422, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn create() {}
+
+#[utoipa::path(
+    patch,
+    path = "/api/tags/{id}",
+    tag = "tags",
+    params(("id" = Uuid, Path, description = "Tag id")),
+    request_body = UpdateTagRequest,
+    responses(
+        (status = 200, body = GenericResponse<tags::Model>),
+        (status = 403, description = "Staff only", body = GenericResponse<ErrorBody>),
+        (status = 404, body = GenericResponse<ErrorBody>),
+        (status = 409, body = GenericResponse<ErrorBody>),
+        (status = 422, description = "Moving a tag under its own descendant", body = GenericResponse<ErrorBody>)
+    )
+)]
+fn update() {}
+
+#[utoipa::path(
+    delete,
+    path = "/api/tags/{id}",
+    tag = "tags",
+    params(("id" = Uuid, Path, description = "Tag id")),
+    responses(
+        (status = 200, description = "Deleted, child tags move up to its parent", body = GenericResponse<String>),
+        (status = 403, description = "Staff only", body = GenericResponse<ErrorBody>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn delete() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/tags/accuracy",
+    tag = "tags",
+    responses(
+        (status = 200, description = "Caller's accuracy per tag on submitted tests, weakest first", body = GenericResponse<Vec<TagAccuracy>>)
+    )
+)]
+fn accuracy() {}
@@ -0,0 +1,175 @@
+use crate::{
+    entities::{test_adaptive_steps, test_question_results, tests},
+    enums::{
+        error::ErrorBody,
+        generic::{GenericResponse, PaginatedResponse},
+    },
+    models::test::{
+        CreateTest, FlagQuestionRequest, QueryTestParams, ResolveTestRequest, ResolveTestResponse,
+        ResultResponse, SolutionResponse, TestingQuestion,
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/api/test",
+    tag = "tests",
+    params(QueryTestParams),
+    responses((status = 200, body = GenericResponse<PaginatedResponse<tests::Model>>))
+)]
+fn get_all() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/test",
+    tag = "tests",
+    request_body = CreateTest,
+    responses(
+        (status = 200, body = GenericResponse<tests::Model>),
+        (status = 403, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn create() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/test/{test_id}",
+    tag = "tests",
+    params(("test_id" = Uuid, Path, description = "Test id")),
+    responses(
+        (status = 200, body = GenericResponse<tests::Model>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn get_by_id() {}
+
+#[utoipa::path(
+    patch,
+    path = "/api/test/{test_id}/start",
+    tag = "tests",
+    params(("test_id" = Uuid, Path, description = "Test id")),
+    responses(
+        (status = 200, description = "First question", body = GenericResponse<TestingQuestion>),
+        (status = 409, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn start() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/test/{test_id}/question_statuses",
+    tag = "tests",
+    params(("test_id" = Uuid, Path, description = "Test id")),
+    responses((status = 200, body = GenericResponse<Vec<test_question_results::Model>>))
+)]
+fn get_all_testing_question_statuses() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/test/{test_id}/question/{quiz_question_id}",
+    tag = "tests",
+    params(
+        ("test_id" = Uuid, Path, description = "Test id"),
+        ("quiz_question_id" = Uuid, Path, description = "Quiz question id")
+    ),
+    responses(
+        (status = 200, body = GenericResponse<TestingQuestion>),
+        (status = 409, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn get_testing_question() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/test/{test_id}/question/{quiz_question_id}",
+    tag = "tests",
+    params(
+        ("test_id" = Uuid, Path, description = "Test id"),
+        ("quiz_question_id" = Uuid, Path, description = "Quiz question id")
+    ),
+    request_body = ResolveTestRequest,
+    responses(
+        (status = 200, description = "`feedback` is only present for practice tests", body = GenericResponse<ResolveTestResponse>),
+        (status = 409, description = "Test ended or written from another session", body = GenericResponse<ErrorBody>)
+    )
+)]
+fn resolve_test_question() {}
+
+#[utoipa::path(
+    patch,
+    path = "/api/test/{test_id}/question/{quiz_question_id}/flag",
+    tag = "tests",
+    params(
+        ("test_id" = Uuid, Path, description = "Test id"),
+        ("quiz_question_id" = Uuid, Path, description = "Quiz question id")
+    ),
+    request_body = FlagQuestionRequest,
+    responses(
+        (status = 200, body = GenericResponse<test_question_results::Model>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn flag_question() {}
+
+#[utoipa::path(
+    patch,
+    path = "/api/test/{test_id}/take-over",
+    tag = "tests",
+    params(("test_id" = Uuid, Path, description = "Test id")),
+    responses(
+        (status = 200, body = GenericResponse<tests::Model>),
+        (status = 409, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn take_over() {}
+
+#[utoipa::path(
+    patch,
+    path = "/api/test/{test_id}/submit",
+    tag = "tests",
+    params(("test_id" = Uuid, Path, description = "Test id")),
+    responses(
+        (status = 200, body = GenericResponse<Vec<test_question_results::Model>>),
+        (status = 409, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn submit() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/test/{test_id}/result",
+    tag = "tests",
+    params(("test_id" = Uuid, Path, description = "Test id")),
+    responses(
+        (status = 200, body = GenericResponse<ResultResponse>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn result() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/test/{test_id}/review/{quiz_question_id}",
+    tag = "tests",
+    params(
+        ("test_id" = Uuid, Path, description = "Test id"),
+        ("quiz_question_id" = Uuid, Path, description = "Quiz question id")
+    ),
+    responses(
+        (status = 200, body = GenericResponse<SolutionResponse>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn review_solution() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/test/{test_id}/adaptive-path",
+    tag = "tests",
+    params(("test_id" = Uuid, Path, description = "Test id")),
+    responses(
+        (status = 200, body = GenericResponse<Vec<test_adaptive_steps::Model>>),
+        (status = 409, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn get_adaptive_path() {}
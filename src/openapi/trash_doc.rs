@@ -0,0 +1,50 @@
+use crate::{
+    entities::{questions, quizes, sets},
+    enums::{error::ErrorBody, generic::GenericResponse},
+    models::trash::TrashResponse,
+};
+
+#[utoipa::path(
+    get,
+    path = "/api/trash",
+    tag = "trash",
+    responses((status = 200, body = GenericResponse<TrashResponse>))
+)]
+fn get_all() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/trash/sets/{id}/restore",
+    tag = "trash",
+    params(("id" = Uuid, Path, description = "Set id")),
+    responses(
+        (status = 200, body = GenericResponse<sets::Model>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn restore_set() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/trash/questions/{id}/restore",
+    tag = "trash",
+    params(("id" = Uuid, Path, description = "Question id")),
+    responses(
+        (status = 200, body = GenericResponse<questions::Model>),
+        (status = 404, body = GenericResponse<ErrorBody>),
+        (status = 409, description = "The set is in the trash", body = GenericResponse<ErrorBody>)
+    )
+)]
+fn restore_question() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/trash/quizzes/{id}/restore",
+    tag = "trash",
+    params(("id" = Uuid, Path, description = "Quiz id")),
+    responses(
+        (status = 200, body = GenericResponse<quizes::Model>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn restore_quiz() {}
@@ -0,0 +1,181 @@
+use crate::{
+    entities::users,
+    enums::{error::ErrorBody, generic::GenericResponse},
+    models::{
+        api_token::{ApiTokenModel, CreateApiTokenRequest, CreatedApiTokenResponse},
+        user::{
+            AcceptStaffInviteRequest, DeleteRequest, InviteStaffRequest, ResetTotpRequest,
+            TotpCodeRequest, TotpSetupResponse, UpdateUserPassword, UpdateUserRequest,
+            UpdateUserRole, UserModel,
+        },
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/api/users",
+    tag = "users",
+    responses(
+        (status = 200, body = GenericResponse<Vec<users::Model>>),
+        (status = 403, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn get_all_users() {}
+
+#[utoipa::path(
+    delete,
+    path = "/api/users",
+    tag = "users",
+    request_body = DeleteRequest,
+    responses(
+        (status = 200, body = GenericResponse<String>),
+        (status = 403, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn delete() {}
+
+#[utoipa::path(
+    patch,
+    path = "/api/users",
+    tag = "users",
+    request_body = UpdateUserRequest,
+    responses((status = 200, body = GenericResponse<UserModel>))
+)]
+fn update_self() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/users/avatar",
+    tag = "users",
+    request_body(content = [u8], description = "Image file", content_type = "application/octet-stream"),
+    responses(
+        (status = 200, body = GenericResponse<UserModel>),
+        (status = 400, description = "Not an image", body = GenericResponse<ErrorBody>),
+        (status = 413, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn upload_avatar() {}
+
+#[utoipa::path(
+    patch,
+    path = "/api/users/update-password",
+    tag = "users",
+    request_body = UpdateUserPassword,
+    responses(
+        (status = 200, body = GenericResponse<UserModel>),
+        (status = 401, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn update_password() {}
+
+#[utoipa::path(
+    patch,
+    path = "/api/users/update-role",
+    tag = "users",
+    request_body = UpdateUserRole,
+    responses(
+        (status = 200, body = GenericResponse<UserModel>),
+        (status = 403, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn update_role() {}
+
+#[utoipa::path(
+    patch,
+    path = "/api/users/reset-totp",
+    tag = "users",
+    request_body = ResetTotpRequest,
+    responses(
+        (status = 200, body = GenericResponse<String>),
+        (status = 403, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn reset_totp() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/users/totp/setup",
+    tag = "users",
+    responses(
+        (status = 200, body = GenericResponse<TotpSetupResponse>),
+        (status = 409, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn setup_totp() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/users/totp/activate",
+    tag = "users",
+    request_body = TotpCodeRequest,
+    responses(
+        (status = 200, description = "Recovery codes", body = GenericResponse<Vec<String>>),
+        (status = 401, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn activate_totp() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/users/totp/disable",
+    tag = "users",
+    request_body = TotpCodeRequest,
+    responses(
+        (status = 200, body = GenericResponse<String>),
+        (status = 401, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn disable_totp() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/users/invite-staff",
+    tag = "users",
+    request_body = InviteStaffRequest,
+    responses(
+        (status = 200, body = GenericResponse<String>),
+        (status = 403, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn invite_staff() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/users/accept-staff-invite",
+    tag = "users",
+    request_body = AcceptStaffInviteRequest,
+    responses(
+        (status = 200, body = GenericResponse<UserModel>),
+        (status = 400, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn accept_staff_invite() {}
+
+#[utoipa::path(
+    get,
+    path = "/api/users/tokens",
+    tag = "users",
+    responses((status = 200, body = GenericResponse<Vec<ApiTokenModel>>))
+)]
+fn get_all_tokens() {}
+
+#[utoipa::path(
+    post,
+    path = "/api/users/tokens",
+    tag = "users",
+    request_body = CreateApiTokenRequest,
+    responses((status = 200, body = GenericResponse<CreatedApiTokenResponse>))
+)]
+fn create_token() {}
+
+#[utoipa::path(
+    delete,
+    path = "/api/users/tokens/{id}",
+    tag = "users",
+    params(("id" = Uuid, Path, description = "API token id")),
+    responses(
+        (status = 200, body = GenericResponse<String>),
+        (status = 404, body = GenericResponse<ErrorBody>)
+    )
+)]
+fn revoke_token() {}
@@ -0,0 +1,170 @@
+use {
+    super::{lockout_secs, take_token, RateLimitStore},
+    crate::{
+        config::{BucketConfig, LockoutConfig},
+        enums::error::*,
+    },
+    async_trait::async_trait,
+    parking_lot::Mutex,
+    std::{
+        collections::HashMap,
+        time::{Duration, Instant},
+    },
+};
+
+// dọn các key cũ khi map lớn hơn ngưỡng này
+const PRUNE_THRESHOLD: usize = 100_000;
+const PRUNE_IDLE: Duration = Duration::from_secs(3600);
+
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+struct Failures {
+    count: u32,
+    locked_until: Option<Instant>,
+    updated_at: Instant,
+}
+
+// Chỉ dùng cho một instance, state mất khi restart
+#[derive(Default)]
+pub struct MemoryStore {
+    buckets: Mutex<HashMap<String, Bucket>>,
+    failures: Mutex<HashMap<String, Failures>>,
+}
+
+#[async_trait]
+impl RateLimitStore for MemoryStore {
+    async fn take(&self, key: &str, cfg: &BucketConfig) -> Result<Option<u64>> {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock();
+
+        if buckets.len() > PRUNE_THRESHOLD {
+            buckets.retain(|_, b| now.duration_since(b.updated_at) < PRUNE_IDLE);
+        }
+
+        let bucket = buckets.entry(key.to_string()).or_insert(Bucket {
+            tokens: cfg.capacity as f64,
+            updated_at: now,
+        });
+        let elapsed = now.duration_since(bucket.updated_at).as_secs_f64();
+        let (tokens, retry_after) = take_token(bucket.tokens, elapsed, cfg);
+        bucket.tokens = tokens;
+        bucket.updated_at = now;
+
+        Ok(retry_after)
+    }
+
+    async fn locked_for(&self, key: &str) -> Result<Option<u64>> {
+        let now = Instant::now();
+
+        Ok(self
+            .failures
+            .lock()
+            .get(key)
+            .and_then(|f| f.locked_until)
+            .filter(|until| *until > now)
+            .map(|until| until.duration_since(now).as_secs().max(1)))
+    }
+
+    async fn record_failure(&self, key: &str, cfg: &LockoutConfig) -> Result<()> {
+        let now = Instant::now();
+        let mut failures = self.failures.lock();
+
+        if failures.len() > PRUNE_THRESHOLD {
+            failures.retain(|_, f| now.duration_since(f.updated_at) < PRUNE_IDLE);
+        }
+
+        let entry = failures.entry(key.to_string()).or_insert(Failures {
+            count: 0,
+            locked_until: None,
+            updated_at: now,
+        });
+        if now.duration_since(entry.updated_at) > Duration::from_secs(cfg.window_secs) {
+            entry.count = 0;
+        }
+        entry.count += 1;
+        entry.updated_at = now;
+        entry.locked_until =
+            lockout_secs(entry.count, cfg).map(|secs| now + Duration::from_secs(secs));
+
+        Ok(())
+    }
+
+    async fn reset_failures(&self, key: &str) -> Result<()> {
+        self.failures.lock().remove(key);
+
+        Ok(())
+    }
+
+    async fn prune(&self, bucket_idle_secs: u64, cfg: &LockoutConfig) -> Result<u64> {
+        let now = Instant::now();
+        let bucket_idle = Duration::from_secs(bucket_idle_secs);
+        let window = Duration::from_secs(cfg.window_secs);
+
+        let mut buckets = self.buckets.lock();
+        let before = buckets.len();
+        buckets.retain(|_, b| now.duration_since(b.updated_at) < bucket_idle);
+        let mut pruned = before - buckets.len();
+        drop(buckets);
+
+        let mut failures = self.failures.lock();
+        let before = failures.len();
+        failures.retain(|_, f| {
+            now.duration_since(f.updated_at) < window
+                || f.locked_until.is_some_and(|until| until > now)
+        });
+        pruned += before - failures.len();
+
+        Ok(pruned as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_lockout_after_max_failures() {
+        let store = MemoryStore::default();
+        let cfg = LockoutConfig {
+            max_failures: 2,
+            ..Default::default()
+        };
+
+        store.record_failure("a", &cfg).await.unwrap();
+        assert_eq!(store.locked_for("a").await.unwrap(), None);
+
+        store.record_failure("a", &cfg).await.unwrap();
+        assert!(store.locked_for("a").await.unwrap().is_some());
+
+        store.reset_failures("a").await.unwrap();
+        assert_eq!(store.locked_for("a").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_prune_keeps_active_keys() {
+        let store = MemoryStore::default();
+        let bucket = BucketConfig {
+            capacity: 1,
+            refill_per_sec: 1.0,
+        };
+        let cfg = LockoutConfig {
+            max_failures: 1,
+            ..Default::default()
+        };
+
+        store.take("ip", &bucket).await.unwrap();
+        store.record_failure("a", &cfg).await.unwrap();
+        assert_eq!(store.prune(60, &cfg).await.unwrap(), 0);
+
+        // bucket đầy lại ngay, lần sai hết cửa sổ nhưng vẫn đang bị khoá
+        let expired = LockoutConfig {
+            window_secs: 0,
+            ..cfg
+        };
+        assert_eq!(store.prune(0, &expired).await.unwrap(), 1);
+        assert!(store.locked_for("a").await.unwrap().is_some());
+    }
+}
@@ -0,0 +1,205 @@
+use {
+    crate::{
+        config::{BucketConfig, LockoutConfig, RateLimitConfig, RateLimitStoreKind},
+        db::db_connection::Database,
+        enums::error::*,
+    },
+    async_trait::async_trait,
+    memory_store::MemoryStore,
+    postgres_store::PostgresStore,
+    std::{net::IpAddr, sync::Arc},
+};
+
+pub mod memory_store;
+pub mod postgres_store;
+
+#[async_trait]
+pub trait RateLimitStore: Send + Sync {
+    // Lấy 1 token của key, trả về số giây phải chờ nếu bucket đã cạn
+    async fn take(&self, key: &str, cfg: &BucketConfig) -> Result<Option<u64>>;
+    // Số giây key còn bị khoá
+    async fn locked_for(&self, key: &str) -> Result<Option<u64>>;
+    async fn record_failure(&self, key: &str, cfg: &LockoutConfig) -> Result<()>;
+    async fn reset_failures(&self, key: &str) -> Result<()>;
+    // Xoá bucket không dùng quá `bucket_idle_secs` (đã đầy lại như mới) và các
+    // lần sai đã hết cửa sổ đếm, không còn khoá. Trả về số key đã xoá
+    async fn prune(&self, bucket_idle_secs: u64, cfg: &LockoutConfig) -> Result<u64>;
+}
+
+pub struct RateLimiter {
+    cfg: RateLimitConfig,
+    store: Arc<dyn RateLimitStore>,
+}
+
+impl RateLimiter {
+    pub fn new(cfg: RateLimitConfig, store: Arc<dyn RateLimitStore>) -> Self {
+        Self { cfg, store }
+    }
+
+    async fn check(&self, key: &str, bucket: &BucketConfig) -> Result<()> {
+        if !self.cfg.enabled {
+            return Ok(());
+        }
+
+        match self.store.take(key, bucket).await? {
+            Some(retry_after) => Err(Error::TooManyRequests(retry_after)),
+            None => Ok(()),
+        }
+    }
+
+    pub async fn check_auth_ip(&self, ip: IpAddr) -> Result<()> {
+        self.check(&format!("auth:ip:{ip}"), &self.cfg.auth_per_ip)
+            .await
+    }
+
+    pub async fn check_write_ip(&self, ip: IpAddr) -> Result<()> {
+        self.check(&format!("write:ip:{ip}"), &self.cfg.write_per_ip)
+            .await
+    }
+
+    // Chặn account đang bị khoá hoặc bị gọi quá nhiều
+    pub async fn check_account(&self, email: &str) -> Result<()> {
+        if !self.cfg.enabled {
+            return Ok(());
+        }
+
+        let key = account_key(email);
+        if let Some(retry_after) = self.store.locked_for(&key).await? {
+            return Err(Error::TooManyRequests(retry_after));
+        }
+
+        self.check(&format!("auth:{key}"), &self.cfg.auth_per_account)
+            .await
+    }
+
+    pub async fn login_failed(&self, email: &str) -> Result<()> {
+        if !self.cfg.enabled {
+            return Ok(());
+        }
+
+        self.store
+            .record_failure(&account_key(email), &self.cfg.lockout)
+            .await
+    }
+
+    pub async fn login_succeeded(&self, email: &str) -> Result<()> {
+        if !self.cfg.enabled {
+            return Ok(());
+        }
+
+        self.store.reset_failures(&account_key(email)).await
+    }
+
+    pub async fn prune(&self) -> Result<u64> {
+        let bucket_idle_secs = [
+            &self.cfg.auth_per_ip,
+            &self.cfg.auth_per_account,
+            &self.cfg.write_per_ip,
+        ]
+        .into_iter()
+        .map(refill_secs)
+        .max()
+        .unwrap_or_default();
+
+        self.store.prune(bucket_idle_secs, &self.cfg.lockout).await
+    }
+}
+
+pub fn init_rate_limiter(cfg: &RateLimitConfig, db: Arc<Database>) -> Arc<RateLimiter> {
+    let store: Arc<dyn RateLimitStore> = match cfg.store {
+        RateLimitStoreKind::Memory => Arc::new(MemoryStore::default()),
+        RateLimitStoreKind::Postgres => Arc::new(PostgresStore::new(db)),
+    };
+
+    Arc::new(RateLimiter::new(cfg.clone(), store))
+}
+
+// Thời gian để bucket cạn đầy lại, bucket bỏ không lâu hơn thì xoá cũng như
+// nhau
+fn refill_secs(cfg: &BucketConfig) -> u64 {
+    if cfg.refill_per_sec <= 0.0 {
+        return u64::MAX;
+    }
+
+    (cfg.capacity as f64 / cfg.refill_per_sec).ceil() as u64
+}
+
+fn account_key(email: &str) -> String {
+    format!("account:{}", email.trim().to_lowercase())
+}
+
+// Nạp lại token theo thời gian đã trôi qua rồi lấy 1 token.
+// Trả về số token còn lại và số giây phải chờ nếu bucket đã cạn.
+pub fn take_token(tokens: f64, elapsed_secs: f64, cfg: &BucketConfig) -> (f64, Option<u64>) {
+    let tokens = (tokens + elapsed_secs.max(0.0) * cfg.refill_per_sec).min(cfg.capacity as f64);
+
+    if tokens >= 1.0 {
+        return (tokens - 1.0, None);
+    }
+
+    let retry_after = if cfg.refill_per_sec > 0.0 {
+        ((1.0 - tokens) / cfg.refill_per_sec).ceil() as u64
+    } else {
+        u64::MAX
+    };
+
+    (tokens, Some(retry_after.max(1)))
+}
+
+// Số giây khoá sau lần sai thứ `failures`, nhân đôi sau mỗi lần vượt ngưỡng
+pub fn lockout_secs(failures: u32, cfg: &LockoutConfig) -> Option<u64> {
+    if failures < cfg.max_failures {
+        return None;
+    }
+
+    let exp = (failures - cfg.max_failures).min(16);
+    Some(cfg.base_secs.saturating_mul(1 << exp).min(cfg.max_secs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_take_token() {
+        let cfg = BucketConfig {
+            capacity: 2,
+            refill_per_sec: 0.5,
+        };
+
+        assert_eq!(take_token(2.0, 0.0, &cfg), (1.0, None));
+        assert_eq!(take_token(0.0, 0.0, &cfg), (0.0, Some(2)));
+        // nạp lại không vượt quá capacity
+        assert_eq!(take_token(0.0, 100.0, &cfg), (1.0, None));
+    }
+
+    #[test]
+    fn test_lockout_secs() {
+        let cfg = LockoutConfig {
+            max_failures: 3,
+            base_secs: 10,
+            max_secs: 60,
+            window_secs: 3600,
+        };
+
+        assert_eq!(lockout_secs(2, &cfg), None);
+        assert_eq!(lockout_secs(3, &cfg), Some(10));
+        assert_eq!(lockout_secs(4, &cfg), Some(20));
+        assert_eq!(lockout_secs(10, &cfg), Some(60));
+    }
+
+    #[test]
+    fn test_refill_secs() {
+        let bucket = |capacity, refill_per_sec| {
+            BucketConfig {
+                capacity,
+                refill_per_sec,
+            }
+        };
+
+        assert_eq!(refill_secs(&bucket(5, 0.05)), 100);
+        assert_eq!(refill_secs(&bucket(10, 3.0)), 4);
+        // không nạp lại thì bucket cạn không bao giờ được xoá
+        assert_eq!(refill_secs(&bucket(10, 0.0)), u64::MAX);
+    }
+}
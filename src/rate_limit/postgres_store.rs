@@ -0,0 +1,247 @@
+use {
+    super::{lockout_secs, take_token, RateLimitStore},
+    crate::{
+        config::{BucketConfig, LockoutConfig},
+        db::db_connection::Database,
+        entities::{login_failures, prelude::*, rate_limit_buckets},
+        enums::error::*,
+    },
+    async_trait::async_trait,
+    chrono::{Duration, NaiveDateTime, Utc},
+    sea_orm::{
+        sea_query::OnConflict, ActiveModelTrait, ColumnTrait, Condition, DeleteMany, EntityTrait,
+        QueryFilter, QuerySelect, Set, TransactionTrait,
+    },
+    std::sync::Arc,
+};
+
+// Lưu state trong postgres để nhiều instance dùng chung
+pub struct PostgresStore {
+    db: Arc<Database>,
+}
+
+impl PostgresStore {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl RateLimitStore for PostgresStore {
+    async fn take(&self, key: &str, cfg: &BucketConfig) -> Result<Option<u64>> {
+        let txn = self
+            .db
+            .get_connection()
+            .await
+            .begin()
+            .await
+            .map_err(Error::BeginTransactionFailed)?;
+        let now = Utc::now().naive_utc();
+
+        RateLimitBuckets::insert(rate_limit_buckets::ActiveModel {
+            key: Set(key.to_string()),
+            tokens: Set(cfg.capacity as f64),
+            updated_at: Set(now),
+        })
+        .on_conflict(
+            OnConflict::column(rate_limit_buckets::Column::Key)
+                .do_nothing()
+                .to_owned(),
+        )
+        .do_nothing()
+        .exec(&txn)
+        .await
+        .map_err(Error::InsertFailed)?;
+
+        // khoá row để các instance không cùng lấy một token
+        let bucket = RateLimitBuckets::find_by_id(key.to_string())
+            .lock_exclusive()
+            .one(&txn)
+            .await
+            .map_err(Error::QueryFailed)?
+            .ok_or(Error::RecordNotFound)?;
+
+        let elapsed = (now - bucket.updated_at).num_milliseconds() as f64 / 1000.0;
+        let (tokens, retry_after) = take_token(bucket.tokens, elapsed, cfg);
+
+        let mut bucket: rate_limit_buckets::ActiveModel = bucket.into();
+        bucket.tokens = Set(tokens);
+        bucket.updated_at = Set(now);
+        bucket.update(&txn).await.map_err(Error::UpdateFailed)?;
+
+        txn.commit().await.map_err(Error::CommitTransactionFailed)?;
+
+        Ok(retry_after)
+    }
+
+    async fn locked_for(&self, key: &str) -> Result<Option<u64>> {
+        let conn = self.db.get_connection().await;
+        let now = Utc::now().naive_utc();
+
+        Ok(LoginFailures::find_by_id(key.to_string())
+            .one(&conn)
+            .await
+            .map_err(Error::QueryFailed)?
+            .and_then(|f| f.locked_until)
+            .filter(|until| *until > now)
+            .map(|until| ((until - now).num_seconds() as u64).max(1)))
+    }
+
+    async fn record_failure(&self, key: &str, cfg: &LockoutConfig) -> Result<()> {
+        let txn = self
+            .db
+            .get_connection()
+            .await
+            .begin()
+            .await
+            .map_err(Error::BeginTransactionFailed)?;
+        let now = Utc::now().naive_utc();
+
+        LoginFailures::insert(login_failures::ActiveModel {
+            key: Set(key.to_string()),
+            failures: Set(0),
+            locked_until: Set(None),
+            updated_at: Set(now),
+        })
+        .on_conflict(
+            OnConflict::column(login_failures::Column::Key)
+                .do_nothing()
+                .to_owned(),
+        )
+        .do_nothing()
+        .exec(&txn)
+        .await
+        .map_err(Error::InsertFailed)?;
+
+        let failure = LoginFailures::find_by_id(key.to_string())
+            .lock_exclusive()
+            .one(&txn)
+            .await
+            .map_err(Error::QueryFailed)?
+            .ok_or(Error::RecordNotFound)?;
+
+        let count = if now - failure.updated_at > Duration::seconds(cfg.window_secs as i64) {
+            1
+        } else {
+            failure.failures as u32 + 1
+        };
+
+        let mut failure: login_failures::ActiveModel = failure.into();
+        failure.failures = Set(count as i32);
+        failure.locked_until =
+            Set(lockout_secs(count, cfg).map(|secs| now + Duration::seconds(secs as i64)));
+        failure.updated_at = Set(now);
+        failure.update(&txn).await.map_err(Error::UpdateFailed)?;
+
+        txn.commit().await.map_err(Error::CommitTransactionFailed)?;
+
+        Ok(())
+    }
+
+    async fn reset_failures(&self, key: &str) -> Result<()> {
+        let conn = self.db.get_connection().await;
+
+        LoginFailures::delete_by_id(key.to_string())
+            .exec(&conn)
+            .await
+            .map_err(Error::DeleteFailed)?;
+
+        Ok(())
+    }
+
+    async fn prune(&self, bucket_idle_secs: u64, cfg: &LockoutConfig) -> Result<u64> {
+        let conn = self.db.get_connection().await;
+        let now = Utc::now().naive_utc();
+
+        let mut pruned = 0;
+        if let Some(before) = ago(now, bucket_idle_secs) {
+            pruned += stale_buckets(before)
+                .exec(&conn)
+                .await
+                .map_err(Error::DeleteFailed)?
+                .rows_affected;
+        }
+        if let Some(before) = ago(now, cfg.window_secs) {
+            pruned += stale_failures(before, now)
+                .exec(&conn)
+                .await
+                .map_err(Error::DeleteFailed)?
+                .rows_affected;
+        }
+
+        Ok(pruned)
+    }
+}
+
+// `None` khi khoảng thời gian quá lớn, coi như chưa có gì cũ tới mức đó
+fn ago(now: NaiveDateTime, secs: u64) -> Option<NaiveDateTime> {
+    i64::try_from(secs)
+        .ok()
+        .and_then(Duration::try_seconds)
+        .and_then(|secs| now.checked_sub_signed(secs))
+}
+
+fn stale_buckets(before: NaiveDateTime) -> DeleteMany<RateLimitBuckets> {
+    RateLimitBuckets::delete_many().filter(rate_limit_buckets::Column::UpdatedAt.lt(before))
+}
+
+fn stale_failures(before: NaiveDateTime, now: NaiveDateTime) -> DeleteMany<LoginFailures> {
+    LoginFailures::delete_many().filter(
+        Condition::all()
+            .add(login_failures::Column::UpdatedAt.lt(before))
+            .add(
+                Condition::any()
+                    .add(login_failures::Column::LockedUntil.is_null())
+                    .add(login_failures::Column::LockedUntil.lte(now)),
+            ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        chrono::NaiveDate,
+        sea_orm::{DbBackend, QueryTrait},
+    };
+
+    fn at(hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 10, 1)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn prune_keeps_buckets_used_recently() {
+        let sql = stale_buckets(at(8)).build(DbBackend::Postgres).to_string();
+
+        // tuỳ bản sea-query mà literal có thêm phần micro giây, chỉ so tới giây
+        assert!(
+            sql.contains(r#"WHERE "rate_limit_buckets"."updated_at" < '2026-10-01 08:00:00"#),
+            "{sql}"
+        );
+    }
+
+    #[test]
+    fn prune_keeps_accounts_still_locked() {
+        let sql = stale_failures(at(8), at(9))
+            .build(DbBackend::Postgres)
+            .to_string();
+
+        assert!(
+            sql.contains(r#""login_failures"."updated_at" < '2026-10-01 08:00:00"#),
+            "{sql}"
+        );
+        assert!(
+            sql.contains(r#"("login_failures"."locked_until" IS NULL OR "login_failures"."locked_until" <= '2026-10-01 09:00:00"#),
+            "{sql}"
+        );
+    }
+
+    #[test]
+    fn prune_skips_unbounded_idle() {
+        assert_eq!(ago(at(9), u64::MAX), None);
+        assert_eq!(ago(at(9), 3600), Some(at(8)));
+    }
+}
@@ -0,0 +1,149 @@
+use {
+    crate::{
+        db::db_connection::Database,
+        entities::{api_tokens, prelude::*, users},
+        enums::error::*,
+    },
+    chrono::{Duration, NaiveDateTime, Utc},
+    sea_orm::{
+        sea_query::Expr, ActiveModelTrait, ColumnTrait, Condition, EntityTrait, QueryFilter,
+        QueryOrder, Set,
+    },
+    serde_json::Value as JsonValue,
+    std::sync::Arc,
+    uuid::Uuid,
+};
+
+// last_used_at chỉ cập nhật khi cũ hơn khoảng này để tránh ghi DB mỗi request
+const TOUCH_INTERVAL_SECS: i64 = 60;
+
+pub struct ApiTokenRepository {
+    db: Arc<Database>,
+}
+
+impl ApiTokenRepository {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    pub async fn create_one(
+        &self,
+        user_id: Uuid,
+        name: String,
+        token_hash: String,
+        prefix: String,
+        scopes: JsonValue,
+        expires_at: Option<NaiveDateTime>,
+    ) -> Result<api_tokens::Model> {
+        let conn = self.db.get_connection().await;
+
+        api_tokens::ActiveModel {
+            user_id: Set(user_id),
+            name: Set(name),
+            token_hash: Set(token_hash),
+            prefix: Set(prefix),
+            scopes: Set(scopes),
+            expires_at: Set(expires_at),
+            ..Default::default()
+        }
+        .insert(&conn)
+        .await
+        .map_err(Error::InsertFailed)
+    }
+
+    pub async fn get_all_of_user(&self, user_id: Uuid) -> Result<Vec<api_tokens::Model>> {
+        let conn = self.db.get_connection().await;
+
+        ApiTokens::find()
+            .filter(
+                Condition::all()
+                    .add(api_tokens::Column::UserId.eq(user_id))
+                    .add(api_tokens::Column::RevokedAt.is_null()),
+            )
+            .order_by_desc(api_tokens::Column::CreatedAt)
+            .all(&conn)
+            .await
+            .map_err(Error::QueryFailed)
+    }
+
+    pub async fn revoke(&self, user_id: Uuid, token_id: Uuid) -> Result<()> {
+        let conn = self.db.get_connection().await;
+
+        let res = ApiTokens::update_many()
+            .col_expr(
+                api_tokens::Column::RevokedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(
+                Condition::all()
+                    .add(api_tokens::Column::Id.eq(token_id))
+                    .add(api_tokens::Column::UserId.eq(user_id))
+                    .add(api_tokens::Column::RevokedAt.is_null()),
+            )
+            .exec(&conn)
+            .await
+            .map_err(Error::UpdateFailed)?;
+
+        if res.rows_affected == 0 {
+            return Err(Error::RecordNotFound);
+        }
+
+        Ok(())
+    }
+
+    // Token còn hiệu lực cùng với user sở hữu
+    pub async fn find_active_by_hash(
+        &self,
+        token_hash: String,
+    ) -> Result<Option<(api_tokens::Model, users::Model)>> {
+        let conn = self.db.get_connection().await;
+        let now = Utc::now().naive_utc();
+
+        let Some((token, Some(user))) = ApiTokens::find()
+            .find_also_related(Users)
+            .filter(
+                Condition::all()
+                    .add(api_tokens::Column::TokenHash.eq(token_hash))
+                    .add(api_tokens::Column::RevokedAt.is_null())
+                    .add(
+                        Condition::any()
+                            .add(api_tokens::Column::ExpiresAt.is_null())
+                            .add(api_tokens::Column::ExpiresAt.gt(now)),
+                    )
+                    .add(users::Column::IsDeleted.eq(false)),
+            )
+            .one(&conn)
+            .await
+            .map_err(Error::QueryFailed)?
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some((token, user)))
+    }
+
+    pub async fn touch(&self, token_id: Uuid) -> Result<()> {
+        let conn = self.db.get_connection().await;
+        let now = Utc::now().naive_utc();
+
+        ApiTokens::update_many()
+            .col_expr(api_tokens::Column::LastUsedAt, Expr::value(now))
+            .filter(
+                Condition::all()
+                    .add(api_tokens::Column::Id.eq(token_id))
+                    .add(
+                        Condition::any()
+                            .add(api_tokens::Column::LastUsedAt.is_null())
+                            .add(
+                                api_tokens::Column::LastUsedAt
+                                    .lt(now - Duration::seconds(TOUCH_INTERVAL_SECS)),
+                            ),
+                    ),
+            )
+            .exec(&conn)
+            .await
+            .map_err(Error::UpdateFailed)?;
+
+        Ok(())
+    }
+}
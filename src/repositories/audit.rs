@@ -0,0 +1,95 @@
+use {
+    crate::{
+        db::db_connection::Database,
+        entities::{audit_events, prelude::*},
+        enums::{error::*, generic::PaginatedResponse},
+        models::audit::{AuditEvent, QueryAuditParams},
+        utils::helpers::json_diff,
+    },
+    sea_orm::{
+        ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, Set,
+    },
+    std::sync::Arc,
+};
+
+pub struct AuditRepository {
+    db: Arc<Database>,
+}
+
+impl AuditRepository {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    // Chỉ có insert, bảng audit_events không cho UPDATE/DELETE
+    pub async fn create_one(&self, event: AuditEvent) -> Result<audit_events::Model> {
+        let conn = self.db.get_connection().await;
+
+        let changes = match (&event.before, &event.after) {
+            (Some(before), Some(after)) => json_diff(before, after),
+            _ => None,
+        };
+
+        audit_events::ActiveModel {
+            actor_id: Set(event.actor_id),
+            action: Set(event.action.as_str().to_string()),
+            resource_type: Set(event.resource_type.as_str().to_string()),
+            resource_id: Set(event.resource_id),
+            before: Set(event.before),
+            after: Set(event.after),
+            changes: Set(changes),
+            ..Default::default()
+        }
+        .insert(&conn)
+        .await
+        .map_err(Error::InsertFailed)
+    }
+
+    pub async fn get_all(
+        &self,
+        params: QueryAuditParams,
+    ) -> Result<PaginatedResponse<audit_events::Model>> {
+        let conn = self.db.get_connection().await;
+
+        let mut query = AuditEvents::find();
+
+        if let Some(actor_id) = params.actor_id {
+            query = query.filter(audit_events::Column::ActorId.eq(actor_id));
+        }
+        if let Some(action) = params.action {
+            query = query.filter(audit_events::Column::Action.eq(action.as_str()));
+        }
+        if let Some(resource_type) = params.resource_type {
+            query = query.filter(audit_events::Column::ResourceType.eq(resource_type.as_str()));
+        }
+        if let Some(resource_id) = params.resource_id {
+            query = query.filter(audit_events::Column::ResourceId.eq(resource_id));
+        }
+        if let Some(from) = params.from {
+            query = query.filter(audit_events::Column::CreatedAt.gte(from));
+        }
+        if let Some(to) = params.to {
+            query = query.filter(audit_events::Column::CreatedAt.lt(to));
+        }
+
+        let page = params.page.unwrap_or(1).max(1);
+        let page_size = params.page_size.unwrap_or(20).clamp(1, 100);
+
+        let paginator = query
+            .order_by_desc(audit_events::Column::CreatedAt)
+            .paginate(&conn, page_size);
+        let total_pages = paginator.num_pages().await.unwrap_or(1);
+
+        let res = paginator
+            .fetch_page(page - 1)
+            .await
+            .map_err(Error::QueryFailed)?;
+
+        Ok(PaginatedResponse {
+            total_pages,
+            current_page: page,
+            page_size,
+            data: res,
+        })
+    }
+}
@@ -0,0 +1,200 @@
+use {
+    crate::{
+        db::db_connection::Database,
+        entities::{
+            change_requests, prelude::ChangeRequests, questions,
+            sea_orm_active_enums::ChangeRequestStatusEnum,
+        },
+        enums::{error::*, generic::PaginatedResponse},
+        models::qna::UpdateQuestionRequest,
+        repositories::question::update_question_in,
+    },
+    chrono::Utc,
+    sea_orm::{
+        ActiveModelTrait, ColumnTrait, Condition, DatabaseTransaction, EntityTrait, PaginatorTrait,
+        QueryFilter, QueryOrder, Set, TransactionTrait, UpdateMany,
+    },
+    std::sync::Arc,
+    uuid::Uuid,
+};
+
+pub struct ChangeRequestRepository {
+    db: Arc<Database>,
+}
+
+impl ChangeRequestRepository {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    async fn begin(&self) -> Result<DatabaseTransaction> {
+        self.db
+            .get_connection()
+            .await
+            .begin()
+            .await
+            .map_err(Error::BeginTransactionFailed)
+    }
+
+    pub async fn get_by_id(&self, id: Uuid) -> Result<change_requests::Model> {
+        let conn = self.db.get_connection().await;
+
+        ChangeRequests::find_by_id(id)
+            .one(&conn)
+            .await
+            .map_err(Error::QueryFailed)?
+            .ok_or(Error::RecordNotFound)
+    }
+
+    // Lịch sử đề xuất theo `condition` (set hoặc câu hỏi), mới nhất trước
+    pub async fn get_all(
+        &self,
+        condition: Condition,
+        status: Option<ChangeRequestStatusEnum>,
+        page: u64,
+        page_size: u64,
+    ) -> Result<PaginatedResponse<change_requests::Model>> {
+        let conn = self.db.get_connection().await;
+        let page = page.max(1);
+
+        let mut condition = condition;
+        if let Some(status) = status {
+            condition = condition.add(change_requests::Column::Status.eq(status));
+        }
+
+        let paginator = ChangeRequests::find()
+            .filter(condition)
+            .order_by_desc(change_requests::Column::CreatedAt)
+            .paginate(&conn, page_size);
+        let total_pages = paginator.num_pages().await.unwrap_or(1);
+        let res = paginator
+            .fetch_page(page - 1)
+            .await
+            .map_err(Error::QueryFailed)?;
+
+        Ok(PaginatedResponse {
+            total_pages,
+            current_page: page,
+            page_size,
+            data: res,
+        })
+    }
+
+    pub async fn create_one(
+        &self,
+        change_request: change_requests::ActiveModel,
+    ) -> Result<change_requests::Model> {
+        let conn = self.db.get_connection().await;
+
+        change_request
+            .insert(&conn)
+            .await
+            .map_err(Error::InsertFailed)
+    }
+
+    // Đóng đề xuất còn Pending, hai người duyệt cùng lúc thì người sau nhận
+    // `ChangeRequestClosed`
+    pub async fn close(
+        &self,
+        id: Uuid,
+        status: ChangeRequestStatusEnum,
+        reviewer_id: Option<Uuid>,
+        review_note: Option<String>,
+    ) -> Result<change_requests::Model> {
+        let conn = self.db.get_connection().await;
+
+        let res = close_pending(id, status, reviewer_id, review_note)
+            .exec(&conn)
+            .await
+            .map_err(Error::UpdateFailed)?;
+        if res.rows_affected == 0 {
+            return Err(Error::ChangeRequestClosed);
+        }
+
+        self.get_by_id(id).await
+    }
+
+    // Nhận đề xuất và áp dụng `payload` vào question trong cùng transaction,
+    // áp dụng lỗi thì đề xuất vẫn còn Pending
+    pub async fn accept(
+        &self,
+        id: Uuid,
+        question_id: Uuid,
+        reviewer_id: Uuid,
+        review_note: Option<String>,
+        payload: UpdateQuestionRequest,
+    ) -> Result<(change_requests::Model, Option<questions::Model>)> {
+        let txn = self.begin().await?;
+
+        let res = close_pending(
+            id,
+            ChangeRequestStatusEnum::Accepted,
+            Some(reviewer_id),
+            review_note,
+        )
+        .exec(&txn)
+        .await
+        .map_err(Error::UpdateFailed)?;
+        if res.rows_affected == 0 {
+            return Err(Error::ChangeRequestClosed);
+        }
+
+        let question = update_question_in(&txn, question_id, payload, reviewer_id).await?;
+        let change_request = ChangeRequests::find_by_id(id)
+            .one(&txn)
+            .await
+            .map_err(Error::QueryFailed)?
+            .ok_or(Error::RecordNotFound)?;
+
+        txn.commit().await.map_err(Error::CommitTransactionFailed)?;
+
+        Ok((change_request, question))
+    }
+}
+
+fn close_pending(
+    id: Uuid,
+    status: ChangeRequestStatusEnum,
+    reviewer_id: Option<Uuid>,
+    review_note: Option<String>,
+) -> UpdateMany<ChangeRequests> {
+    ChangeRequests::update_many()
+        .set(change_requests::ActiveModel {
+            status: Set(status),
+            reviewer_id: Set(reviewer_id),
+            review_note: Set(review_note),
+            reviewed_at: Set(Some(Utc::now().naive_utc())),
+            ..Default::default()
+        })
+        .filter(
+            Condition::all()
+                .add(change_requests::Column::Id.eq(id))
+                .add(change_requests::Column::Status.eq(ChangeRequestStatusEnum::Pending)),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        sea_orm::{DbBackend, QueryTrait},
+    };
+
+    #[test]
+    fn close_only_touches_pending_change_request() {
+        let id = Uuid::new_v4();
+        let sql = close_pending(id, ChangeRequestStatusEnum::Accepted, None, None)
+            .build(DbBackend::Postgres)
+            .to_string()
+            .replace('"', "");
+
+        let (_, filter) = sql.split_once("WHERE").unwrap();
+
+        assert!(filter.contains(&format!("id = '{id}'")), "{sql}");
+        // sea-query có bản quote tên enum trong CAST, có bản không
+        assert!(
+            filter.contains("CAST('Pending' AS change_request_status_enum)"),
+            "{sql}"
+        );
+    }
+}
@@ -0,0 +1,281 @@
+use {
+    crate::{
+        db::db_connection::Database,
+        entities::{
+            comment_mentions, comments,
+            prelude::{CommentMentions, Comments},
+        },
+        enums::{error::*, generic::PaginatedResponse},
+        models::comment::{CommentResponse, CommentTarget, CommentThread},
+    },
+    chrono::Utc,
+    sea_orm::{
+        sea_query::Query, ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait,
+        DatabaseTransaction, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
+        Set, TransactionTrait,
+    },
+    std::{collections::HashMap, sync::Arc},
+    uuid::Uuid,
+};
+
+fn target_condition(target: CommentTarget) -> Condition {
+    match target {
+        CommentTarget::Set(set_id) => {
+            Condition::all()
+                .add(comments::Column::SetId.eq(set_id))
+                .add(comments::Column::QuestionId.is_null())
+        }
+        CommentTarget::Question(question_id) => {
+            Condition::all().add(comments::Column::QuestionId.eq(question_id))
+        }
+    }
+}
+
+// Gắn danh sách người được nhắc tới vào từng bình luận
+async fn with_mentions<C: ConnectionTrait>(
+    conn: &C,
+    models: Vec<comments::Model>,
+) -> Result<Vec<CommentResponse>> {
+    let mentions: Vec<(Uuid, Uuid)> = CommentMentions::find()
+        .select_only()
+        .column(comment_mentions::Column::CommentId)
+        .column(comment_mentions::Column::UserId)
+        .filter(comment_mentions::Column::CommentId.is_in(models.iter().map(|c| c.id)))
+        .into_tuple()
+        .all(conn)
+        .await
+        .map_err(Error::QueryFailed)?;
+
+    let mut mention_ids: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for (comment_id, user_id) in mentions {
+        mention_ids.entry(comment_id).or_default().push(user_id);
+    }
+
+    Ok(models
+        .into_iter()
+        .map(|comment| {
+            CommentResponse {
+                mention_ids: mention_ids.remove(&comment.id).unwrap_or_default(),
+                comment,
+            }
+        })
+        .collect())
+}
+
+async fn replace_mentions(
+    txn: &DatabaseTransaction,
+    comment_id: Uuid,
+    mention_ids: Vec<Uuid>,
+) -> Result<()> {
+    CommentMentions::delete_many()
+        .filter(comment_mentions::Column::CommentId.eq(comment_id))
+        .exec(txn)
+        .await
+        .map_err(Error::DeleteFailed)?;
+    if !mention_ids.is_empty() {
+        CommentMentions::insert_many(mention_ids.into_iter().map(|user_id| {
+            comment_mentions::ActiveModel {
+                comment_id: Set(comment_id),
+                user_id: Set(user_id),
+            }
+        }))
+        .exec(txn)
+        .await
+        .map_err(Error::InsertFailed)?;
+    }
+
+    Ok(())
+}
+
+pub struct CommentRepository {
+    db: Arc<Database>,
+}
+
+impl CommentRepository {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    async fn begin(&self) -> Result<DatabaseTransaction> {
+        self.db
+            .get_connection()
+            .await
+            .begin()
+            .await
+            .map_err(Error::BeginTransactionFailed)
+    }
+
+    pub async fn get_by_id(&self, id: Uuid) -> Result<comments::Model> {
+        let conn = self.db.get_connection().await;
+
+        Comments::find_by_id(id)
+            .one(&conn)
+            .await
+            .map_err(Error::QueryFailed)?
+            .ok_or(Error::RecordNotFound)
+    }
+
+    // Phân trang theo bình luận gốc mới nhất trước, trả lời kèm theo đầy đủ
+    pub async fn get_threads(
+        &self,
+        target: CommentTarget,
+        page: u64,
+        page_size: u64,
+    ) -> Result<PaginatedResponse<CommentThread>> {
+        let conn = self.db.get_connection().await;
+        let page = page.max(1);
+
+        let paginator = Comments::find()
+            .filter(target_condition(target).add(comments::Column::ParentId.is_null()))
+            .order_by_desc(comments::Column::CreatedAt)
+            .paginate(&conn, page_size);
+        let total_pages = paginator.num_pages().await.unwrap_or(1);
+        let roots = paginator
+            .fetch_page(page - 1)
+            .await
+            .map_err(Error::QueryFailed)?;
+
+        let replies = Comments::find()
+            .filter(comments::Column::ParentId.is_in(roots.iter().map(|c| c.id)))
+            .order_by_asc(comments::Column::CreatedAt)
+            .all(&conn)
+            .await
+            .map_err(Error::QueryFailed)?;
+
+        let mut replies_of: HashMap<Uuid, Vec<CommentResponse>> = HashMap::new();
+        for reply in with_mentions(&conn, replies).await? {
+            if let Some(parent_id) = reply.comment.parent_id {
+                replies_of.entry(parent_id).or_default().push(reply);
+            }
+        }
+
+        let data = with_mentions(&conn, roots)
+            .await?
+            .into_iter()
+            .map(|comment| {
+                CommentThread {
+                    replies: replies_of.remove(&comment.comment.id).unwrap_or_default(),
+                    comment,
+                }
+            })
+            .collect();
+
+        Ok(PaginatedResponse {
+            total_pages,
+            current_page: page,
+            page_size,
+            data,
+        })
+    }
+
+    // Bình luận nhắc tới user, mới nhất trước
+    pub async fn get_mentions(
+        &self,
+        user_id: Uuid,
+        page: u64,
+        page_size: u64,
+    ) -> Result<PaginatedResponse<CommentResponse>> {
+        let conn = self.db.get_connection().await;
+        let page = page.max(1);
+
+        let paginator = Comments::find()
+            .filter(
+                comments::Column::Id.in_subquery(
+                    Query::select()
+                        .column(comment_mentions::Column::CommentId)
+                        .from(CommentMentions)
+                        .and_where(comment_mentions::Column::UserId.eq(user_id))
+                        .to_owned(),
+                ),
+            )
+            .order_by_desc(comments::Column::CreatedAt)
+            .paginate(&conn, page_size);
+        let total_pages = paginator.num_pages().await.unwrap_or(1);
+        let res = paginator
+            .fetch_page(page - 1)
+            .await
+            .map_err(Error::QueryFailed)?;
+
+        Ok(PaginatedResponse {
+            total_pages,
+            current_page: page,
+            page_size,
+            data: with_mentions(&conn, res).await?,
+        })
+    }
+
+    pub async fn create_one(
+        &self,
+        comment: comments::ActiveModel,
+        mention_ids: Vec<Uuid>,
+    ) -> Result<CommentResponse> {
+        let txn = self.begin().await?;
+
+        let comment = comment.insert(&txn).await.map_err(Error::InsertFailed)?;
+        replace_mentions(&txn, comment.id, mention_ids.clone()).await?;
+
+        txn.commit().await.map_err(Error::CommitTransactionFailed)?;
+
+        Ok(CommentResponse {
+            comment,
+            mention_ids,
+        })
+    }
+
+    pub async fn update_one(
+        &self,
+        id: Uuid,
+        content: Option<String>,
+        mention_ids: Option<Vec<Uuid>>,
+    ) -> Result<CommentResponse> {
+        let txn = self.begin().await?;
+
+        let mut comment: comments::ActiveModel = Comments::find_by_id(id)
+            .one(&txn)
+            .await
+            .map_err(Error::QueryFailed)?
+            .ok_or(Error::RecordNotFound)?
+            .into();
+        if let Some(content) = content {
+            comment.content = Set(content);
+        }
+        comment.updated_at = Set(Utc::now().naive_utc());
+        let comment = comment.update(&txn).await.map_err(Error::UpdateFailed)?;
+
+        if let Some(mention_ids) = mention_ids {
+            replace_mentions(&txn, id, mention_ids).await?;
+        }
+        let res = with_mentions(&txn, vec![comment]).await?.remove(0);
+
+        txn.commit().await.map_err(Error::CommitTransactionFailed)?;
+
+        Ok(res)
+    }
+
+    pub async fn set_resolved(
+        &self,
+        id: Uuid,
+        resolved_by: Option<Uuid>,
+    ) -> Result<CommentResponse> {
+        let conn = self.db.get_connection().await;
+
+        let mut comment: comments::ActiveModel = self.get_by_id(id).await?.into();
+        comment.resolved_by = Set(resolved_by);
+        comment.resolved_at = Set(resolved_by.map(|_| Utc::now().naive_utc()));
+        let comment = comment.update(&conn).await.map_err(Error::UpdateFailed)?;
+
+        Ok(with_mentions(&conn, vec![comment]).await?.remove(0))
+    }
+
+    // Xoá bình luận gốc thì xoá luôn các trả lời
+    pub async fn delete_one(&self, id: Uuid) -> Result<()> {
+        let conn = self.db.get_connection().await;
+
+        Comments::delete_by_id(id)
+            .exec(&conn)
+            .await
+            .map_err(Error::DeleteFailed)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+use {
+    crate::{
+        db::db_connection::Database,
+        entities::{
+            prelude::*, questions, quiz_questions, sea_orm_active_enums::*, test_question_results,
+            tests,
+        },
+        enums::error::*,
+        models::difficulty::DifficultyRange,
+        utils::difficulty::Estimate,
+    },
+    sea_orm::{
+        sea_query::Expr, ColumnTrait, Condition, EntityTrait, JoinType, QueryFilter, QuerySelect,
+        RelationTrait, Select, TransactionTrait,
+    },
+    std::{collections::HashMap, sync::Arc},
+    uuid::Uuid,
+};
+
+// Điều kiện độ khó của câu hỏi trong set
+pub fn difficulty_between(range: DifficultyRange) -> Condition {
+    let mut condition = Condition::all();
+    if let Some(min) = range.min {
+        condition = condition.add(questions::Column::Difficulty.gte(min));
+    }
+    if let Some(max) = range.max {
+        condition = condition.add(questions::Column::Difficulty.lte(max));
+    }
+    condition
+}
+
+fn graded_attempts() -> Select<TestQuestionResults> {
+    TestQuestionResults::find()
+        .select_only()
+        .column(tests::Column::UserId)
+        .column(test_question_results::Column::QuizQuestionId)
+        .column(quiz_questions::Column::SampleId)
+        .column(test_question_results::Column::IsCorrect)
+        .join(
+            JoinType::InnerJoin,
+            test_question_results::Relation::Tests.def(),
+        )
+        .join(
+            JoinType::InnerJoin,
+            test_question_results::Relation::QuizQuestions.def(),
+        )
+        .filter(
+            Condition::all()
+                .add(tests::Column::Status.eq(StatusEnum::Submitted))
+                .add(tests::Column::IsPractice.eq(false))
+                .add(test_question_results::Column::IsCorrect.is_not_null()),
+        )
+}
+
+pub struct DifficultyRepository {
+    db: Arc<Database>,
+}
+
+impl DifficultyRepository {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    // (user_id, quiz_question_id, sample_id, is_correct) của các câu đã chấm
+    // trong bài đã nộp, không tính bài luyện tập
+    pub async fn get_graded_attempts(&self) -> Result<Vec<(Uuid, Uuid, Option<Uuid>, bool)>> {
+        let conn = self.db.get_connection().await;
+
+        graded_attempts()
+            .into_tuple()
+            .all(&conn)
+            .await
+            .map_err(Error::QueryFailed)
+    }
+
+    // Ghi đè ước lượng cũ, không đổi `updated_at` vì nội dung không đổi
+    pub async fn save_estimates(
+        &self,
+        questions: &HashMap<Uuid, Estimate>,
+        quiz_questions: &HashMap<Uuid, Estimate>,
+    ) -> Result<()> {
+        let txn = self
+            .db
+            .get_connection()
+            .await
+            .begin()
+            .await
+            .map_err(Error::BeginTransactionFailed)?;
+
+        for (id, estimate) in questions {
+            Questions::update_many()
+                .col_expr(
+                    questions::Column::Difficulty,
+                    Expr::value(estimate.difficulty),
+                )
+                .col_expr(
+                    questions::Column::DifficultyConfidence,
+                    Expr::value(estimate.confidence),
+                )
+                .col_expr(
+                    questions::Column::DifficultyAttempts,
+                    Expr::value(estimate.attempts as i32),
+                )
+                .filter(questions::Column::Id.eq(*id))
+                .exec(&txn)
+                .await
+                .map_err(Error::UpdateFailed)?;
+        }
+        for (id, estimate) in quiz_questions {
+            QuizQuestions::update_many()
+                .col_expr(
+                    quiz_questions::Column::Difficulty,
+                    Expr::value(estimate.difficulty),
+                )
+                .col_expr(
+                    quiz_questions::Column::DifficultyConfidence,
+                    Expr::value(estimate.confidence),
+                )
+                .col_expr(
+                    quiz_questions::Column::DifficultyAttempts,
+                    Expr::value(estimate.attempts as i32),
+                )
+                .filter(quiz_questions::Column::Id.eq(*id))
+                .exec(&txn)
+                .await
+                .map_err(Error::UpdateFailed)?;
+        }
+
+        txn.commit().await.map_err(Error::CommitTransactionFailed)
+    }
+}
+
+#[cfg(test)]
+mod statement_tests {
+    use {
+        super::*,
+        sea_orm::{DbBackend, QueryTrait},
+    };
+
+    #[test]
+    fn practice_attempts_do_not_calibrate_difficulty() {
+        let sql = graded_attempts()
+            .build(DbBackend::Postgres)
+            .to_string()
+            .replace('"', "");
+
+        // sea-query có bản quote tên enum trong CAST, có bản không
+        assert!(sql.contains("CAST('Submitted' AS status_enum)"), "{sql}");
+        assert!(sql.contains("tests.is_practice = FALSE"), "{sql}");
+    }
+}
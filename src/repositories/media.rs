@@ -0,0 +1,148 @@
+use {
+    crate::{
+        db::db_connection::Database,
+        entities::{media, prelude::*, questions},
+        enums::error::*,
+    },
+    sea_orm::{
+        sea_query::Expr, ActiveModelTrait, ColumnTrait, Condition, EntityTrait, QueryFilter,
+        QuerySelect, Select, Set,
+    },
+    serde_json::json,
+    std::sync::Arc,
+    uuid::Uuid,
+};
+
+// Set có câu hỏi hoặc đáp án gắn media. `answers` là cột json nên phải ép sang
+// jsonb mới dùng được `@>`
+fn sets_using(media_id: Uuid) -> Select<Questions> {
+    Questions::find()
+        .select_only()
+        .column(questions::Column::SetId)
+        .distinct()
+        .filter(
+            Condition::all()
+                .add(questions::Column::IsDeleted.eq(false))
+                .add(
+                    Condition::any()
+                        .add(questions::Column::MediaId.eq(media_id))
+                        .add(Expr::cust_with_values(
+                            r#""questions"."answers"::jsonb @> $1::jsonb"#,
+                            [json!([{ "media_id": media_id }])],
+                        )),
+                ),
+        )
+}
+
+pub struct MediaRepository {
+    db: Arc<Database>,
+}
+
+impl MediaRepository {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    pub async fn create_one(
+        &self,
+        id: Uuid,
+        owner_id: Uuid,
+        kind: &str,
+        content_type: &str,
+        size: i64,
+        storage_key: String,
+    ) -> Result<media::Model> {
+        let conn = self.db.get_connection().await;
+
+        media::ActiveModel {
+            id: Set(id),
+            owner_id: Set(owner_id),
+            kind: Set(kind.to_string()),
+            content_type: Set(content_type.to_string()),
+            size: Set(size),
+            storage_key: Set(storage_key),
+            ..Default::default()
+        }
+        .insert(&conn)
+        .await
+        .map_err(Error::InsertFailed)
+    }
+
+    pub async fn get_by_id(&self, id: Uuid) -> Result<media::Model> {
+        let conn = self.db.get_connection().await;
+
+        Media::find_by_id(id)
+            .one(&conn)
+            .await
+            .map_err(Error::QueryFailed)?
+            .ok_or(Error::RecordNotFound)
+    }
+
+    // Các media không thuộc về owner trong danh sách, rỗng nghĩa là đều hợp lệ
+    pub async fn find_not_owned(&self, ids: Vec<Uuid>, owner_id: Uuid) -> Result<Vec<Uuid>> {
+        let conn = self.db.get_connection().await;
+
+        let owned: Vec<Uuid> = Media::find()
+            .select_only()
+            .column(media::Column::Id)
+            .filter(
+                Condition::all()
+                    .add(media::Column::Id.is_in(ids.clone()))
+                    .add(media::Column::OwnerId.eq(owner_id)),
+            )
+            .into_tuple()
+            .all(&conn)
+            .await
+            .map_err(Error::QueryFailed)?;
+
+        Ok(ids.into_iter().filter(|id| !owned.contains(id)).collect())
+    }
+
+    // Set chứa câu hỏi dùng media cho chính nó hoặc cho một đáp án
+    pub async fn get_set_ids_using(&self, media_id: Uuid) -> Result<Vec<Uuid>> {
+        let conn = self.db.get_connection().await;
+
+        sets_using(media_id)
+            .into_tuple()
+            .all(&conn)
+            .await
+            .map_err(Error::QueryFailed)
+    }
+
+    pub async fn delete_one(&self, id: Uuid) -> Result<()> {
+        let conn = self.db.get_connection().await;
+
+        Media::delete_by_id(id)
+            .exec(&conn)
+            .await
+            .map_err(Error::DeleteFailed)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        sea_orm::{DbBackend, QueryTrait, Value},
+    };
+
+    #[test]
+    fn answers_are_cast_to_jsonb_before_containment() {
+        let media_id = Uuid::new_v4();
+        let stmt = sets_using(media_id).build(DbBackend::Postgres);
+
+        assert!(
+            stmt.sql
+                .contains(r#""questions"."answers"::jsonb @> $3::jsonb"#),
+            "{}",
+            stmt.sql
+        );
+        let values = stmt.values.unwrap().0;
+        assert_eq!(
+            values[2],
+            Value::Json(Some(Box::new(json!([{ "media_id": media_id }]))))
+        );
+    }
+}
@@ -1,6 +1,18 @@
+pub mod api_token;
+pub mod audit;
+pub mod change_request;
+pub mod comment;
+pub mod difficulty;
+pub mod media;
+pub mod note;
+pub mod oidc;
+pub mod ownership;
 pub mod question;
 pub mod quiz;
 pub mod quiz_question;
 pub mod set;
+pub mod tag;
 pub mod test;
+pub mod trash;
 pub mod user;
+pub mod user_token;
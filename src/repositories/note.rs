@@ -0,0 +1,174 @@
+use {
+    crate::{
+        db::db_connection::Database,
+        entities::{notes, prelude::Notes},
+        enums::{error::*, generic::PaginatedResponse},
+        models::note::{NoteTarget, QueryNoteParams},
+    },
+    chrono::Utc,
+    sea_orm::{
+        sea_query::{Expr, Func, LikeExpr, OnConflict},
+        ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, Set,
+    },
+    std::sync::Arc,
+    uuid::Uuid,
+};
+
+fn target_condition(user_id: Uuid, target: NoteTarget) -> Condition {
+    let condition = Condition::all().add(notes::Column::UserId.eq(user_id));
+    match target {
+        NoteTarget::Question(id) => condition.add(notes::Column::QuestionId.eq(id)),
+        NoteTarget::QuizQuestion(id) => condition.add(notes::Column::QuizQuestionId.eq(id)),
+    }
+}
+
+// `%`/`_` trong từ khoá là ký tự thường, không phải wildcard
+fn contains_pattern(q: &str) -> String {
+    let mut pattern = String::with_capacity(q.len() + 2);
+    pattern.push('%');
+    for c in q.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            pattern.push('\\');
+        }
+        pattern.push(c);
+    }
+    pattern.push('%');
+    pattern
+}
+
+pub struct NoteRepository {
+    db: Arc<Database>,
+}
+
+impl NoteRepository {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    pub async fn get_all(
+        &self,
+        user_id: Uuid,
+        params: QueryNoteParams,
+    ) -> Result<PaginatedResponse<notes::Model>> {
+        let conn = self.db.get_connection().await;
+
+        let mut query = Notes::find().filter(notes::Column::UserId.eq(user_id));
+        if let Some(q) = params.q {
+            query = query.filter(
+                Expr::expr(Func::lower(Expr::col(notes::Column::Content)))
+                    .like(LikeExpr::new(contains_pattern(&q.to_lowercase())).escape('\\')),
+            );
+        }
+
+        let page = params.page.unwrap_or(1).max(1);
+        let page_size = params.page_size.unwrap_or(10).clamp(1, 100);
+
+        let paginator = query
+            .order_by_desc(notes::Column::UpdatedAt)
+            .paginate(&conn, page_size);
+        let total_pages = paginator.num_pages().await.unwrap_or(1);
+
+        let res = paginator
+            .fetch_page(page - 1)
+            .await
+            .map_err(Error::QueryFailed)?;
+
+        Ok(PaginatedResponse {
+            total_pages,
+            current_page: page,
+            page_size,
+            data: res,
+        })
+    }
+
+    pub async fn get_one(&self, user_id: Uuid, target: NoteTarget) -> Result<notes::Model> {
+        let conn = self.db.get_connection().await;
+
+        Notes::find()
+            .filter(target_condition(user_id, target))
+            .one(&conn)
+            .await
+            .map_err(Error::QueryFailed)?
+            .ok_or(Error::RecordNotFound)
+    }
+
+    pub async fn upsert(
+        &self,
+        user_id: Uuid,
+        target: NoteTarget,
+        content: String,
+    ) -> Result<notes::Model> {
+        let conn = self.db.get_connection().await;
+
+        let (target_column, question_id, quiz_question_id) = match target {
+            NoteTarget::Question(id) => (notes::Column::QuestionId, Some(id), None),
+            NoteTarget::QuizQuestion(id) => (notes::Column::QuizQuestionId, None, Some(id)),
+        };
+        let on_conflict = OnConflict::columns([notes::Column::UserId, target_column])
+            .update_columns([notes::Column::Content, notes::Column::UpdatedAt])
+            .to_owned();
+
+        Notes::insert(notes::ActiveModel {
+            user_id: Set(user_id),
+            question_id: Set(question_id),
+            quiz_question_id: Set(quiz_question_id),
+            content: Set(content),
+            updated_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        })
+        .on_conflict(on_conflict)
+        .exec_with_returning(&conn)
+        .await
+        .map_err(Error::InsertFailed)
+    }
+
+    pub async fn delete_one(&self, user_id: Uuid, target: NoteTarget) -> Result<()> {
+        let conn = self.db.get_connection().await;
+
+        let res = Notes::delete_many()
+            .filter(target_condition(user_id, target))
+            .exec(&conn)
+            .await
+            .map_err(Error::DeleteFailed)?;
+        if res.rows_affected == 0 {
+            return Err(Error::RecordNotFound);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        sea_orm::{DbBackend, QueryTrait},
+    };
+
+    #[test]
+    fn note_is_scoped_to_user_and_target() {
+        let (user_id, id) = (Uuid::new_v4(), Uuid::new_v4());
+        let sql = Notes::find()
+            .filter(target_condition(user_id, NoteTarget::QuizQuestion(id)))
+            .build(DbBackend::Postgres)
+            .to_string();
+        let (_, sql) = sql.split_once("WHERE").unwrap();
+
+        assert!(
+            sql.contains(&format!(r#""user_id" = '{user_id}'"#)),
+            "{sql}"
+        );
+        assert!(
+            sql.contains(&format!(r#""quiz_question_id" = '{id}'"#)),
+            "{sql}"
+        );
+        assert!(!sql.contains(r#""question_id""#), "{sql}");
+    }
+
+    #[test]
+    fn search_escapes_like_wildcards() {
+        assert_eq!(contains_pattern("đạo hàm"), "%đạo hàm%");
+        assert_eq!(contains_pattern("100%"), "%100\\%%");
+        assert_eq!(contains_pattern("a_b\\c"), "%a\\_b\\\\c%");
+    }
+}
@@ -0,0 +1,187 @@
+use {
+    super::user::new_user_active_model,
+    crate::{
+        db::db_connection::Database,
+        entities::{
+            oidc_states, prelude::*, sea_orm_active_enums::RoleEnum, user_identities, users,
+        },
+        enums::error::*,
+        models::user::RegisterUserRequest,
+        oidc::ExternalIdentity,
+    },
+    chrono::{NaiveDateTime, Utc},
+    sea_orm::{
+        sea_query::Expr, ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, EntityTrait,
+        PaginatorTrait, QueryFilter, Set, TransactionTrait,
+    },
+    std::sync::Arc,
+    uuid::Uuid,
+};
+
+pub struct OidcRepository {
+    db: Arc<Database>,
+}
+
+impl OidcRepository {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    pub async fn create_state(
+        &self,
+        state: String,
+        nonce: String,
+        pkce_verifier: String,
+        expires_at: NaiveDateTime,
+    ) -> Result<()> {
+        let conn = self.db.get_connection().await;
+
+        // dọn các state đã hết hạn mà user không quay lại
+        OidcStates::delete_many()
+            .filter(oidc_states::Column::ExpiresAt.lt(Utc::now().naive_utc()))
+            .exec(&conn)
+            .await
+            .map_err(Error::DeleteFailed)?;
+
+        oidc_states::ActiveModel {
+            state: Set(state),
+            nonce: Set(nonce),
+            pkce_verifier: Set(pkce_verifier),
+            expires_at: Set(expires_at),
+            ..Default::default()
+        }
+        .insert(&conn)
+        .await
+        .map_err(Error::InsertFailed)?;
+
+        Ok(())
+    }
+
+    // Lấy và xoá state, mỗi state chỉ dùng được một lần
+    pub async fn consume_state(&self, state: String) -> Result<oidc_states::Model> {
+        let txn = self
+            .db
+            .get_connection()
+            .await
+            .begin()
+            .await
+            .map_err(Error::BeginTransactionFailed)?;
+
+        let saved = OidcStates::find_by_id(state.clone())
+            .filter(oidc_states::Column::ExpiresAt.gt(Utc::now().naive_utc()))
+            .one(&txn)
+            .await
+            .map_err(Error::QueryFailed)?
+            .ok_or(Error::InvalidToken)?;
+
+        let res = OidcStates::delete_by_id(state)
+            .exec(&txn)
+            .await
+            .map_err(Error::DeleteFailed)?;
+        if res.rows_affected == 0 {
+            return Err(Error::InvalidToken);
+        }
+
+        txn.commit().await.map_err(Error::CommitTransactionFailed)?;
+
+        Ok(saved)
+    }
+
+    // Tìm user đã liên kết với danh tính này và cập nhật lần login cuối
+    pub async fn find_user_id(&self, issuer: &str, subject: &str) -> Result<Option<Uuid>> {
+        let conn = self.db.get_connection().await;
+
+        let Some(identity) = UserIdentities::find()
+            .filter(
+                Condition::all()
+                    .add(user_identities::Column::Issuer.eq(issuer))
+                    .add(user_identities::Column::Subject.eq(subject)),
+            )
+            .one(&conn)
+            .await
+            .map_err(Error::QueryFailed)?
+        else {
+            return Ok(None);
+        };
+
+        UserIdentities::update_many()
+            .col_expr(
+                user_identities::Column::LastLoginAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(user_identities::Column::Id.eq(identity.id))
+            .exec(&conn)
+            .await
+            .map_err(Error::UpdateFailed)?;
+
+        Ok(Some(identity.user_id))
+    }
+
+    pub async fn link_identity(&self, user_id: Uuid, identity: &ExternalIdentity) -> Result<()> {
+        let conn = self.db.get_connection().await;
+
+        insert_identity(&conn, user_id, identity).await
+    }
+
+    // Tạo user mới cho lần login OIDC đầu tiên
+    pub async fn create_user_with_identity(
+        &self,
+        mut payload: RegisterUserRequest,
+        is_verified: bool,
+        identity: &ExternalIdentity,
+    ) -> Result<users::Model> {
+        let txn = self
+            .db
+            .get_connection()
+            .await
+            .begin()
+            .await
+            .map_err(Error::BeginTransactionFailed)?;
+
+        // name là unique, thêm hậu tố khi trùng
+        let taken = Users::find()
+            .filter(users::Column::Name.eq(payload.name.clone()))
+            .count(&txn)
+            .await
+            .map_err(Error::QueryFailed)?
+            > 0;
+        if taken {
+            payload.name = format!(
+                "{}-{}",
+                payload.name,
+                &Uuid::new_v4().simple().to_string()[..6]
+            );
+        }
+
+        let user = Users::insert(new_user_active_model(payload, RoleEnum::User, is_verified))
+            .exec_with_returning(&txn)
+            .await
+            .map_err(Error::InsertFailed)?;
+
+        insert_identity(&txn, user.id, identity).await?;
+
+        txn.commit().await.map_err(Error::CommitTransactionFailed)?;
+
+        Ok(user)
+    }
+}
+
+async fn insert_identity<C: ConnectionTrait>(
+    conn: &C,
+    user_id: Uuid,
+    identity: &ExternalIdentity,
+) -> Result<()> {
+    user_identities::ActiveModel {
+        user_id: Set(user_id),
+        issuer: Set(identity.issuer.clone()),
+        subject: Set(identity.subject.clone()),
+        email: Set(identity.email.clone()),
+        last_login_at: Set(Some(Utc::now().naive_utc())),
+        ..Default::default()
+    }
+    .insert(conn)
+    .await
+    .map_err(Error::InsertFailed)?;
+
+    Ok(())
+}
@@ -0,0 +1,382 @@
+use {
+    crate::{
+        db::db_connection::Database,
+        entities::{
+            ownership_transfers,
+            prelude::{OwnershipTransfers, Questions, Quizes, Sets, SharedQuizes, SharedSets},
+            questions, quizes, sets, shared_quizes, shared_sets,
+        },
+        enums::{error::*, generic::PaginatedResponse},
+        models::{audit::AuditResource, set::SharedPermission},
+    },
+    chrono::Utc,
+    sea_orm::{
+        sea_query::{Expr, OnConflict},
+        ActiveModelTrait, ColumnTrait, Condition, DatabaseTransaction, EntityTrait, Insert,
+        PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Select, Set, TransactionTrait,
+    },
+    std::sync::Arc,
+    uuid::Uuid,
+};
+
+// Chủ mới không cần share nữa, chủ cũ được share lại nếu `keep_previous_owner`
+async fn transfer_set(
+    txn: &DatabaseTransaction,
+    set_id: Uuid,
+    from_user_id: Uuid,
+    to_user_id: Uuid,
+    keep_previous_owner: bool,
+) -> Result<()> {
+    let res = Sets::update_many()
+        .col_expr(sets::Column::OwnerId, Expr::value(to_user_id))
+        .col_expr(sets::Column::UpdatedAt, Expr::value(Utc::now().naive_utc()))
+        .filter(
+            Condition::all()
+                .add(sets::Column::Id.eq(set_id))
+                .add(sets::Column::OwnerId.eq(from_user_id)),
+        )
+        .exec(txn)
+        .await
+        .map_err(Error::UpdateFailed)?;
+    if res.rows_affected == 0 {
+        return Err(Error::RecordNotFound);
+    }
+
+    // câu hỏi chủ cũ tạo trong set đi theo set, chỉ creator mới xoá được câu hỏi
+    Questions::update_many()
+        .col_expr(questions::Column::CreatorId, Expr::value(to_user_id))
+        .filter(
+            Condition::all()
+                .add(questions::Column::SetId.eq(set_id))
+                .add(questions::Column::CreatorId.eq(from_user_id)),
+        )
+        .exec(txn)
+        .await
+        .map_err(Error::UpdateFailed)?;
+
+    SharedSets::delete_many()
+        .filter(
+            Condition::all()
+                .add(shared_sets::Column::SetId.eq(set_id))
+                .add(shared_sets::Column::UserId.eq(to_user_id)),
+        )
+        .exec(txn)
+        .await
+        .map_err(Error::DeleteFailed)?;
+
+    if keep_previous_owner {
+        share_back_set(set_id, from_user_id)
+            .exec(txn)
+            .await
+            .map_err(Error::InsertFailed)?;
+    }
+
+    Ok(())
+}
+
+// Chủ cũ giữ quyền Edit trên set đã chuyển
+fn share_back_set(set_id: Uuid, from_user_id: Uuid) -> Insert<shared_sets::ActiveModel> {
+    SharedSets::insert(shared_sets::ActiveModel {
+        set_id: Set(set_id),
+        user_id: Set(from_user_id),
+        permission: Set(SharedPermission::Edit as i32),
+        ..Default::default()
+    })
+    .on_conflict(
+        OnConflict::columns([shared_sets::Column::SetId, shared_sets::Column::UserId])
+            .update_column(shared_sets::Column::Permission)
+            .to_owned(),
+    )
+}
+
+async fn transfer_quiz(
+    txn: &DatabaseTransaction,
+    quiz_id: Uuid,
+    from_user_id: Uuid,
+    to_user_id: Uuid,
+    keep_previous_owner: bool,
+) -> Result<()> {
+    let res = Quizes::update_many()
+        .col_expr(quizes::Column::CreatorId, Expr::value(to_user_id))
+        .col_expr(
+            quizes::Column::UpdatedAt,
+            Expr::value(Utc::now().naive_utc()),
+        )
+        .filter(
+            Condition::all()
+                .add(quizes::Column::Id.eq(quiz_id))
+                .add(quizes::Column::CreatorId.eq(from_user_id)),
+        )
+        .exec(txn)
+        .await
+        .map_err(Error::UpdateFailed)?;
+    if res.rows_affected == 0 {
+        return Err(Error::RecordNotFound);
+    }
+
+    SharedQuizes::delete_many()
+        .filter(
+            Condition::all()
+                .add(shared_quizes::Column::QuizId.eq(quiz_id))
+                .add(shared_quizes::Column::UserId.eq(to_user_id)),
+        )
+        .exec(txn)
+        .await
+        .map_err(Error::DeleteFailed)?;
+
+    if keep_previous_owner {
+        SharedQuizes::insert(shared_quizes::ActiveModel {
+            quiz_id: Set(quiz_id),
+            user_id: Set(from_user_id),
+            ..Default::default()
+        })
+        .on_conflict(
+            OnConflict::columns([shared_quizes::Column::QuizId, shared_quizes::Column::UserId])
+                .do_nothing()
+                .to_owned(),
+        )
+        .do_nothing()
+        .exec(txn)
+        .await
+        .map_err(Error::InsertFailed)?;
+    }
+
+    Ok(())
+}
+
+fn history_row(
+    resource: AuditResource,
+    resource_id: Uuid,
+    from_user_id: Uuid,
+    to_user_id: Uuid,
+    actor_id: Uuid,
+    keep_previous_owner: bool,
+) -> ownership_transfers::ActiveModel {
+    ownership_transfers::ActiveModel {
+        resource_type: Set(resource.as_str().to_string()),
+        resource_id: Set(resource_id),
+        from_user_id: Set(from_user_id),
+        to_user_id: Set(to_user_id),
+        actor_id: Set(Some(actor_id)),
+        keep_previous_owner: Set(keep_previous_owner),
+        ..Default::default()
+    }
+}
+
+fn history_of(resource: AuditResource, resource_id: Uuid) -> Select<OwnershipTransfers> {
+    OwnershipTransfers::find()
+        .filter(
+            Condition::all()
+                .add(ownership_transfers::Column::ResourceType.eq(resource.as_str()))
+                .add(ownership_transfers::Column::ResourceId.eq(resource_id)),
+        )
+        .order_by_desc(ownership_transfers::Column::CreatedAt)
+}
+
+pub struct OwnershipRepository {
+    db: Arc<Database>,
+}
+
+impl OwnershipRepository {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    async fn begin(&self) -> Result<DatabaseTransaction> {
+        self.db
+            .get_connection()
+            .await
+            .begin()
+            .await
+            .map_err(Error::BeginTransactionFailed)
+    }
+
+    // Chủ hiện tại của set/quiz, kể cả đang trong thùng rác
+    pub async fn get_owner(&self, resource: AuditResource, id: Uuid) -> Result<Uuid> {
+        let conn = self.db.get_connection().await;
+
+        let owner_id: Option<Uuid> = match resource {
+            AuditResource::Set => {
+                Sets::find_by_id(id)
+                    .select_only()
+                    .column(sets::Column::OwnerId)
+                    .into_tuple()
+                    .one(&conn)
+                    .await
+            }
+            _ => {
+                Quizes::find_by_id(id)
+                    .select_only()
+                    .column(quizes::Column::CreatorId)
+                    .into_tuple()
+                    .one(&conn)
+                    .await
+            }
+        }
+        .map_err(Error::QueryFailed)?;
+
+        owner_id.ok_or(Error::RecordNotFound)
+    }
+
+    // Toàn bộ set và quiz của user, kể cả đang trong thùng rác
+    pub async fn get_owned(&self, user_id: Uuid) -> Result<(Vec<Uuid>, Vec<Uuid>)> {
+        let conn = self.db.get_connection().await;
+
+        let set_ids = Sets::find()
+            .select_only()
+            .column(sets::Column::Id)
+            .filter(sets::Column::OwnerId.eq(user_id))
+            .into_tuple()
+            .all(&conn)
+            .await
+            .map_err(Error::QueryFailed)?;
+        let quiz_ids = Quizes::find()
+            .select_only()
+            .column(quizes::Column::Id)
+            .filter(quizes::Column::CreatorId.eq(user_id))
+            .into_tuple()
+            .all(&conn)
+            .await
+            .map_err(Error::QueryFailed)?;
+
+        Ok((set_ids, quiz_ids))
+    }
+
+    // Chuyển các set và quiz của `from_user_id` trong một transaction và ghi lịch
+    // sử cho từng cái
+    pub async fn transfer(
+        &self,
+        set_ids: &[Uuid],
+        quiz_ids: &[Uuid],
+        from_user_id: Uuid,
+        to_user_id: Uuid,
+        actor_id: Uuid,
+        keep_previous_owner: bool,
+    ) -> Result<Vec<ownership_transfers::Model>> {
+        let txn = self.begin().await?;
+
+        let mut records = vec![];
+        for &set_id in set_ids {
+            transfer_set(&txn, set_id, from_user_id, to_user_id, keep_previous_owner).await?;
+            records.push((AuditResource::Set, set_id));
+        }
+        for &quiz_id in quiz_ids {
+            transfer_quiz(&txn, quiz_id, from_user_id, to_user_id, keep_previous_owner).await?;
+            records.push((AuditResource::Quiz, quiz_id));
+        }
+
+        let mut transfers = vec![];
+        for (resource, resource_id) in records {
+            let transfer = history_row(
+                resource,
+                resource_id,
+                from_user_id,
+                to_user_id,
+                actor_id,
+                keep_previous_owner,
+            )
+            .insert(&txn)
+            .await
+            .map_err(Error::InsertFailed)?;
+            transfers.push(transfer);
+        }
+
+        txn.commit().await.map_err(Error::CommitTransactionFailed)?;
+
+        Ok(transfers)
+    }
+
+    // Lịch sử chuyển của một set/quiz, mới nhất trước
+    pub async fn get_history(
+        &self,
+        resource: AuditResource,
+        resource_id: Uuid,
+        page: u64,
+        page_size: u64,
+    ) -> Result<PaginatedResponse<ownership_transfers::Model>> {
+        let conn = self.db.get_connection().await;
+        let page = page.max(1);
+
+        let paginator = history_of(resource, resource_id).paginate(&conn, page_size);
+        let total_pages = paginator.num_pages().await.unwrap_or(1);
+        let res = paginator
+            .fetch_page(page - 1)
+            .await
+            .map_err(Error::QueryFailed)?;
+
+        Ok(PaginatedResponse {
+            total_pages,
+            current_page: page,
+            page_size,
+            data: res,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        sea_orm::{ActiveValue, DbBackend, QueryTrait},
+    };
+
+    #[test]
+    fn previous_owner_is_shared_back_with_edit() {
+        let (set_id, from_user_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let sql = share_back_set(set_id, from_user_id)
+            .build(DbBackend::Postgres)
+            .to_string();
+
+        assert!(
+            sql.contains(&format!("'{set_id}', '{from_user_id}', 2")),
+            "{sql}"
+        );
+        // đã được share sẵn thì nâng lên Edit thay vì lỗi trùng khoá
+        assert!(
+            sql.contains(r#"ON CONFLICT ("set_id", "user_id") DO UPDATE SET "permission" = "excluded"."permission""#),
+            "{sql}"
+        );
+    }
+
+    #[test]
+    fn history_row_records_both_owners_and_actor() {
+        let (quiz_id, from, to, actor) = (
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        );
+
+        let row = history_row(AuditResource::Quiz, quiz_id, from, to, actor, true);
+
+        assert_eq!(
+            row.resource_type,
+            ActiveValue::Set(AuditResource::Quiz.as_str().to_string())
+        );
+        assert_eq!(row.resource_id, ActiveValue::Set(quiz_id));
+        assert_eq!(row.from_user_id, ActiveValue::Set(from));
+        assert_eq!(row.to_user_id, ActiveValue::Set(to));
+        assert_eq!(row.actor_id, ActiveValue::Set(Some(actor)));
+        assert_eq!(row.keep_previous_owner, ActiveValue::Set(true));
+    }
+
+    #[test]
+    fn history_is_per_resource_newest_first() {
+        let set_id = Uuid::new_v4();
+        let sql = history_of(AuditResource::Set, set_id)
+            .build(DbBackend::Postgres)
+            .to_string();
+
+        assert!(
+            sql.contains(&format!(
+                r#""resource_type" = '{}' AND "ownership_transfers"."resource_id" = '{set_id}'"#,
+                AuditResource::Set.as_str()
+            )),
+            "{sql}"
+        );
+        assert!(
+            sql.contains(r#"ORDER BY "ownership_transfers"."created_at" DESC"#),
+            "{sql}"
+        );
+    }
+}
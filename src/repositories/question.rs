@@ -1,19 +1,132 @@
 use {
     crate::{
         db::db_connection::Database,
-        entities::{prelude::Questions, questions},
+        entities::{
+            prelude::{QuestionRevisions, Questions},
+            question_revisions, questions,
+        },
         enums::{error::*, generic::PaginatedResponse},
-        models::qna::{CreateQnARequest, QueryQuestionParams, UpdateQuestionRequest},
+        models::{
+            difficulty::DifficultyRange,
+            qna::{CreateQnARequest, QueryQuestionParams, UpdateQuestionRequest},
+        },
+        repositories::{
+            difficulty::difficulty_between,
+            tag::{expand_tag_ids, tagged_questions},
+        },
+        utils::helpers::parse_id_list,
     },
     chrono::Utc,
     sea_orm::{
-        ActiveModelTrait, ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter,
-        QueryOrder, Set,
+        ActiveModelTrait, ColumnTrait, Condition, DatabaseTransaction, EntityTrait, PaginatorTrait,
+        QueryFilter, QueryOrder, QuerySelect, Select, Set, TransactionTrait,
     },
     std::sync::Arc,
     uuid::Uuid,
 };
 
+// Ghi bản chụp hiện tại của câu hỏi thành revision tiếp theo, câu hỏi phải
+// được khoá trong `txn`
+async fn insert_revision(
+    txn: &DatabaseTransaction,
+    question: &questions::Model,
+    author_id: Uuid,
+    restored_from: Option<i32>,
+) -> Result<question_revisions::Model> {
+    let latest: Option<i32> = QuestionRevisions::find()
+        .select_only()
+        .column_as(question_revisions::Column::Revision.max(), "revision")
+        .filter(question_revisions::Column::QuestionId.eq(question.id))
+        .into_tuple()
+        .one(txn)
+        .await
+        .map_err(Error::QueryFailed)?
+        .flatten();
+
+    snapshot_of(question, author_id, latest.unwrap_or(0) + 1, restored_from)
+        .insert(txn)
+        .await
+        .map_err(Error::InsertFailed)
+}
+
+fn snapshot_of(
+    question: &questions::Model,
+    author_id: Uuid,
+    revision: i32,
+    restored_from: Option<i32>,
+) -> question_revisions::ActiveModel {
+    question_revisions::ActiveModel {
+        question_id: Set(question.id),
+        revision: Set(revision),
+        author_id: Set(Some(author_id)),
+        content: Set(question.content.clone()),
+        content_format: Set(question.content_format),
+        answers: Set(question.answers.clone()),
+        media_id: Set(question.media_id),
+        restored_from: Set(restored_from),
+        ..Default::default()
+    }
+}
+
+// Revision liền trước `revision`, dùng để tính diff cho revision cuối trang
+fn previous_of(question_id: Uuid, revision: i32) -> Select<QuestionRevisions> {
+    QuestionRevisions::find()
+        .filter(
+            Condition::all()
+                .add(question_revisions::Column::QuestionId.eq(question_id))
+                .add(question_revisions::Column::Revision.lt(revision)),
+        )
+        .order_by_desc(question_revisions::Column::Revision)
+}
+
+// Sửa question trong `txn` của caller và ghi revision mới, trả `None` khi
+// payload không đổi gì
+pub async fn update_question_in(
+    txn: &DatabaseTransaction,
+    id: Uuid,
+    payload: UpdateQuestionRequest,
+    caller_id: Uuid,
+) -> Result<Option<questions::Model>> {
+    let mut question: questions::ActiveModel = Questions::find_by_id(id)
+        .filter(questions::Column::IsDeleted.eq(false))
+        .lock_exclusive()
+        .one(txn)
+        .await
+        .map_err(Error::QueryFailed)?
+        .ok_or(Error::RecordNotFound)?
+        .into();
+
+    let mut updated = false;
+    if let Some(c) = payload.content {
+        question.content = Set(c);
+        updated = true;
+    }
+    if let Some(answers) = payload.answers {
+        let answers = serde_json::to_value(answers).map_err(|e| Error::Anyhow(e.into()))?;
+        question.answers = Set(answers);
+        updated = true;
+    }
+    if let Some(format) = payload.content_format {
+        question.content_format = Set(format);
+        updated = true;
+    }
+    if let Some(media_id) = payload.media_id {
+        question.media_id = Set(Some(media_id));
+        updated = true;
+    }
+    if !updated {
+        return Ok(None);
+    }
+
+    question.updated_at = Set(Utc::now().naive_utc());
+    question.latest_updater_id = Set(Some(caller_id));
+
+    let question = question.update(txn).await.map_err(Error::UpdateFailed)?;
+    insert_revision(txn, &question, caller_id, None).await?;
+
+    Ok(Some(question))
+}
+
 pub struct QnARepository {
     db: Arc<Database>,
 }
@@ -23,26 +136,40 @@ impl QnARepository {
         Self { db }
     }
 
+    async fn begin(&self) -> Result<DatabaseTransaction> {
+        self.db
+            .get_connection()
+            .await
+            .begin()
+            .await
+            .map_err(Error::BeginTransactionFailed)
+    }
+
     pub async fn create_one(
         &self,
         payload: CreateQnARequest,
         creator_id: Uuid,
     ) -> Result<questions::Model> {
-        let conn = self.db.get_connection().await;
+        let txn = self.begin().await?;
 
         let answers = serde_json::to_value(payload.answers).map_err(|e| Error::Anyhow(e.into()))?;
 
         let question = questions::ActiveModel {
             content: Set(payload.content),
+            content_format: Set(payload.content_format),
             r#type: Set(payload.r#type),
             set_id: Set(payload.set_id),
             creator_id: Set(creator_id),
             answers: Set(answers),
+            media_id: Set(payload.media_id),
             ..Default::default()
         }
-        .insert(&conn)
+        .insert(&txn)
         .await
         .map_err(Error::InsertFailed)?;
+        insert_revision(&txn, &question, creator_id, None).await?;
+
+        txn.commit().await.map_err(Error::CommitTransactionFailed)?;
 
         Ok(question)
     }
@@ -89,6 +216,15 @@ impl QnARepository {
         if let Some(creator_id) = params.creator_id {
             query = query.filter(questions::Column::CreatorId.eq(creator_id));
         }
+        // gồm cả tag con và tag của set
+        if let Some(tag_ids) = params.tag_ids {
+            let tag_ids = expand_tag_ids(&conn, parse_id_list("tag_ids", &tag_ids)?).await?;
+            query = query.filter(tagged_questions(tag_ids));
+        }
+        query = query.filter(difficulty_between(DifficultyRange {
+            min: params.min_difficulty,
+            max: params.max_difficulty,
+        }));
         query = query.filter(questions::Column::SetId.eq(set_id));
 
         // 🔹 Apply sorting (default: created_at DESC)
@@ -97,6 +233,7 @@ impl QnARepository {
                 let column = match sort_by.as_str() {
                     "content" => questions::Column::Content,
                     "type" => questions::Column::Type,
+                    "difficulty" => questions::Column::Difficulty,
                     _ => questions::Column::CreatedAt,
                 };
 
@@ -134,36 +271,13 @@ impl QnARepository {
         payload: UpdateQuestionRequest,
         caller_id: Uuid,
     ) -> Result<Option<questions::Model>> {
-        let conn = self.db.get_connection().await;
-        let mut question: questions::ActiveModel = Questions::find_by_id(id)
-            .filter(questions::Column::IsDeleted.eq(false))
-            .one(&conn)
-            .await
-            .map_err(Error::QueryFailed)?
-            .ok_or(Error::RecordNotFound)?
-            .into();
-
-        let mut updated = false;
-        if let Some(c) = payload.content {
-            question.content = Set(c);
-            updated = true;
+        let txn = self.begin().await?;
+        let question = update_question_in(&txn, id, payload, caller_id).await?;
+        if question.is_some() {
+            txn.commit().await.map_err(Error::CommitTransactionFailed)?;
         }
-        if let Some(answers) = payload.answers {
-            let answers = serde_json::to_value(answers).map_err(|e| Error::Anyhow(e.into()))?;
-            question.answers = Set(answers);
-            updated = true;
-        }
-
-        if updated {
-            question.updated_at = Set(Utc::now().naive_utc());
-            question.latest_updater_id = Set(Some(caller_id));
 
-            Ok(Some(
-                question.update(&conn).await.map_err(Error::UpdateFailed)?,
-            ))
-        } else {
-            Ok(None)
-        }
+        Ok(question)
     }
 
     pub async fn delete_question(&self, id: Uuid) -> Result<()> {
@@ -177,6 +291,7 @@ impl QnARepository {
             .into();
 
         updating_question.is_deleted = Set(true);
+        updating_question.deleted_at = Set(Some(Utc::now().naive_utc()));
         updating_question
             .update(&conn)
             .await
@@ -196,5 +311,156 @@ impl QnARepository {
 
         Ok(question.is_some())
     }
+
+    // Revision mới nhất trước, kèm revision liền trước của trang để tính diff
+    pub async fn get_revisions(
+        &self,
+        question_id: Uuid,
+        page: u64,
+        page_size: u64,
+    ) -> Result<(
+        PaginatedResponse<question_revisions::Model>,
+        Option<question_revisions::Model>,
+    )> {
+        let conn = self.db.get_connection().await;
+        let page = page.max(1);
+
+        let paginator = QuestionRevisions::find()
+            .filter(question_revisions::Column::QuestionId.eq(question_id))
+            .order_by_desc(question_revisions::Column::Revision)
+            .paginate(&conn, page_size);
+        let total_pages = paginator.num_pages().await.unwrap_or(1);
+        let res = paginator
+            .fetch_page(page - 1)
+            .await
+            .map_err(Error::QueryFailed)?;
+
+        let previous = match res.last() {
+            Some(oldest) => {
+                previous_of(question_id, oldest.revision)
+                    .one(&conn)
+                    .await
+                    .map_err(Error::QueryFailed)?
+            }
+            None => None,
+        };
+
+        Ok((
+            PaginatedResponse {
+                total_pages,
+                current_page: page,
+                page_size,
+                data: res,
+            },
+            previous,
+        ))
+    }
+
+    pub async fn get_revision(
+        &self,
+        question_id: Uuid,
+        revision_id: Uuid,
+    ) -> Result<question_revisions::Model> {
+        let conn = self.db.get_connection().await;
+
+        QuestionRevisions::find_by_id(revision_id)
+            .filter(question_revisions::Column::QuestionId.eq(question_id))
+            .one(&conn)
+            .await
+            .map_err(Error::QueryFailed)?
+            .ok_or(Error::RecordNotFound)
+    }
+
+    // Đưa câu hỏi về bản chụp của `revision`, ghi thành revision mới
+    pub async fn restore_revision(
+        &self,
+        revision: &question_revisions::Model,
+        caller_id: Uuid,
+    ) -> Result<questions::Model> {
+        let txn = self.begin().await?;
+
+        let mut question: questions::ActiveModel = Questions::find_by_id(revision.question_id)
+            .filter(questions::Column::IsDeleted.eq(false))
+            .lock_exclusive()
+            .one(&txn)
+            .await
+            .map_err(Error::QueryFailed)?
+            .ok_or(Error::RecordNotFound)?
+            .into();
+        question.content = Set(revision.content.clone());
+        question.content_format = Set(revision.content_format);
+        question.answers = Set(revision.answers.clone());
+        question.media_id = Set(revision.media_id);
+        question.updated_at = Set(Utc::now().naive_utc());
+        question.latest_updater_id = Set(Some(caller_id));
+        let question = question.update(&txn).await.map_err(Error::UpdateFailed)?;
+
+        insert_revision(&txn, &question, caller_id, Some(revision.revision)).await?;
+
+        txn.commit().await.map_err(Error::CommitTransactionFailed)?;
+
+        Ok(question)
+    }
 }
 //
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::entities::sea_orm_active_enums::{ContentFormatEnum, QuestionTypeEnum},
+        sea_orm::{ActiveValue, DbBackend, QueryTrait},
+        serde_json::json,
+    };
+
+    #[test]
+    fn restored_snapshot_is_a_new_revision_pointing_at_the_source() {
+        let now = Utc::now().naive_utc();
+        let question = questions::Model {
+            id: Uuid::new_v4(),
+            r#type: QuestionTypeEnum::MultipleChoice,
+            content: "Thủ đô của Việt Nam?".to_owned(),
+            content_format: ContentFormatEnum::Plain,
+            answers: json!([{ "content": "Hà Nội", "is_answer": true }]),
+            set_id: Uuid::new_v4(),
+            creator_id: Uuid::new_v4(),
+            latest_updater_id: None,
+            created_at: now,
+            updated_at: now,
+            is_deleted: false,
+            deleted_at: None,
+            difficulty: None,
+            difficulty_confidence: None,
+            difficulty_attempts: 0,
+            media_id: None,
+        };
+        let author_id = Uuid::new_v4();
+
+        let snapshot = snapshot_of(&question, author_id, 7, Some(3));
+
+        assert_eq!(snapshot.question_id, ActiveValue::Set(question.id));
+        assert_eq!(snapshot.revision, ActiveValue::Set(7));
+        assert_eq!(snapshot.author_id, ActiveValue::Set(Some(author_id)));
+        assert_eq!(snapshot.content, ActiveValue::Set(question.content));
+        assert_eq!(snapshot.answers, ActiveValue::Set(question.answers));
+        assert_eq!(snapshot.restored_from, ActiveValue::Set(Some(3)));
+    }
+
+    #[test]
+    fn previous_is_the_closest_older_revision() {
+        let question_id = Uuid::new_v4();
+        let sql = previous_of(question_id, 5)
+            .build(DbBackend::Postgres)
+            .to_string();
+
+        assert!(
+            sql.contains(&format!(r#""question_id" = '{question_id}'"#)),
+            "{sql}"
+        );
+        assert!(sql.contains(r#""revision" < 5"#), "{sql}");
+        assert!(
+            sql.contains(r#"ORDER BY "question_revisions"."revision" DESC"#),
+            "{sql}"
+        );
+    }
+}
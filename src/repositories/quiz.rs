@@ -3,7 +3,7 @@ use {
         db::db_connection::Database,
         entities::{
             prelude::{Quizes, *},
-            quizes, shared_quizes, users,
+            questions, quiz_questions, quizes, shared_quizes, users,
         },
         enums::{error::*, generic::PaginatedResponse},
         models::{
@@ -13,11 +13,14 @@ use {
             },
             user::UserModel,
         },
+        repositories::tag::{expand_tag_ids, tagged_questions},
+        utils::helpers::parse_id_list,
     },
     chrono::Utc,
     sea_orm::{
-        sea_query::OnConflict, ActiveModelTrait, ColumnTrait, Condition, EntityTrait, JoinType,
-        ModelTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, RelationTrait, Set,
+        sea_query::{Expr, OnConflict, Query},
+        ActiveModelTrait, ColumnTrait, Condition, EntityTrait, JoinType, ModelTrait,
+        PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, RelationTrait, Set, TransactionTrait,
     },
     std::sync::Arc,
     uuid::Uuid,
@@ -117,18 +120,42 @@ impl QuizRepository {
         }
     }
 
+    // Xoá mềm quiz cùng các câu hỏi của quiz với cùng `deleted_at`
     pub async fn delete_one(&self, id: Uuid) -> Result<()> {
-        let conn = self.db.get_connection().await;
+        let txn = self
+            .db
+            .get_connection()
+            .await
+            .begin()
+            .await
+            .map_err(Error::BeginTransactionFailed)?;
+        let now = Utc::now().naive_utc();
+
         let mut quiz: quizes::ActiveModel = Quizes::find_by_id(id)
             .filter(quizes::Column::IsDeleted.eq(false))
-            .one(&conn)
+            .one(&txn)
             .await
             .map_err(Error::QueryFailed)?
             .ok_or(Error::RecordNotFound)?
             .into();
 
         quiz.is_deleted = Set(true);
-        quiz.update(&conn).await.map_err(Error::DeleteFailed)?;
+        quiz.deleted_at = Set(Some(now));
+        quiz.update(&txn).await.map_err(Error::DeleteFailed)?;
+
+        QuizQuestions::update_many()
+            .col_expr(quiz_questions::Column::IsDeleted, Expr::value(true))
+            .col_expr(quiz_questions::Column::DeletedAt, Expr::value(now))
+            .filter(
+                Condition::all()
+                    .add(quiz_questions::Column::QuizId.eq(id))
+                    .add(quiz_questions::Column::IsDeleted.eq(false)),
+            )
+            .exec(&txn)
+            .await
+            .map_err(Error::DeleteFailed)?;
+
+        txn.commit().await.map_err(Error::CommitTransactionFailed)?;
 
         Ok(())
     }
@@ -191,6 +218,28 @@ impl QuizRepository {
         } else {
             condition = condition.add(condition_visibility);
         }
+        // quiz có câu hỏi lấy từ question gắn tag
+        if let Some(tag_ids) = params.tag_ids {
+            let tag_ids = expand_tag_ids(&conn, parse_id_list("tag_ids", &tag_ids)?).await?;
+            condition = condition.add(
+                quizes::Column::Id.in_subquery(
+                    Query::select()
+                        .column(quiz_questions::Column::QuizId)
+                        .from(QuizQuestions)
+                        .and_where(quiz_questions::Column::IsDeleted.eq(false))
+                        .and_where(
+                            quiz_questions::Column::SampleId.in_subquery(
+                                Query::select()
+                                    .column(questions::Column::Id)
+                                    .from(Questions)
+                                    .and_where(tagged_questions(tag_ids))
+                                    .to_owned(),
+                            ),
+                        )
+                        .to_owned(),
+                ),
+            );
+        }
 
         let query = Quizes::find()
             .join(JoinType::InnerJoin, quizes::Relation::SharedQuizes.def())
@@ -2,15 +2,17 @@ use {
     crate::{
         db::db_connection::Database,
         entities::{
-            prelude::{QuizQuestionAnswers, QuizQuestions},
-            quiz_question_answers, quiz_questions,
+            prelude::{QuizQuestionAnswers, QuizQuestions, TestQuestionResults},
+            questions, quiz_question_answers, quiz_questions, test_question_results, tests,
         },
         enums::error::*,
         models::quiz_question::{CreateQuizQuestionRequest, UpdateQuizQuestionRequest},
+        utils::mistakes::AnsweredItem,
     },
+    chrono::{NaiveDateTime, Utc},
     sea_orm::{
-        ActiveModelTrait, ColumnTrait, Condition, EntityTrait, QueryFilter, QueryOrder, Set,
-        TransactionTrait, TryIntoModel,
+        ActiveModelTrait, ColumnTrait, Condition, EntityTrait, JoinType, QueryFilter, QueryOrder,
+        QuerySelect, RelationTrait, Set, TransactionTrait, TryIntoModel,
     },
     std::sync::Arc,
     uuid::Uuid,
@@ -109,6 +111,7 @@ impl QuizQuestionRepository {
                 quiz_id: Set(quiz_id),
                 sample_id: Set(p.sample_id),
                 question_content: Set(p.question_content.clone()),
+                content_format: Set(p.content_format),
                 r#type: Set(p.r#type.clone()),
                 index: Set(p.index),
                 point: Set(p.point),
@@ -367,6 +370,74 @@ impl QuizQuestionRepository {
             .map_err(Error::QueryFailed)
     }
 
+    pub async fn get_by_ids(
+        &self,
+        ids: Vec<Uuid>,
+    ) -> Result<Vec<(quiz_questions::Model, Vec<quiz_question_answers::Model>)>> {
+        let conn = self.db.get_connection().await;
+
+        QuizQuestions::find()
+            .filter(quiz_questions::Column::Id.is_in(ids))
+            .find_with_related(QuizQuestionAnswers)
+            .all(&conn)
+            .await
+            .map_err(Error::QueryFailed)
+    }
+
+    // Các câu đã chấm của user theo thứ tự bắt đầu bài, tính cả bài luyện tập
+    pub async fn get_answer_history(
+        &self,
+        user_id: Uuid,
+        set_id: Option<Uuid>,
+    ) -> Result<Vec<AnsweredItem>> {
+        let conn = self.db.get_connection().await;
+
+        let mut condition = Condition::all()
+            .add(tests::Column::UserId.eq(user_id))
+            .add(quiz_questions::Column::IsDeleted.eq(false))
+            .add(test_question_results::Column::IsCorrect.is_not_null());
+        if let Some(set_id) = set_id {
+            condition = condition.add(questions::Column::SetId.eq(set_id));
+        }
+
+        let rows: Vec<(Uuid, Option<Uuid>, bool, NaiveDateTime)> = TestQuestionResults::find()
+            .select_only()
+            .column(test_question_results::Column::QuizQuestionId)
+            .column(quiz_questions::Column::SampleId)
+            .column(test_question_results::Column::IsCorrect)
+            .column(tests::Column::CreatedAt)
+            .join(
+                JoinType::InnerJoin,
+                test_question_results::Relation::Tests.def(),
+            )
+            .join(
+                JoinType::InnerJoin,
+                test_question_results::Relation::QuizQuestions.def(),
+            )
+            .join(
+                JoinType::LeftJoin,
+                quiz_questions::Relation::Questions.def(),
+            )
+            .filter(condition)
+            .order_by_asc(tests::Column::CreatedAt)
+            .into_tuple()
+            .all(&conn)
+            .await
+            .map_err(Error::QueryFailed)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(quiz_question_id, sample_id, is_correct, answered_at)| {
+                AnsweredItem {
+                    quiz_question_id,
+                    sample_id,
+                    is_correct,
+                    answered_at,
+                }
+            })
+            .collect())
+    }
+
     pub async fn delete(&self, id: Uuid, quiz_id: Uuid) -> Result<()> {
         let conn = self.db.get_connection().await;
 
@@ -383,6 +454,7 @@ impl QuizQuestionRepository {
             .into();
 
         quiz_question.is_deleted = Set(true);
+        quiz_question.deleted_at = Set(Some(Utc::now().naive_utc()));
         quiz_question
             .update(&conn)
             .await
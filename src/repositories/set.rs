@@ -2,8 +2,8 @@ use {
     crate::{
         db::db_connection::Database,
         entities::{
-            prelude::{Sets, SharedSets},
-            sets, shared_quizes, shared_sets,
+            prelude::{Questions, Sets, SharedSets},
+            questions, sets, shared_quizes, shared_sets,
         },
         enums::error::*,
         models::set::{
@@ -12,14 +12,31 @@ use {
     },
     chrono::Utc,
     sea_orm::{
-        sea_query::OnConflict, ActiveModelTrait, ColumnTrait, Condition, EntityTrait, JoinType,
-        QueryFilter, QuerySelect, RelationTrait, Set,
+        sea_query::{Expr, OnConflict},
+        ActiveModelTrait, ColumnTrait, Condition, EntityTrait, JoinType, QueryFilter, QuerySelect,
+        RelationTrait, Select, Set, TransactionTrait,
     },
     serde_json::Value as JsonValue,
     std::sync::Arc,
     uuid::Uuid,
 };
 
+// Quyền cao hơn bao gồm quyền thấp hơn: Edit thì cũng Comment và View được
+fn shared_at_least(
+    set_id: Uuid,
+    user_id: Uuid,
+    permission: SharedPermission,
+) -> Select<SharedSets> {
+    // WHERE shared_sets.set_id = set_id AND shared_sets.user_id = user_id AND
+    // shared_sets.permission >= permission
+    SharedSets::find().filter(
+        Condition::all()
+            .add(shared_sets::Column::SetId.eq(set_id))
+            .add(shared_sets::Column::UserId.eq(user_id))
+            .add(shared_sets::Column::Permission.gte(permission as i32)),
+    )
+}
+
 pub struct SetRepository {
     db: Arc<Database>,
 }
@@ -193,18 +210,43 @@ impl SetRepository {
     }
 
     // Done ✅
+    // Xoá mềm set cùng các câu hỏi còn lại trong set, dùng chung `deleted_at`
+    // để khi khôi phục chỉ lấy lại những câu hỏi bị xoá theo set
     pub async fn delete_one(&self, set_id: Uuid) -> Result<()> {
-        let conn = self.db.get_connection().await;
+        let txn = self
+            .db
+            .get_connection()
+            .await
+            .begin()
+            .await
+            .map_err(Error::BeginTransactionFailed)?;
+        let now = Utc::now().naive_utc();
+
         let mut set: sets::ActiveModel = Sets::find_by_id(set_id)
             .filter(sets::Column::IsDeleted.eq(false))
-            .one(&conn)
+            .one(&txn)
             .await
             .map_err(Error::QueryFailed)?
             .ok_or(Error::RecordNotFound)?
             .into();
 
         set.is_deleted = Set(true);
-        let _ = set.update(&conn).await.map_err(Error::UpdateFailed)?;
+        set.deleted_at = Set(Some(now));
+        let _ = set.update(&txn).await.map_err(Error::UpdateFailed)?;
+
+        Questions::update_many()
+            .col_expr(questions::Column::IsDeleted, Expr::value(true))
+            .col_expr(questions::Column::DeletedAt, Expr::value(now))
+            .filter(
+                Condition::all()
+                    .add(questions::Column::SetId.eq(set_id))
+                    .add(questions::Column::IsDeleted.eq(false)),
+            )
+            .exec(&txn)
+            .await
+            .map_err(Error::UpdateFailed)?;
+
+        txn.commit().await.map_err(Error::CommitTransactionFailed)?;
 
         Ok(())
     }
@@ -236,15 +278,7 @@ impl SetRepository {
     ) -> Result<bool> {
         let conn = self.db.get_connection().await;
 
-        // WHERE shared_sets.set_id = set_id AND shared_sets.user_id = user_id AND
-        // shared_sets.permission = permission
-        let condition = Condition::all()
-            .add(shared_sets::Column::SetId.eq(set_id))
-            .add(shared_sets::Column::UserId.eq(user_id))
-            .add(shared_sets::Column::Permission.gte(permission as i32));
-
-        let res = SharedSets::find()
-            .filter(condition)
+        let res = shared_at_least(set_id, user_id, permission)
             .one(&conn)
             .await
             .map_err(Error::QueryFailed)?;
@@ -316,6 +350,31 @@ impl SetRepository {
             .map_err(Error::InsertFailed)
     }
 
+    pub async fn upsert_share(
+        &self,
+        set_id: Uuid,
+        user_id: Uuid,
+        permission: i32,
+    ) -> Result<shared_sets::Model> {
+        let conn = self.db.get_connection().await;
+
+        let on_conflict =
+            OnConflict::columns([shared_sets::Column::SetId, shared_sets::Column::UserId])
+                .update_column(shared_sets::Column::Permission)
+                .to_owned();
+
+        SharedSets::insert(shared_sets::ActiveModel {
+            set_id: Set(set_id),
+            user_id: Set(user_id),
+            permission: Set(permission),
+            ..Default::default()
+        })
+        .on_conflict(on_conflict)
+        .exec_with_returning(&conn)
+        .await
+        .map_err(Error::InsertFailed)
+    }
+
     // pub async fn get_shared_set(&self, set_id: Uuid, user_id: Uuid) ->
     // Result<Option<sets::Model>> {     let conn =
     // self.db.get_connection().await;
@@ -367,3 +426,27 @@ impl SetRepository {
     //     //     .map_err(Error::QueryFailed)
     // }
 }
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        sea_orm::{DbBackend, QueryTrait},
+    };
+
+    #[test]
+    fn comment_permission_includes_edit_share() {
+        let (set_id, user_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let sql = shared_at_least(set_id, user_id, SharedPermission::Comment)
+            .build(DbBackend::Postgres)
+            .to_string();
+
+        assert!(sql.contains(&format!(r#""set_id" = '{set_id}'"#)), "{sql}");
+        assert!(
+            sql.contains(&format!(r#""user_id" = '{user_id}'"#)),
+            "{sql}"
+        );
+        // View (0) không bình luận được, Comment (1) và Edit (2) thì được
+        assert!(sql.contains(r#""permission" >= 1"#), "{sql}");
+    }
+}
@@ -0,0 +1,469 @@
+use {
+    crate::{
+        db::db_connection::Database,
+        entities::{
+            prelude::*, question_tags, questions, quiz_questions, sea_orm_active_enums::*,
+            set_tags, tags, test_question_results, tests,
+        },
+        enums::error::*,
+        models::difficulty::DifficultyRange,
+        repositories::difficulty::difficulty_between,
+    },
+    sea_orm::{
+        sea_query::{Expr, Func, Query, SimpleExpr},
+        ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DatabaseTransaction,
+        EntityTrait, JoinType, Order, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
+        RelationTrait, Select, Set, TransactionTrait,
+    },
+    std::{
+        collections::{HashMap, HashSet},
+        sync::Arc,
+    },
+    uuid::Uuid,
+};
+
+// Các tag đã cho kèm toàn bộ tag con cháu trong cây chủ đề
+pub async fn expand_tag_ids<C: ConnectionTrait>(conn: &C, tag_ids: Vec<Uuid>) -> Result<Vec<Uuid>> {
+    let edges: Vec<(Uuid, Option<Uuid>)> = Tags::find()
+        .select_only()
+        .column(tags::Column::Id)
+        .column(tags::Column::ParentId)
+        .into_tuple()
+        .all(conn)
+        .await
+        .map_err(Error::QueryFailed)?;
+
+    Ok(with_descendants(tag_ids, edges))
+}
+
+// `edges` là cặp (tag, tag cha) của cả cây
+fn with_descendants(tag_ids: Vec<Uuid>, edges: Vec<(Uuid, Option<Uuid>)>) -> Vec<Uuid> {
+    let mut children: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for (id, parent_id) in edges {
+        if let Some(parent_id) = parent_id {
+            children.entry(parent_id).or_default().push(id);
+        }
+    }
+
+    let mut expanded: HashSet<Uuid> = HashSet::new();
+    let mut stack = tag_ids;
+    while let Some(id) = stack.pop() {
+        if expanded.insert(id) {
+            stack.extend(children.get(&id).into_iter().flatten());
+        }
+    }
+
+    expanded.into_iter().collect()
+}
+
+// Câu hỏi gắn một trong các tag, trực tiếp hoặc qua set chứa nó
+pub fn tagged_questions(tag_ids: Vec<Uuid>) -> SimpleExpr {
+    questions::Column::Id
+        .in_subquery(
+            Query::select()
+                .column(question_tags::Column::QuestionId)
+                .from(QuestionTags)
+                .and_where(question_tags::Column::TagId.is_in(tag_ids.clone()))
+                .to_owned(),
+        )
+        .or(questions::Column::SetId.in_subquery(
+            Query::select()
+                .column(set_tags::Column::SetId)
+                .from(SetTags)
+                .and_where(set_tags::Column::TagId.is_in(tag_ids))
+                .to_owned(),
+        ))
+}
+
+fn graded_results(user_id: Uuid) -> Select<TestQuestionResults> {
+    TestQuestionResults::find()
+        .select_only()
+        .column(test_question_results::Column::IsCorrect)
+        .column(questions::Column::Id)
+        .column(questions::Column::SetId)
+        .join(
+            JoinType::InnerJoin,
+            test_question_results::Relation::Tests.def(),
+        )
+        .join(
+            JoinType::InnerJoin,
+            test_question_results::Relation::QuizQuestions.def(),
+        )
+        .join(
+            JoinType::InnerJoin,
+            quiz_questions::Relation::Questions.def(),
+        )
+        .filter(
+            Condition::all()
+                .add(tests::Column::UserId.eq(user_id))
+                .add(tests::Column::Status.eq(StatusEnum::Submitted))
+                .add(tests::Column::IsPractice.eq(false))
+                .add(test_question_results::Column::IsCorrect.is_not_null()),
+        )
+}
+
+pub struct TagRepository {
+    db: Arc<Database>,
+}
+
+impl TagRepository {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    async fn begin(&self) -> Result<DatabaseTransaction> {
+        self.db
+            .get_connection()
+            .await
+            .begin()
+            .await
+            .map_err(Error::BeginTransactionFailed)
+    }
+
+    pub async fn get_all(&self) -> Result<Vec<tags::Model>> {
+        let conn = self.db.get_connection().await;
+
+        Tags::find()
+            .order_by_asc(tags::Column::Name)
+            .all(&conn)
+            .await
+            .map_err(Error::QueryFailed)
+    }
+
+    pub async fn get_by_id(&self, id: Uuid) -> Result<tags::Model> {
+        let conn = self.db.get_connection().await;
+
+        Tags::find_by_id(id)
+            .one(&conn)
+            .await
+            .map_err(Error::QueryFailed)?
+            .ok_or(Error::RecordNotFound)
+    }
+
+    pub async fn count_by_ids(&self, ids: Vec<Uuid>) -> Result<u64> {
+        let conn = self.db.get_connection().await;
+
+        Tags::find()
+            .filter(tags::Column::Id.is_in(ids))
+            .count(&conn)
+            .await
+            .map_err(Error::QueryFailed)
+    }
+
+    // Tên tag không phân biệt hoa thường trong cùng một tag cha
+    pub async fn name_exists(
+        &self,
+        parent_id: Option<Uuid>,
+        name: &str,
+        except_id: Option<Uuid>,
+    ) -> Result<bool> {
+        let conn = self.db.get_connection().await;
+
+        let mut condition = Condition::all()
+            .add(Expr::expr(Func::lower(Expr::col(tags::Column::Name))).eq(name.to_lowercase()));
+        condition = match parent_id {
+            Some(parent_id) => condition.add(tags::Column::ParentId.eq(parent_id)),
+            None => condition.add(tags::Column::ParentId.is_null()),
+        };
+        if let Some(except_id) = except_id {
+            condition = condition.add(tags::Column::Id.ne(except_id));
+        }
+
+        Ok(Tags::find()
+            .filter(condition)
+            .count(&conn)
+            .await
+            .map_err(Error::QueryFailed)?
+            > 0)
+    }
+
+    pub async fn create_one(
+        &self,
+        name: String,
+        parent_id: Option<Uuid>,
+        creator_id: Uuid,
+    ) -> Result<tags::Model> {
+        let conn = self.db.get_connection().await;
+
+        tags::ActiveModel {
+            name: Set(name),
+            parent_id: Set(parent_id),
+            creator_id: Set(Some(creator_id)),
+            ..Default::default()
+        }
+        .insert(&conn)
+        .await
+        .map_err(Error::InsertFailed)
+    }
+
+    pub async fn update_one(
+        &self,
+        id: Uuid,
+        name: Option<String>,
+        parent_id: Option<Option<Uuid>>,
+    ) -> Result<tags::Model> {
+        let conn = self.db.get_connection().await;
+
+        let mut tag: tags::ActiveModel = self.get_by_id(id).await?.into();
+        if let Some(name) = name {
+            tag.name = Set(name);
+        }
+        if let Some(parent_id) = parent_id {
+            tag.parent_id = Set(parent_id);
+        }
+
+        tag.update(&conn).await.map_err(Error::UpdateFailed)
+    }
+
+    // Tag con được chuyển lên tag cha của tag bị xoá
+    pub async fn delete_one(&self, id: Uuid) -> Result<()> {
+        let txn = self.begin().await?;
+
+        let tag = Tags::find_by_id(id)
+            .one(&txn)
+            .await
+            .map_err(Error::QueryFailed)?
+            .ok_or(Error::RecordNotFound)?;
+
+        Tags::update_many()
+            .col_expr(tags::Column::ParentId, Expr::value(tag.parent_id))
+            .filter(tags::Column::ParentId.eq(id))
+            .exec(&txn)
+            .await
+            .map_err(Error::UpdateFailed)?;
+        Tags::delete_by_id(id)
+            .exec(&txn)
+            .await
+            .map_err(Error::DeleteFailed)?;
+
+        txn.commit().await.map_err(Error::CommitTransactionFailed)
+    }
+
+    pub async fn get_tags_of_question(&self, question_id: Uuid) -> Result<Vec<tags::Model>> {
+        let conn = self.db.get_connection().await;
+
+        Tags::find()
+            .join(JoinType::InnerJoin, tags::Relation::QuestionTags.def())
+            .filter(question_tags::Column::QuestionId.eq(question_id))
+            .order_by_asc(tags::Column::Name)
+            .all(&conn)
+            .await
+            .map_err(Error::QueryFailed)
+    }
+
+    pub async fn replace_question_tags(&self, question_id: Uuid, tag_ids: Vec<Uuid>) -> Result<()> {
+        let txn = self.begin().await?;
+
+        QuestionTags::delete_many()
+            .filter(question_tags::Column::QuestionId.eq(question_id))
+            .exec(&txn)
+            .await
+            .map_err(Error::DeleteFailed)?;
+        if !tag_ids.is_empty() {
+            QuestionTags::insert_many(tag_ids.into_iter().map(|tag_id| {
+                question_tags::ActiveModel {
+                    question_id: Set(question_id),
+                    tag_id: Set(tag_id),
+                }
+            }))
+            .exec(&txn)
+            .await
+            .map_err(Error::InsertFailed)?;
+        }
+
+        txn.commit().await.map_err(Error::CommitTransactionFailed)
+    }
+
+    pub async fn get_tags_of_set(&self, set_id: Uuid) -> Result<Vec<tags::Model>> {
+        let conn = self.db.get_connection().await;
+
+        Tags::find()
+            .join(JoinType::InnerJoin, tags::Relation::SetTags.def())
+            .filter(set_tags::Column::SetId.eq(set_id))
+            .order_by_asc(tags::Column::Name)
+            .all(&conn)
+            .await
+            .map_err(Error::QueryFailed)
+    }
+
+    pub async fn replace_set_tags(&self, set_id: Uuid, tag_ids: Vec<Uuid>) -> Result<()> {
+        let txn = self.begin().await?;
+
+        SetTags::delete_many()
+            .filter(set_tags::Column::SetId.eq(set_id))
+            .exec(&txn)
+            .await
+            .map_err(Error::DeleteFailed)?;
+        if !tag_ids.is_empty() {
+            SetTags::insert_many(tag_ids.into_iter().map(|tag_id| {
+                set_tags::ActiveModel {
+                    set_id: Set(set_id),
+                    tag_id: Set(tag_id),
+                }
+            }))
+            .exec(&txn)
+            .await
+            .map_err(Error::InsertFailed)?;
+        }
+
+        txn.commit().await.map_err(Error::CommitTransactionFailed)
+    }
+
+    // Chọn ngẫu nhiên câu hỏi gắn tag trong các set được phép dùng
+    pub async fn random_question_ids(
+        &self,
+        tag_ids: Vec<Uuid>,
+        set_ids: Vec<Uuid>,
+        r#type: Option<QuestionTypeEnum>,
+        difficulty: DifficultyRange,
+        count: u64,
+    ) -> Result<Vec<Uuid>> {
+        let conn = self.db.get_connection().await;
+
+        let mut condition = Condition::all()
+            .add(questions::Column::IsDeleted.eq(false))
+            .add(questions::Column::SetId.is_in(set_ids))
+            .add(tagged_questions(expand_tag_ids(&conn, tag_ids).await?))
+            .add(difficulty_between(difficulty));
+        if let Some(r#type) = r#type {
+            condition = condition.add(questions::Column::Type.eq(r#type));
+        }
+
+        Questions::find()
+            .select_only()
+            .column(questions::Column::Id)
+            .filter(condition)
+            .order_by(Expr::cust("RANDOM()"), Order::Asc)
+            .limit(count)
+            .into_tuple()
+            .all(&conn)
+            .await
+            .map_err(Error::QueryFailed)
+    }
+
+    // (is_correct, question_id, set_id) của các câu đã chấm trong bài đã nộp,
+    // không tính bài luyện tập, chỉ các câu hỏi quiz được tạo từ câu hỏi trong set
+    pub async fn get_graded_results(&self, user_id: Uuid) -> Result<Vec<(bool, Uuid, Uuid)>> {
+        let conn = self.db.get_connection().await;
+
+        graded_results(user_id)
+            .into_tuple()
+            .all(&conn)
+            .await
+            .map_err(Error::QueryFailed)
+    }
+
+    // Cặp (question_id, tag_id) và (set_id, tag_id) trực tiếp
+    pub async fn get_tag_links(
+        &self,
+        question_ids: Vec<Uuid>,
+        set_ids: Vec<Uuid>,
+    ) -> Result<(Vec<(Uuid, Uuid)>, Vec<(Uuid, Uuid)>)> {
+        let conn = self.db.get_connection().await;
+
+        let question_links = QuestionTags::find()
+            .select_only()
+            .column(question_tags::Column::QuestionId)
+            .column(question_tags::Column::TagId)
+            .filter(question_tags::Column::QuestionId.is_in(question_ids))
+            .into_tuple()
+            .all(&conn)
+            .await
+            .map_err(Error::QueryFailed)?;
+        let set_links = SetTags::find()
+            .select_only()
+            .column(set_tags::Column::SetId)
+            .column(set_tags::Column::TagId)
+            .filter(set_tags::Column::SetId.is_in(set_ids))
+            .into_tuple()
+            .all(&conn)
+            .await
+            .map_err(Error::QueryFailed)?;
+
+        Ok((question_links, set_links))
+    }
+}
+
+#[cfg(test)]
+mod statement_tests {
+    use {
+        super::*,
+        sea_orm::{DbBackend, QueryTrait},
+    };
+
+    #[test]
+    fn filter_on_a_tag_includes_its_descendants() {
+        let (math, algebra, equations, history) = (
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        );
+        let edges = vec![
+            (math, None),
+            (algebra, Some(math)),
+            (equations, Some(algebra)),
+            (history, None),
+        ];
+
+        let mut expanded = with_descendants(vec![algebra], edges.clone());
+        expanded.sort();
+        let mut expected = vec![algebra, equations];
+        expected.sort();
+
+        assert_eq!(expanded, expected);
+        assert_eq!(with_descendants(vec![math], edges).len(), 3);
+    }
+
+    #[test]
+    fn descendants_stop_on_cycles() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let expanded = with_descendants(vec![a], vec![(a, Some(b)), (b, Some(a))]);
+
+        assert_eq!(expanded.len(), 2);
+    }
+
+    #[test]
+    fn tag_filter_matches_question_or_set_tags() {
+        let tag_id = Uuid::new_v4();
+        let sql = Questions::find()
+            .filter(tagged_questions(vec![tag_id]))
+            .build(DbBackend::Postgres)
+            .to_string();
+
+        assert!(
+            sql.contains(&format!(
+                r#""questions"."id" IN (SELECT "question_id" FROM "question_tags" WHERE "question_tags"."tag_id" IN ('{tag_id}'))"#
+            )),
+            "{sql}"
+        );
+        assert!(
+            sql.contains(&format!(
+                r#"OR "questions"."set_id" IN (SELECT "set_id" FROM "set_tags" WHERE "set_tags"."tag_id" IN ('{tag_id}'))"#
+            )),
+            "{sql}"
+        );
+    }
+
+    #[test]
+    fn accuracy_only_counts_graded_answers_of_submitted_real_tests() {
+        let user_id = Uuid::new_v4();
+        let sql = graded_results(user_id)
+            .build(DbBackend::Postgres)
+            .to_string()
+            .replace('"', "");
+
+        assert!(
+            sql.contains(&format!("tests.user_id = '{user_id}'")),
+            "{sql}"
+        );
+        // sea-query có bản quote tên enum trong CAST, có bản không
+        assert!(sql.contains("CAST('Submitted' AS status_enum)"), "{sql}");
+        assert!(sql.contains("tests.is_practice = FALSE"), "{sql}");
+        assert!(
+            sql.contains("test_question_results.is_correct IS NOT NULL"),
+            "{sql}"
+        );
+    }
+}
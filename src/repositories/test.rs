@@ -2,21 +2,70 @@ use {
     crate::{
         db::db_connection::Database,
         entities::{
-            prelude::{TestAnswers, TestQuestionResults, Tests},
+            prelude::{QuizQuestions, TestAdaptiveSteps, TestAnswers, TestQuestionResults, Tests},
+            questions, quiz_questions,
             sea_orm_active_enums::StatusEnum,
-            test_answers, test_question_results, tests,
+            test_adaptive_steps, test_answers, test_question_results, tests,
         },
         enums::{error::*, generic::PaginatedResponse},
-        models::test::{QueryTestParams, SaveTestAnswer, UpdateTest},
+        models::test::{AdaptiveStep, QueryTestParams, SaveTestAnswer, UpdateTest},
     },
     sea_orm::{
-        sea_query::OnConflict, ActiveModelTrait, ColumnTrait, Condition, EntityTrait,
-        PaginatorTrait, QueryFilter, QueryOrder, Set, TransactionTrait, TryIntoModel,
+        sea_query::{Expr, OnConflict},
+        ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, EntityTrait, JoinType,
+        PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, RelationTrait, Set, TransactionTrait,
+        TryIntoModel, UpdateMany,
     },
     std::sync::Arc,
     uuid::Uuid,
 };
 
+// Tăng revision của test, chỉ khớp khi revision vẫn là `expected_revision`
+fn bump_revision(caller_id: Uuid, test_id: Uuid, expected_revision: i32) -> UpdateMany<Tests> {
+    Tests::update_many()
+        .col_expr(
+            tests::Column::Revision,
+            Expr::col(tests::Column::Revision).add(1),
+        )
+        .filter(
+            Condition::all()
+                .add(tests::Column::Id.eq(test_id))
+                .add(tests::Column::UserId.eq(caller_id))
+                .add(tests::Column::Revision.eq(expected_revision)),
+        )
+}
+
+fn practice_attempt(
+    test_id: Uuid,
+    quiz_question_id: Uuid,
+    is_correct: Option<bool>,
+) -> UpdateMany<TestQuestionResults> {
+    TestQuestionResults::update_many()
+        .col_expr(
+            test_question_results::Column::IsCorrect,
+            Expr::value(is_correct),
+        )
+        .col_expr(
+            test_question_results::Column::Attempts,
+            Expr::col(test_question_results::Column::Attempts).add(1),
+        )
+        .filter(
+            Condition::all()
+                .add(test_question_results::Column::TestId.eq(test_id))
+                .add(test_question_results::Column::QuizQuestionId.eq(quiz_question_id)),
+        )
+}
+
+fn take_over_statement(
+    caller_id: Uuid,
+    test_id: Uuid,
+    expected_revision: i32,
+) -> UpdateMany<Tests> {
+    bump_revision(caller_id, test_id, expected_revision)
+        .col_expr(tests::Column::SessionId, Expr::value(Uuid::new_v4()))
+        .filter(tests::Column::Status.is_not_in([StatusEnum::Submitted, StatusEnum::Abandoned]))
+}
+
 pub struct TestRepository {
     db: Arc<Database>,
 }
@@ -26,6 +75,7 @@ impl TestRepository {
         Self { db }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn create_one(
         &self,
         quiz_id: Uuid,
@@ -33,6 +83,8 @@ impl TestRepository {
         duration: i32,
         first_quiz_question_id: Uuid,
         total_question: i32,
+        is_adaptive: bool,
+        is_practice: bool,
     ) -> Result<tests::Model> {
         let conn = self.db.get_connection().await;
 
@@ -43,6 +95,8 @@ impl TestRepository {
             current_quiz_question_id: Set(first_quiz_question_id),
             remaining_time: Set(duration),
             total_question: Set(total_question),
+            is_adaptive: Set(is_adaptive),
+            is_practice: Set(is_practice),
             ..Default::default()
         }
         .insert(&conn)
@@ -124,36 +178,7 @@ impl TestRepository {
             .ok_or(Error::RecordNotFound)?
             .into();
 
-        let mut updated = false;
-
-        if let Some(started_at) = payload.started_at {
-            existing_test.started_at = Set(Some(started_at));
-            updated = true;
-        }
-        if let Some(submitted_at) = payload.submitted_at {
-            existing_test.submitted_at = Set(Some(submitted_at));
-            updated = true;
-        }
-        if let Some(current_testing_quiz_question) = payload.current_testing_quiz_question {
-            existing_test.current_quiz_question_id = Set(current_testing_quiz_question);
-            updated = true;
-        }
-        if let Some(remaining_time) = payload.remaining_time {
-            existing_test.remaining_time = Set(remaining_time);
-            updated = true;
-        }
-        if let Some(resolved_count) = payload.resolved_count {
-            existing_test.completed_questions = Set(resolved_count);
-            updated = true;
-        }
-        if let Some(status) = payload.status {
-            existing_test.status = Set(status);
-            updated = true;
-        }
-        if let Some(score) = payload.score {
-            existing_test.score = Set(Some(score));
-            updated = true;
-        }
+        let updated = apply_update_test(&mut existing_test, payload);
 
         if updated {
             Ok(Some(
@@ -194,28 +219,92 @@ impl TestRepository {
             .await
             .map_err(Error::BeginTransactionFailed)?;
 
-        let mut res = Vec::new();
+        let res = upsert_test_answers(&txn, test_id, quiz_question_id, payloads).await?;
+
+        txn.commit().await.map_err(|e| Error::Anyhow(e.into()))?;
+
+        Ok(res)
+    }
+
+    // Lưu câu trả lời với optimistic concurrency: chỉ ghi khi revision và
+    // session của client khớp với test hiện tại, sau đó tăng revision
+    #[allow(clippy::too_many_arguments)]
+    pub async fn resolve_test_answers(
+        &self,
+        caller_id: Uuid,
+        test_id: Uuid,
+        quiz_question_id: Uuid,
+        expected_revision: i32,
+        session_id: Uuid,
+        payloads: Vec<SaveTestAnswer>,
+        update: UpdateTest,
+        adaptive_step: Option<AdaptiveStep>,
+    ) -> Result<tests::Model> {
+        let txn = self
+            .db
+            .get_connection()
+            .await
+            .begin()
+            .await
+            .map_err(Error::BeginTransactionFailed)?;
+
+        let mut active_model = tests::ActiveModel {
+            ..Default::default()
+        };
+        apply_update_test(&mut active_model, update);
+
+        // UPDATE tests SET ..., revision = revision + 1 WHERE id = test_id AND
+        // user_id = caller_id AND revision = expected_revision AND session_id =
+        // session_id
+        let res = bump_revision(caller_id, test_id, expected_revision)
+            .set(active_model)
+            .filter(tests::Column::SessionId.eq(session_id))
+            .exec(&txn)
+            .await
+            .map_err(Error::UpdateFailed)?;
 
-        for payload in payloads.into_iter() {
-            let json = serde_json::to_value(payload).map_err(|e| Error::Anyhow(e.into()))?;
-            let mut test_result =
-                test_answers::ActiveModel::from_json(json).map_err(|e| Error::Anyhow(e.into()))?;
-            test_result.test_id = Set(test_id);
-            test_result.quiz_question_id = Set(quiz_question_id);
+        if res.rows_affected == 0 {
+            return Err(Error::TestConflict);
+        }
 
-            let model = test_result
-                .save(&txn)
-                .await
-                .map_err(Error::InsertFailed)?
-                .try_into_model()
-                .map_err(Error::IntoModelError)?;
+        upsert_test_answers(&txn, test_id, quiz_question_id, payloads).await?;
 
-            res.push(model);
+        if let Some(step) = adaptive_step {
+            insert_adaptive_step(&txn, test_id, step).await?;
         }
 
-        txn.commit().await.map_err(|e| Error::Anyhow(e.into()))?;
+        let updated_test = Tests::find_by_id(test_id)
+            .one(&txn)
+            .await
+            .map_err(Error::QueryFailed)?
+            .ok_or(Error::RecordNotFound)?;
 
-        Ok(res)
+        txn.commit().await.map_err(Error::CommitTransactionFailed)?;
+
+        Ok(updated_test)
+    }
+
+    // Cấp session mới cho test => mọi ghi từ session cũ sẽ bị từ chối. Chỉ
+    // thành công khi revision chưa đổi, hai lần take-over cùng lúc thì một lần
+    // nhận `TestConflict`
+    pub async fn take_over_session(
+        &self,
+        caller_id: Uuid,
+        test_id: Uuid,
+        expected_revision: i32,
+    ) -> Result<tests::Model> {
+        let conn = self.db.get_connection().await;
+
+        let res = take_over_statement(caller_id, test_id, expected_revision)
+            .exec(&conn)
+            .await
+            .map_err(Error::UpdateFailed)?;
+
+        if res.rows_affected == 0 {
+            return Err(Error::TestConflict);
+        }
+
+        self.get_by_id(caller_id, test_id).await
     }
 
     pub async fn get_test_answers(
@@ -313,6 +402,47 @@ impl TestRepository {
         Ok(res)
     }
 
+    // Chấm ngay một lần trả lời của bài luyện tập, lần sau ghi đè lần trước
+    pub async fn record_practice_attempt(
+        &self,
+        test_id: Uuid,
+        quiz_question_id: Uuid,
+        is_correct: Option<bool>,
+    ) -> Result<()> {
+        let conn = self.db.get_connection().await;
+
+        practice_attempt(test_id, quiz_question_id, is_correct)
+            .exec(&conn)
+            .await
+            .map_err(Error::UpdateFailed)?;
+
+        Ok(())
+    }
+
+    pub async fn set_flag(
+        &self,
+        test_id: Uuid,
+        quiz_question_id: Uuid,
+        is_flagged: bool,
+    ) -> Result<test_question_results::Model> {
+        let conn = self.db.get_connection().await;
+
+        let mut result: test_question_results::ActiveModel = TestQuestionResults::find()
+            .filter(
+                Condition::all()
+                    .add(test_question_results::Column::TestId.eq(test_id))
+                    .add(test_question_results::Column::QuizQuestionId.eq(quiz_question_id)),
+            )
+            .one(&conn)
+            .await
+            .map_err(Error::QueryFailed)?
+            .ok_or(Error::RecordNotFound)?
+            .into();
+
+        result.is_flagged = Set(is_flagged);
+        result.update(&conn).await.map_err(Error::UpdateFailed)
+    }
+
     pub async fn get_test_question_result(
         &self,
         test_id: Uuid,
@@ -334,6 +464,49 @@ impl TestRepository {
             .ok_or(Error::RecordNotFound)
     }
 
+    // (id, độ khó của quiz question, độ khó của câu hỏi gốc) của các câu trong
+    // quiz
+    pub async fn get_quiz_question_difficulties(
+        &self,
+        quiz_id: Uuid,
+    ) -> Result<Vec<(Uuid, Option<f64>, Option<f64>)>> {
+        let conn = self.db.get_connection().await;
+
+        QuizQuestions::find()
+            .select_only()
+            .column(quiz_questions::Column::Id)
+            .column(quiz_questions::Column::Difficulty)
+            .column(questions::Column::Difficulty)
+            .join(
+                JoinType::LeftJoin,
+                quiz_questions::Relation::Questions.def(),
+            )
+            .filter(
+                Condition::all()
+                    .add(quiz_questions::Column::QuizId.eq(quiz_id))
+                    .add(quiz_questions::Column::IsDeleted.eq(false)),
+            )
+            .order_by_asc(quiz_questions::Column::Index)
+            .into_tuple()
+            .all(&conn)
+            .await
+            .map_err(Error::QueryFailed)
+    }
+
+    pub async fn get_adaptive_steps(
+        &self,
+        test_id: Uuid,
+    ) -> Result<Vec<test_adaptive_steps::Model>> {
+        let conn = self.db.get_connection().await;
+
+        TestAdaptiveSteps::find()
+            .filter(test_adaptive_steps::Column::TestId.eq(test_id))
+            .order_by_asc(test_adaptive_steps::Column::Step)
+            .all(&conn)
+            .await
+            .map_err(Error::QueryFailed)
+    }
+
     pub async fn get_all_test_question_result(
         &self,
         test_id: Uuid,
@@ -347,3 +520,180 @@ impl TestRepository {
             .map_err(Error::QueryFailed)
     }
 }
+
+// Ghi bước vừa trả lời và tạo dòng kết quả cho câu tiếp theo, chỉ các câu đã
+// hỏi mới được chấm khi nộp bài
+async fn insert_adaptive_step<C: ConnectionTrait>(
+    conn: &C,
+    test_id: Uuid,
+    step: AdaptiveStep,
+) -> Result<()> {
+    test_adaptive_steps::ActiveModel {
+        test_id: Set(test_id),
+        step: Set(step.step),
+        quiz_question_id: Set(step.quiz_question_id),
+        difficulty: Set(step.difficulty),
+        is_correct: Set(step.is_correct),
+        ability: Set(step.ability),
+        ability_se: Set(step.ability_se),
+        ..Default::default()
+    }
+    .insert(conn)
+    .await
+    .map_err(Error::InsertFailed)?;
+
+    if let Some(next_quiz_question_id) = step.next_quiz_question_id {
+        test_question_results::ActiveModel {
+            test_id: Set(test_id),
+            quiz_question_id: Set(next_quiz_question_id),
+            ..Default::default()
+        }
+        .insert(conn)
+        .await
+        .map_err(Error::InsertFailed)?;
+    }
+
+    Ok(())
+}
+
+fn apply_update_test(model: &mut tests::ActiveModel, payload: UpdateTest) -> bool {
+    let mut updated = false;
+
+    if let Some(started_at) = payload.started_at {
+        model.started_at = Set(Some(started_at));
+        updated = true;
+    }
+    if let Some(submitted_at) = payload.submitted_at {
+        model.submitted_at = Set(Some(submitted_at));
+        updated = true;
+    }
+    if let Some(current_testing_quiz_question) = payload.current_testing_quiz_question {
+        model.current_quiz_question_id = Set(current_testing_quiz_question);
+        updated = true;
+    }
+    if let Some(remaining_time) = payload.remaining_time {
+        model.remaining_time = Set(remaining_time);
+        updated = true;
+    }
+    if let Some(resolved_count) = payload.resolved_count {
+        model.completed_questions = Set(resolved_count);
+        updated = true;
+    }
+    if let Some(status) = payload.status {
+        model.status = Set(status);
+        updated = true;
+    }
+    if let Some(score) = payload.score {
+        model.score = Set(Some(score));
+        updated = true;
+    }
+    if let Some(session_id) = payload.session_id {
+        model.session_id = Set(Some(session_id));
+        updated = true;
+    }
+    if let Some(ability) = payload.ability {
+        model.ability = Set(Some(ability));
+        updated = true;
+    }
+    if let Some(ability_se) = payload.ability_se {
+        model.ability_se = Set(Some(ability_se));
+        updated = true;
+    }
+
+    updated
+}
+
+async fn upsert_test_answers<C: ConnectionTrait>(
+    conn: &C,
+    test_id: Uuid,
+    quiz_question_id: Uuid,
+    payloads: Vec<SaveTestAnswer>,
+) -> Result<Vec<test_answers::Model>> {
+    let mut res = Vec::new();
+
+    for payload in payloads.into_iter() {
+        let json = serde_json::to_value(payload).map_err(|e| Error::Anyhow(e.into()))?;
+        let mut test_result =
+            test_answers::ActiveModel::from_json(json).map_err(|e| Error::Anyhow(e.into()))?;
+        test_result.test_id = Set(test_id);
+        test_result.quiz_question_id = Set(quiz_question_id);
+
+        let model = test_result
+            .save(conn)
+            .await
+            .map_err(Error::InsertFailed)?
+            .try_into_model()
+            .map_err(Error::IntoModelError)?;
+
+        res.push(model);
+    }
+
+    Ok(res)
+}
+
+#[cfg(test)]
+mod statement_tests {
+    use {
+        super::*,
+        sea_orm::{DbBackend, QueryTrait},
+    };
+
+    #[test]
+    fn practice_retry_regrades_and_counts_the_attempt() {
+        let (test_id, quiz_question_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let sql = practice_attempt(test_id, quiz_question_id, Some(false))
+            .build(DbBackend::Postgres)
+            .to_string();
+
+        assert!(
+            sql.contains(r#"SET "is_correct" = FALSE, "attempts" = "attempts" + 1"#),
+            "{sql}"
+        );
+        assert!(
+            sql.contains(&format!(
+                r#""test_id" = '{test_id}' AND "test_question_results"."quiz_question_id" = '{quiz_question_id}'"#
+            )),
+            "{sql}"
+        );
+    }
+
+    #[test]
+    fn take_over_only_matches_the_revision_it_read() {
+        let (caller_id, test_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let sql = take_over_statement(caller_id, test_id, 4)
+            .build(DbBackend::Postgres)
+            .to_string();
+
+        assert!(sql.contains(r#""revision" = "revision" + 1"#), "{sql}");
+        assert!(sql.contains(r#""session_id" = '"#), "{sql}");
+        assert!(sql.contains(&format!(r#""id" = '{test_id}'"#)), "{sql}");
+        assert!(
+            sql.contains(&format!(r#""user_id" = '{caller_id}'"#)),
+            "{sql}"
+        );
+        assert!(sql.contains(r#""revision" = 4"#), "{sql}");
+        // sea-query có bản quote tên enum trong CAST, có bản không
+        let unquoted = sql.replace('"', "");
+        assert!(
+            unquoted.contains(
+                "status NOT IN (CAST('Submitted' AS status_enum), CAST('Abandoned' AS status_enum))"
+            ),
+            "{sql}"
+        );
+    }
+
+    #[test]
+    fn answer_write_needs_current_revision_and_session() {
+        let session_id = Uuid::new_v4();
+        let sql = bump_revision(Uuid::new_v4(), Uuid::new_v4(), 2)
+            .filter(tests::Column::SessionId.eq(session_id))
+            .build(DbBackend::Postgres)
+            .to_string();
+
+        assert!(sql.contains(r#""revision" = 2"#), "{sql}");
+        assert!(
+            sql.contains(&format!(r#""session_id" = '{session_id}'"#)),
+            "{sql}"
+        );
+    }
+}
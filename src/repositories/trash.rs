@@ -0,0 +1,421 @@
+use {
+    crate::{
+        db::db_connection::Database,
+        entities::{
+            prelude::*, questions, quiz_questions, quizes, sets, test_answers,
+            test_question_results, tests,
+        },
+        enums::error::*,
+        models::trash::PurgeReport,
+    },
+    chrono::NaiveDateTime,
+    sea_orm::{
+        sea_query::{Expr, Query},
+        ActiveModelTrait, ColumnTrait, Condition, DatabaseTransaction, DeleteMany, EntityTrait,
+        JoinType, QueryFilter, QueryOrder, QuerySelect, RelationTrait, Select, Set,
+        TransactionTrait, UpdateMany,
+    },
+    std::sync::Arc,
+    uuid::Uuid,
+};
+
+// Chỉ khôi phục câu hỏi bị xoá cùng lúc với set (cùng `deleted_at`), câu hỏi
+// đã bị xoá riêng trước đó vẫn nằm trong thùng rác
+fn restore_questions_of_set(set_id: Uuid, deleted_at: NaiveDateTime) -> UpdateMany<Questions> {
+    Questions::update_many()
+        .col_expr(questions::Column::IsDeleted, Expr::value(false))
+        .col_expr(
+            questions::Column::DeletedAt,
+            Expr::value(None::<NaiveDateTime>),
+        )
+        .filter(
+            Condition::all()
+                .add(questions::Column::SetId.eq(set_id))
+                .add(questions::Column::IsDeleted.eq(true))
+                .add(questions::Column::DeletedAt.eq(deleted_at)),
+        )
+}
+
+fn restore_questions_of_quiz(
+    quiz_id: Uuid,
+    deleted_at: NaiveDateTime,
+) -> UpdateMany<QuizQuestions> {
+    QuizQuestions::update_many()
+        .col_expr(quiz_questions::Column::IsDeleted, Expr::value(false))
+        .col_expr(
+            quiz_questions::Column::DeletedAt,
+            Expr::value(None::<NaiveDateTime>),
+        )
+        .filter(
+            Condition::all()
+                .add(quiz_questions::Column::QuizId.eq(quiz_id))
+                .add(quiz_questions::Column::IsDeleted.eq(true))
+                .add(quiz_questions::Column::DeletedAt.eq(deleted_at)),
+        )
+}
+
+// Quiz quá hạn và chưa có bài test nào
+fn purgeable_quizzes(cutoff: NaiveDateTime) -> Select<Quizes> {
+    Quizes::find()
+        .select_only()
+        .column(quizes::Column::Id)
+        .filter(
+            Condition::all()
+                .add(quizes::Column::IsDeleted.eq(true))
+                .add(quizes::Column::DeletedAt.lt(cutoff))
+                .add(
+                    quizes::Column::Id.not_in_subquery(
+                        Query::select()
+                            .column(tests::Column::QuizId)
+                            .from(Tests)
+                            .to_owned(),
+                    ),
+                ),
+        )
+}
+
+// Câu hỏi quiz quá hạn và chưa có câu trả lời hay kết quả nào
+fn purge_quiz_questions(cutoff: NaiveDateTime) -> DeleteMany<QuizQuestions> {
+    QuizQuestions::delete_many().filter(
+        Condition::all()
+            .add(quiz_questions::Column::IsDeleted.eq(true))
+            .add(quiz_questions::Column::DeletedAt.lt(cutoff))
+            .add(
+                quiz_questions::Column::Id.not_in_subquery(
+                    Query::select()
+                        .column(test_answers::Column::QuizQuestionId)
+                        .from(TestAnswers)
+                        .to_owned(),
+                ),
+            )
+            .add(
+                quiz_questions::Column::Id.not_in_subquery(
+                    Query::select()
+                        .column(test_question_results::Column::QuizQuestionId)
+                        .from(TestQuestionResults)
+                        .to_owned(),
+                ),
+            ),
+    )
+}
+
+pub struct TrashRepository {
+    db: Arc<Database>,
+}
+
+impl TrashRepository {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    async fn begin(&self) -> Result<DatabaseTransaction> {
+        self.db
+            .get_connection()
+            .await
+            .begin()
+            .await
+            .map_err(Error::BeginTransactionFailed)
+    }
+
+    pub async fn get_deleted_sets(&self, owner_id: Uuid) -> Result<Vec<sets::Model>> {
+        let conn = self.db.get_connection().await;
+
+        Sets::find()
+            .filter(
+                Condition::all()
+                    .add(sets::Column::OwnerId.eq(owner_id))
+                    .add(sets::Column::IsDeleted.eq(true)),
+            )
+            .order_by_desc(sets::Column::DeletedAt)
+            .all(&conn)
+            .await
+            .map_err(Error::QueryFailed)
+    }
+
+    // Câu hỏi bị xoá riêng lẻ, set chứa nó vẫn còn
+    pub async fn get_deleted_questions(&self, creator_id: Uuid) -> Result<Vec<questions::Model>> {
+        let conn = self.db.get_connection().await;
+
+        Questions::find()
+            .join(JoinType::InnerJoin, questions::Relation::Sets.def())
+            .filter(
+                Condition::all()
+                    .add(questions::Column::CreatorId.eq(creator_id))
+                    .add(questions::Column::IsDeleted.eq(true))
+                    .add(sets::Column::IsDeleted.eq(false)),
+            )
+            .order_by_desc(questions::Column::DeletedAt)
+            .all(&conn)
+            .await
+            .map_err(Error::QueryFailed)
+    }
+
+    pub async fn get_deleted_quizzes(&self, creator_id: Uuid) -> Result<Vec<quizes::Model>> {
+        let conn = self.db.get_connection().await;
+
+        Quizes::find()
+            .filter(
+                Condition::all()
+                    .add(quizes::Column::CreatorId.eq(creator_id))
+                    .add(quizes::Column::IsDeleted.eq(true)),
+            )
+            .order_by_desc(quizes::Column::DeletedAt)
+            .all(&conn)
+            .await
+            .map_err(Error::QueryFailed)
+    }
+
+    // Khôi phục set và các câu hỏi bị xoá cùng lúc với set
+    pub async fn restore_set(&self, owner_id: Uuid, set_id: Uuid) -> Result<sets::Model> {
+        let txn = self.begin().await?;
+
+        let set = Sets::find_by_id(set_id)
+            .filter(
+                Condition::all()
+                    .add(sets::Column::OwnerId.eq(owner_id))
+                    .add(sets::Column::IsDeleted.eq(true)),
+            )
+            .one(&txn)
+            .await
+            .map_err(Error::QueryFailed)?
+            .ok_or(Error::RecordNotFound)?;
+        let deleted_at = set.deleted_at;
+
+        let mut set: sets::ActiveModel = set.into();
+        set.is_deleted = Set(false);
+        set.deleted_at = Set(None);
+        let set = set.update(&txn).await.map_err(Error::UpdateFailed)?;
+
+        if let Some(deleted_at) = deleted_at {
+            restore_questions_of_set(set_id, deleted_at)
+                .exec(&txn)
+                .await
+                .map_err(Error::UpdateFailed)?;
+        }
+
+        txn.commit().await.map_err(Error::CommitTransactionFailed)?;
+
+        Ok(set)
+    }
+
+    pub async fn restore_question(
+        &self,
+        creator_id: Uuid,
+        question_id: Uuid,
+    ) -> Result<questions::Model> {
+        let conn = self.db.get_connection().await;
+
+        let (question, set) = Questions::find_by_id(question_id)
+            .find_also_related(Sets)
+            .filter(
+                Condition::all()
+                    .add(questions::Column::CreatorId.eq(creator_id))
+                    .add(questions::Column::IsDeleted.eq(true)),
+            )
+            .one(&conn)
+            .await
+            .map_err(Error::QueryFailed)?
+            .ok_or(Error::RecordNotFound)?;
+
+        if set.is_none_or(|set| set.is_deleted) {
+            return Err(Error::ParentDeleted);
+        }
+
+        let mut question: questions::ActiveModel = question.into();
+        question.is_deleted = Set(false);
+        question.deleted_at = Set(None);
+
+        question.update(&conn).await.map_err(Error::UpdateFailed)
+    }
+
+    // Khôi phục quiz và các câu hỏi bị xoá cùng lúc với quiz
+    pub async fn restore_quiz(&self, creator_id: Uuid, quiz_id: Uuid) -> Result<quizes::Model> {
+        let txn = self.begin().await?;
+
+        let quiz = Quizes::find_by_id(quiz_id)
+            .filter(
+                Condition::all()
+                    .add(quizes::Column::CreatorId.eq(creator_id))
+                    .add(quizes::Column::IsDeleted.eq(true)),
+            )
+            .one(&txn)
+            .await
+            .map_err(Error::QueryFailed)?
+            .ok_or(Error::RecordNotFound)?;
+        let deleted_at = quiz.deleted_at;
+
+        let mut quiz: quizes::ActiveModel = quiz.into();
+        quiz.is_deleted = Set(false);
+        quiz.deleted_at = Set(None);
+        let quiz = quiz.update(&txn).await.map_err(Error::UpdateFailed)?;
+
+        if let Some(deleted_at) = deleted_at {
+            restore_questions_of_quiz(quiz_id, deleted_at)
+                .exec(&txn)
+                .await
+                .map_err(Error::UpdateFailed)?;
+        }
+
+        txn.commit().await.map_err(Error::CommitTransactionFailed)?;
+
+        Ok(quiz)
+    }
+
+    // Xoá hẳn nội dung đã nằm trong thùng rác trước `cutoff`. Quiz đã có bài test
+    // và câu hỏi quiz đã có câu trả lời được giữ lại vì `tests`, `test_answers`,
+    // `test_question_results` tham chiếu tới chúng với `Restrict`
+    pub async fn purge(&self, cutoff: NaiveDateTime) -> Result<PurgeReport> {
+        let txn = self.begin().await?;
+        let mut report = PurgeReport::default();
+
+        // quiz_questions.quiz_id là `Restrict` nên xoá câu hỏi của quiz trước
+        let quiz_ids = purgeable_quizzes(cutoff)
+            .into_tuple::<Uuid>()
+            .all(&txn)
+            .await
+            .map_err(Error::QueryFailed)?;
+
+        if !quiz_ids.is_empty() {
+            report.quiz_questions += QuizQuestions::delete_many()
+                .filter(quiz_questions::Column::QuizId.is_in(quiz_ids.clone()))
+                .exec(&txn)
+                .await
+                .map_err(Error::DeleteFailed)?
+                .rows_affected;
+            report.quizzes = Quizes::delete_many()
+                .filter(quizes::Column::Id.is_in(quiz_ids))
+                .exec(&txn)
+                .await
+                .map_err(Error::DeleteFailed)?
+                .rows_affected;
+        }
+
+        report.quiz_questions += purge_quiz_questions(cutoff)
+            .exec(&txn)
+            .await
+            .map_err(Error::DeleteFailed)?
+            .rows_affected;
+
+        // questions.set_id là `Restrict`, quiz_questions.sample_id tự về NULL
+        let set_ids = Sets::find()
+            .select_only()
+            .column(sets::Column::Id)
+            .filter(
+                Condition::all()
+                    .add(sets::Column::IsDeleted.eq(true))
+                    .add(sets::Column::DeletedAt.lt(cutoff)),
+            )
+            .into_tuple::<Uuid>()
+            .all(&txn)
+            .await
+            .map_err(Error::QueryFailed)?;
+
+        if !set_ids.is_empty() {
+            report.questions += Questions::delete_many()
+                .filter(questions::Column::SetId.is_in(set_ids.clone()))
+                .exec(&txn)
+                .await
+                .map_err(Error::DeleteFailed)?
+                .rows_affected;
+            report.sets = Sets::delete_many()
+                .filter(sets::Column::Id.is_in(set_ids))
+                .exec(&txn)
+                .await
+                .map_err(Error::DeleteFailed)?
+                .rows_affected;
+        }
+
+        report.questions += Questions::delete_many()
+            .filter(
+                Condition::all()
+                    .add(questions::Column::IsDeleted.eq(true))
+                    .add(questions::Column::DeletedAt.lt(cutoff)),
+            )
+            .exec(&txn)
+            .await
+            .map_err(Error::DeleteFailed)?
+            .rows_affected;
+
+        txn.commit().await.map_err(Error::CommitTransactionFailed)?;
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod statement_tests {
+    use {
+        super::*,
+        chrono::NaiveDate,
+        sea_orm::{DbBackend, QueryTrait},
+    };
+
+    // tuỳ bản sea-query mà literal có thêm phần micro giây, chỉ so tới giây
+    fn at(hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 10, 1)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn restore_only_brings_back_children_deleted_with_parent() {
+        let set_id = Uuid::new_v4();
+        let sql = restore_questions_of_set(set_id, at(9))
+            .build(DbBackend::Postgres)
+            .to_string();
+
+        assert!(
+            sql.contains(r#"SET "is_deleted" = FALSE, "deleted_at" = NULL"#),
+            "{sql}"
+        );
+        assert!(sql.contains(&format!(r#""set_id" = '{set_id}'"#)), "{sql}");
+        assert!(
+            sql.contains(r#""deleted_at" = '2026-10-01 09:00:00"#),
+            "{sql}"
+        );
+
+        let quiz_id = Uuid::new_v4();
+        let sql = restore_questions_of_quiz(quiz_id, at(9))
+            .build(DbBackend::Postgres)
+            .to_string();
+
+        assert!(
+            sql.contains(&format!(r#""quiz_id" = '{quiz_id}'"#)),
+            "{sql}"
+        );
+        assert!(
+            sql.contains(r#""deleted_at" = '2026-10-01 09:00:00"#),
+            "{sql}"
+        );
+    }
+
+    #[test]
+    fn purge_keeps_taken_quizzes_and_answered_quiz_questions() {
+        let sql = purgeable_quizzes(at(0))
+            .build(DbBackend::Postgres)
+            .to_string();
+
+        assert!(
+            sql.contains(r#""deleted_at" < '2026-10-01 00:00:00"#),
+            "{sql}"
+        );
+        assert!(
+            sql.contains(r#""id" NOT IN (SELECT "quiz_id" FROM "tests")"#),
+            "{sql}"
+        );
+
+        let sql = purge_quiz_questions(at(0))
+            .build(DbBackend::Postgres)
+            .to_string();
+
+        assert!(
+            sql.contains(r#""id" NOT IN (SELECT "quiz_question_id" FROM "test_answers")"#),
+            "{sql}"
+        );
+        assert!(
+            sql.contains(r#""id" NOT IN (SELECT "quiz_question_id" FROM "test_question_results")"#),
+            "{sql}"
+        );
+    }
+}
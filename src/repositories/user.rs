@@ -1,12 +1,13 @@
 use {
     crate::{
         db::db_connection::Database,
-        entities::{prelude::*, sea_orm_active_enums::RoleEnum, users},
+        entities::{prelude::*, recovery_codes, sea_orm_active_enums::RoleEnum, users},
         enums::error::{Error, Result},
         models::user::{RegisterUserRequest, UpdateUserRequest},
     },
     sea_orm::{
-        ActiveModelTrait, ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter, Set,
+        sea_query::Expr, ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, EntityTrait,
+        PaginatorTrait, QueryFilter, Set, TransactionTrait,
     },
     std::sync::Arc,
     uuid::Uuid,
@@ -42,33 +43,48 @@ impl UserRepository {
             .ok_or(Error::RecordNotFound)
     }
 
+    pub async fn set_avatar_media(&self, user_id: Uuid, media_id: Uuid) -> Result<users::Model> {
+        let conn = self.db.get_connection().await;
+
+        let mut user: users::ActiveModel = self.get_by_id(user_id).await?.into();
+        user.avatar_media_id = Set(Some(media_id));
+        user.avatar_url = Set(None);
+        user.updated_at = Set(chrono::Utc::now().naive_utc());
+
+        user.update(&conn).await.map_err(Error::UpdateFailed)
+    }
+
     // Done ✅
     pub async fn create_user(&self, payload: RegisterUserRequest) -> Result<users::Model> {
         let conn = self.db.get_connection().await;
 
-        let RegisterUserRequest {
-            email,
-            password,
-            name,
-            role,
-            avatar_url,
-        } = payload;
+        users::Entity::insert(registered_user(payload))
+            .exec_with_returning(&conn)
+            .await
+            .map_err(Error::InsertFailed)
+    }
 
-        let new_user = users::ActiveModel {
-            name: Set(name),
-            email: Set(email),
-            password: Set(password),
-            role: Set(role),
-            avatar_url: Set(avatar_url),
-            ..Default::default()
-        };
+    // Tạo tài khoản staff đầu tiên từ CLI, đã xác minh sẵn
+    pub async fn create_staff(&self, payload: RegisterUserRequest) -> Result<users::Model> {
+        let conn = self.db.get_connection().await;
 
-        users::Entity::insert(new_user)
+        users::Entity::insert(bootstrap_staff(payload))
             .exec_with_returning(&conn)
             .await
             .map_err(Error::InsertFailed)
     }
 
+    // Lịch sử đổi role nằm trong audit log
+    pub async fn update_role(&self, user_id: Uuid, new_role: RoleEnum) -> Result<users::Model> {
+        let conn = self.db.get_connection().await;
+
+        let mut user: users::ActiveModel = self.get_by_id(user_id).await?.into();
+        user.role = Set(new_role);
+        user.updated_at = Set(chrono::Utc::now().naive_utc());
+
+        user.update(&conn).await.map_err(Error::UpdateFailed)
+    }
+
     // Done ✅
     pub async fn update_user(
         &self,
@@ -122,6 +138,7 @@ impl UserRepository {
         }
         if let Some(avatar_url) = avatar_url {
             user.avatar_url = Set(Some(avatar_url));
+            user.avatar_media_id = Set(None);
             updated = true;
         }
 
@@ -164,6 +181,173 @@ impl UserRepository {
         Ok(())
     }
 
+    pub async fn mark_verified(&self, user_id: Uuid) -> Result<users::Model> {
+        let conn = self.db.get_connection().await;
+        let mut user: users::ActiveModel = Users::find_by_id(user_id)
+            .filter(users::Column::IsDeleted.eq(false))
+            .one(&conn)
+            .await
+            .map_err(Error::QueryFailed)?
+            .ok_or(Error::RecordNotFound)?
+            .into();
+
+        user.is_verified = Set(true);
+        user.updated_at = Set(chrono::Utc::now().naive_utc());
+        user.update(&conn).await.map_err(Error::UpdateFailed)
+    }
+
+    // Lưu secret TOTP đang chờ kích hoạt
+    pub async fn set_totp_secret(&self, user_id: Uuid, secret: String) -> Result<()> {
+        let conn = self.db.get_connection().await;
+
+        let res = Users::update_many()
+            .col_expr(users::Column::TotpSecret, Expr::value(secret))
+            .col_expr(
+                users::Column::TotpLastStep,
+                Expr::value(Option::<i64>::None),
+            )
+            .filter(
+                Condition::all()
+                    .add(users::Column::Id.eq(user_id))
+                    .add(users::Column::TotpEnabled.eq(false))
+                    .add(users::Column::IsDeleted.eq(false)),
+            )
+            .exec(&conn)
+            .await
+            .map_err(Error::UpdateFailed)?;
+
+        if res.rows_affected == 0 {
+            return Err(Error::TotpAlreadyEnabled);
+        }
+
+        Ok(())
+    }
+
+    // Bật TOTP và thay toàn bộ recovery code cũ
+    pub async fn enable_totp(
+        &self,
+        user_id: Uuid,
+        step: i64,
+        code_hashes: Vec<String>,
+    ) -> Result<()> {
+        let txn = self
+            .db
+            .get_connection()
+            .await
+            .begin()
+            .await
+            .map_err(Error::BeginTransactionFailed)?;
+
+        let res = Users::update_many()
+            .col_expr(users::Column::TotpEnabled, Expr::value(true))
+            .col_expr(users::Column::TotpLastStep, Expr::value(step))
+            .filter(
+                Condition::all()
+                    .add(users::Column::Id.eq(user_id))
+                    .add(users::Column::TotpEnabled.eq(false)),
+            )
+            .exec(&txn)
+            .await
+            .map_err(Error::UpdateFailed)?;
+
+        if res.rows_affected == 0 {
+            return Err(Error::TotpAlreadyEnabled);
+        }
+
+        delete_recovery_codes(&txn, user_id).await?;
+        RecoveryCodes::insert_many(code_hashes.into_iter().map(|code_hash| {
+            recovery_codes::ActiveModel {
+                user_id: Set(user_id),
+                code_hash: Set(code_hash),
+                ..Default::default()
+            }
+        }))
+        .exec(&txn)
+        .await
+        .map_err(Error::InsertFailed)?;
+
+        txn.commit().await.map_err(Error::CommitTransactionFailed)
+    }
+
+    // Chỉ nhận bước mới hơn bước đã dùng, tránh hai request cùng dùng một code
+    pub async fn advance_totp_step(&self, user_id: Uuid, step: i64) -> Result<bool> {
+        let conn = self.db.get_connection().await;
+
+        let res = Users::update_many()
+            .col_expr(users::Column::TotpLastStep, Expr::value(step))
+            .filter(
+                Condition::all().add(users::Column::Id.eq(user_id)).add(
+                    Condition::any()
+                        .add(users::Column::TotpLastStep.is_null())
+                        .add(users::Column::TotpLastStep.lt(step)),
+                ),
+            )
+            .exec(&conn)
+            .await
+            .map_err(Error::UpdateFailed)?;
+
+        Ok(res.rows_affected == 1)
+    }
+
+    pub async fn consume_recovery_code(&self, user_id: Uuid, code_hash: String) -> Result<bool> {
+        let conn = self.db.get_connection().await;
+
+        let res = RecoveryCodes::update_many()
+            .col_expr(
+                recovery_codes::Column::UsedAt,
+                Expr::value(chrono::Utc::now().naive_utc()),
+            )
+            .filter(
+                Condition::all()
+                    .add(recovery_codes::Column::UserId.eq(user_id))
+                    .add(recovery_codes::Column::CodeHash.eq(code_hash))
+                    .add(recovery_codes::Column::UsedAt.is_null()),
+            )
+            .exec(&conn)
+            .await
+            .map_err(Error::UpdateFailed)?;
+
+        Ok(res.rows_affected == 1)
+    }
+
+    // Tắt TOTP, xoá secret và recovery code
+    pub async fn reset_totp(&self, user_id: Uuid) -> Result<()> {
+        let txn = self
+            .db
+            .get_connection()
+            .await
+            .begin()
+            .await
+            .map_err(Error::BeginTransactionFailed)?;
+
+        let res = Users::update_many()
+            .col_expr(users::Column::TotpEnabled, Expr::value(false))
+            .col_expr(
+                users::Column::TotpSecret,
+                Expr::value(Option::<String>::None),
+            )
+            .col_expr(
+                users::Column::TotpLastStep,
+                Expr::value(Option::<i64>::None),
+            )
+            .filter(
+                Condition::all()
+                    .add(users::Column::Id.eq(user_id))
+                    .add(users::Column::IsDeleted.eq(false)),
+            )
+            .exec(&txn)
+            .await
+            .map_err(Error::UpdateFailed)?;
+
+        if res.rows_affected == 0 {
+            return Err(Error::RecordNotFound);
+        }
+
+        delete_recovery_codes(&txn, user_id).await?;
+
+        txn.commit().await.map_err(Error::CommitTransactionFailed)
+    }
+
     pub async fn check_role(&self, user_id: Uuid, role: RoleEnum) -> Result<bool> {
         let conn = self.db.get_connection().await;
 
@@ -171,7 +355,7 @@ impl UserRepository {
             .filter(
                 Condition::all()
                     .add(users::Column::Role.eq(role))
-                    .add(users::Column::IsDeleted.eq(true)),
+                    .add(users::Column::IsDeleted.eq(false)),
             )
             .one(&conn)
             .await
@@ -180,3 +364,78 @@ impl UserRepository {
         Ok(user.is_some())
     }
 }
+
+// Đăng ký luôn là User chưa xác minh, role không nhận từ request
+fn registered_user(payload: RegisterUserRequest) -> users::ActiveModel {
+    new_user_active_model(payload, RoleEnum::User, false)
+}
+
+// Staff đầu tiên tạo từ CLI không có email nào để xác minh
+fn bootstrap_staff(payload: RegisterUserRequest) -> users::ActiveModel {
+    new_user_active_model(payload, RoleEnum::Staff, true)
+}
+
+pub(crate) fn new_user_active_model(
+    payload: RegisterUserRequest,
+    role: RoleEnum,
+    is_verified: bool,
+) -> users::ActiveModel {
+    let RegisterUserRequest {
+        email,
+        password,
+        name,
+        avatar_url,
+    } = payload;
+
+    users::ActiveModel {
+        name: Set(name),
+        email: Set(email),
+        password: Set(password),
+        role: Set(role),
+        avatar_url: Set(avatar_url),
+        is_verified: Set(is_verified),
+        ..Default::default()
+    }
+}
+
+async fn delete_recovery_codes<C: ConnectionTrait>(conn: &C, user_id: Uuid) -> Result<()> {
+    RecoveryCodes::delete_many()
+        .filter(recovery_codes::Column::UserId.eq(user_id))
+        .exec(conn)
+        .await
+        .map_err(Error::DeleteFailed)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, sea_orm::ActiveValue, serde_json::json};
+
+    fn payload() -> RegisterUserRequest {
+        serde_json::from_value(json!({
+            "email": "an@example.com",
+            "password": "hashed",
+            "name": "An",
+            "role": "Staff",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn registration_always_creates_unverified_user() {
+        let user = registered_user(payload());
+
+        assert_eq!(user.role, ActiveValue::Set(RoleEnum::User));
+        assert_eq!(user.is_verified, ActiveValue::Set(false));
+    }
+
+    #[test]
+    fn bootstrap_creates_verified_staff() {
+        let user = bootstrap_staff(payload());
+
+        assert_eq!(user.role, ActiveValue::Set(RoleEnum::Staff));
+        assert_eq!(user.is_verified, ActiveValue::Set(true));
+        assert_eq!(user.email, ActiveValue::Set("an@example.com".to_owned()));
+    }
+}
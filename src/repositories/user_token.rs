@@ -0,0 +1,145 @@
+use {
+    crate::{
+        db::db_connection::Database,
+        entities::{prelude::UserTokens, sea_orm_active_enums::TokenPurposeEnum, user_tokens},
+        enums::error::*,
+    },
+    chrono::{NaiveDateTime, Utc},
+    sea_orm::{
+        sea_query::Expr, ActiveModelTrait, ColumnTrait, Condition, EntityTrait, QueryFilter, Set,
+        UpdateMany,
+    },
+    serde_json::Value as JsonValue,
+    std::sync::Arc,
+    uuid::Uuid,
+};
+
+// Chỉ token chưa dùng và còn hạn mới được đánh dấu, dùng lần hai không khớp
+// dòng nào
+fn consume_statement(
+    id: Uuid,
+    purpose: TokenPurposeEnum,
+    now: NaiveDateTime,
+) -> UpdateMany<UserTokens> {
+    UserTokens::update_many()
+        .col_expr(user_tokens::Column::UsedAt, Expr::value(now))
+        .filter(
+            Condition::all()
+                .add(user_tokens::Column::Id.eq(id))
+                .add(user_tokens::Column::Purpose.eq(purpose))
+                .add(user_tokens::Column::UsedAt.is_null())
+                .add(user_tokens::Column::ExpiresAt.gt(now)),
+        )
+}
+
+pub struct UserTokenRepository {
+    db: Arc<Database>,
+}
+
+impl UserTokenRepository {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    pub async fn create_one(
+        &self,
+        user_id: Option<Uuid>,
+        email: String,
+        purpose: TokenPurposeEnum,
+        payload: Option<JsonValue>,
+        expires_at: NaiveDateTime,
+    ) -> Result<user_tokens::Model> {
+        let conn = self.db.get_connection().await;
+
+        user_tokens::ActiveModel {
+            user_id: Set(user_id),
+            email: Set(email),
+            purpose: Set(purpose),
+            payload: Set(payload),
+            expires_at: Set(expires_at),
+            ..Default::default()
+        }
+        .insert(&conn)
+        .await
+        .map_err(Error::InsertFailed)
+    }
+
+    // Đánh dấu token đã dùng, chỉ thành công một lần với token còn hạn
+    pub async fn consume(&self, id: Uuid, purpose: TokenPurposeEnum) -> Result<user_tokens::Model> {
+        let conn = self.db.get_connection().await;
+        let now = Utc::now().naive_utc();
+
+        let res = consume_statement(id, purpose, now)
+            .exec(&conn)
+            .await
+            .map_err(Error::UpdateFailed)?;
+
+        if res.rows_affected == 0 {
+            return Err(Error::InvalidToken);
+        }
+
+        UserTokens::find_by_id(id)
+            .one(&conn)
+            .await
+            .map_err(Error::QueryFailed)?
+            .ok_or(Error::InvalidToken)
+    }
+
+    // Vô hiệu hoá các token chưa dùng của email cho cùng mục đích
+    pub async fn revoke_all(&self, email: &str, purpose: TokenPurposeEnum) -> Result<()> {
+        let conn = self.db.get_connection().await;
+
+        UserTokens::update_many()
+            .col_expr(
+                user_tokens::Column::UsedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(
+                Condition::all()
+                    .add(user_tokens::Column::Email.eq(email))
+                    .add(user_tokens::Column::Purpose.eq(purpose))
+                    .add(user_tokens::Column::UsedAt.is_null()),
+            )
+            .exec(&conn)
+            .await
+            .map_err(Error::UpdateFailed)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        chrono::NaiveDate,
+        sea_orm::{DbBackend, QueryTrait},
+    };
+
+    #[test]
+    fn invite_token_is_consumed_only_once_before_expiry() {
+        let id = Uuid::new_v4();
+        let now = NaiveDate::from_ymd_opt(2026, 10, 1)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap();
+        let sql = consume_statement(id, TokenPurposeEnum::InviteStaff, now)
+            .build(DbBackend::Postgres)
+            .to_string()
+            .replace('"', "");
+        let (_, filter) = sql.split_once("WHERE").unwrap();
+
+        assert!(filter.contains(&format!("id = '{id}'")), "{sql}");
+        // sea-query có bản quote tên enum trong CAST, có bản không
+        assert!(
+            filter.contains("CAST('InviteStaff' AS token_purpose_enum)"),
+            "{sql}"
+        );
+        assert!(filter.contains("used_at IS NULL"), "{sql}");
+        // tuỳ bản sea-query mà literal có thêm phần micro giây, chỉ so tới giây
+        assert!(
+            filter.contains("expires_at > '2026-10-01 09:00:00"),
+            "{sql}"
+        );
+    }
+}
@@ -0,0 +1,14 @@
+use {
+    crate::{
+        controllers::audit_controller::AuditController, middleware::jwt::check_jwt,
+        server::AppState,
+    },
+    axum::{middleware, routing::get, Router},
+};
+
+pub fn get_audit_router(state: &AppState) -> Router {
+    Router::new()
+        .route("/", get(AuditController::get_all))
+        .layer(middleware::from_fn_with_state(state.clone(), check_jwt))
+        .with_state(state.clone())
+}
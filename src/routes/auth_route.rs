@@ -1,18 +1,36 @@
 use {
     crate::{
-        controllers::auth_controller::AuthController, middleware::jwt::check_jwt, server::AppState,
+        controllers::auth_controller::AuthController,
+        middleware::{jwt::check_jwt, rate_limit::limit_auth},
+        server::AppState,
+    },
+    axum::{
+        middleware,
+        routing::{get, post},
+        Router,
     },
-    axum::{middleware, routing::post, Router},
 };
 
 pub fn get_auth_router(state: &AppState) -> Router {
     let logout_router = Router::new()
         .route("/logout", post(AuthController::logout))
-        .layer(middleware::from_fn(check_jwt));
+        .layer(middleware::from_fn_with_state(state.clone(), check_jwt));
 
     let auth_router = Router::new()
         .route("/register", post(AuthController::register_user))
         .route("/login", post(AuthController::login))
+        .route("/login/totp", post(AuthController::login_totp))
+        .route("/login/totp/setup", post(AuthController::login_totp_setup))
+        .route("/oidc/authorize", get(AuthController::oidc_authorize))
+        .route("/oidc/callback", post(AuthController::oidc_callback))
+        .route("/verify-email", post(AuthController::verify_email))
+        .route(
+            "/resend-verification",
+            post(AuthController::resend_verification),
+        )
+        .route("/forgot-password", post(AuthController::forgot_password))
+        .route("/reset-password", post(AuthController::reset_password))
+        .layer(middleware::from_fn_with_state(state.clone(), limit_auth))
         .with_state(state.clone());
 
     Router::new().merge(logout_router).merge(auth_router)
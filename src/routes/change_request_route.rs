@@ -0,0 +1,21 @@
+use {
+    crate::{
+        controllers::change_request_controller::ChangeRequestController,
+        middleware::jwt::check_jwt, server::AppState,
+    },
+    axum::{
+        middleware,
+        routing::{get, post},
+        Router,
+    },
+};
+
+pub fn get_change_request_router(state: &AppState) -> Router {
+    Router::new()
+        .route("/{id}", get(ChangeRequestController::get_by_id))
+        .route("/{id}/accept", post(ChangeRequestController::accept))
+        .route("/{id}/reject", post(ChangeRequestController::reject))
+        .route("/{id}/withdraw", post(ChangeRequestController::withdraw))
+        .layer(middleware::from_fn_with_state(state.clone(), check_jwt))
+        .with_state(state.clone())
+}
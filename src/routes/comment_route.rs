@@ -0,0 +1,23 @@
+use {
+    crate::{
+        controllers::comment_controller::CommentController, middleware::jwt::check_jwt,
+        server::AppState,
+    },
+    axum::{
+        middleware,
+        routing::{get, patch},
+        Router,
+    },
+};
+
+pub fn get_comment_router(state: &AppState) -> Router {
+    Router::new()
+        .route("/mentions", get(CommentController::get_mentions))
+        .route(
+            "/{id}",
+            patch(CommentController::update).delete(CommentController::delete),
+        )
+        .route("/{id}/resolve", patch(CommentController::resolve))
+        .layer(middleware::from_fn_with_state(state.clone(), check_jwt))
+        .with_state(state.clone())
+}
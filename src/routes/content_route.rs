@@ -0,0 +1,14 @@
+use {
+    crate::{
+        controllers::content_controller::ContentController, middleware::jwt::check_jwt,
+        server::AppState,
+    },
+    axum::{middleware, routing::post, Router},
+};
+
+pub fn get_content_router(state: &AppState) -> Router {
+    Router::new()
+        .route("/render", post(ContentController::render))
+        .layer(middleware::from_fn_with_state(state.clone(), check_jwt))
+        .with_state(state.clone())
+}
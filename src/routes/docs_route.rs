@@ -0,0 +1,8 @@
+use {crate::openapi::ApiDoc, axum::Router, utoipa::OpenApi, utoipa_swagger_ui::SwaggerUi};
+
+// Swagger UI ở /api/docs, spec ở /api/openapi.json
+pub fn get_docs_router() -> Router {
+    SwaggerUi::new("/api/docs")
+        .url("/api/openapi.json", ApiDoc::openapi())
+        .into()
+}
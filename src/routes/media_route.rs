@@ -0,0 +1,30 @@
+use {
+    crate::{
+        controllers::media_controller::MediaController, middleware::jwt::check_jwt,
+        server::AppState,
+    },
+    axum::{
+        extract::DefaultBodyLimit,
+        middleware,
+        routing::{get, post},
+        Router,
+    },
+};
+
+pub fn get_media_router(state: &AppState) -> Router {
+    Router::new()
+        .route(
+            "/",
+            post(MediaController::upload).layer(DefaultBodyLimit::max(
+                state.media_service.max_upload_bytes(),
+            )),
+        )
+        .route(
+            "/{id}",
+            get(MediaController::get).delete(MediaController::delete),
+        )
+        .layer(middleware::from_fn_with_state(state.clone(), check_jwt))
+        // link đã ký, không qua check_jwt
+        .route("/{id}/content", get(MediaController::download))
+        .with_state(state.clone())
+}
@@ -1,20 +1,42 @@
 use {
-    crate::{controllers::fallback, server::AppState},
+    crate::{
+        controllers::{auth_controller::AuthController, fallback},
+        middleware::{rate_limit::limit_write, request_id::request_id},
+        server::AppState,
+    },
+    audit_route::get_audit_router,
     auth_route::get_auth_router,
-    axum::{routing::get, Router},
+    axum::{middleware, routing::get, Router},
+    change_request_route::get_change_request_router,
+    comment_route::get_comment_router,
+    content_route::get_content_router,
+    docs_route::get_docs_router,
+    media_route::get_media_router,
+    note_route::get_note_router,
     qna_route::get_question_router,
     quiz_route::quiz_router,
     set_route::get_set_router,
+    tag_route::get_tag_router,
     test_route::get_test_router,
+    trash_route::get_trash_router,
     user_route::get_user_router,
 };
 
+mod audit_route;
 pub mod auth_route;
+mod change_request_route;
+mod comment_route;
+mod content_route;
+mod docs_route;
+mod media_route;
+mod note_route;
 mod qna_route;
 pub mod quiz_question_route;
 mod quiz_route;
 mod set_route;
+mod tag_route;
 pub mod test_route;
+mod trash_route;
 mod user_route;
 
 async fn root() -> &'static str {
@@ -28,10 +50,22 @@ pub fn setup_routing(state: AppState) -> Router {
         .nest("/sets", get_set_router(&state))
         .nest("/questions", get_question_router(&state))
         .nest("/quizzes", quiz_router(&state))
-        .nest("/test", get_test_router(&state));
+        .nest("/test", get_test_router(&state))
+        .nest("/trash", get_trash_router(&state))
+        .nest("/audit-events", get_audit_router(&state))
+        .nest("/media", get_media_router(&state))
+        .nest("/content", get_content_router(&state))
+        .nest("/tags", get_tag_router(&state))
+        .nest("/notes", get_note_router(&state))
+        .nest("/comments", get_comment_router(&state))
+        .nest("/change-requests", get_change_request_router(&state))
+        .layer(middleware::from_fn_with_state(state.clone(), limit_write));
 
     Router::new()
         .fallback(fallback)
         .route("/", get(root))
+        .route("/.well-known/jwks.json", get(AuthController::jwks))
         .nest("/api", api_routes)
+        .merge(get_docs_router())
+        .layer(middleware::from_fn(request_id))
 }
@@ -0,0 +1,13 @@
+use {
+    crate::{
+        controllers::note_controller::NoteController, middleware::jwt::check_jwt, server::AppState,
+    },
+    axum::{middleware, routing::get, Router},
+};
+
+pub fn get_note_router(state: &AppState) -> Router {
+    Router::new()
+        .route("/", get(NoteController::get_all))
+        .layer(middleware::from_fn_with_state(state.clone(), check_jwt))
+        .with_state(state.clone())
+}
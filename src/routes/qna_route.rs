@@ -1,8 +1,18 @@
 use {
     crate::{
-        controllers::qna_controller::QnAController, middleware::jwt::check_jwt, server::AppState,
+        controllers::{
+            change_request_controller::ChangeRequestController,
+            comment_controller::CommentController, note_controller::NoteController,
+            qna_controller::QnAController,
+        },
+        middleware::jwt::check_jwt,
+        server::AppState,
+    },
+    axum::{
+        middleware,
+        routing::{get, post},
+        Router,
     },
-    axum::{middleware, routing::get, Router},
 };
 
 pub fn get_question_router(state: &AppState) -> Router {
@@ -14,6 +24,30 @@ pub fn get_question_router(state: &AppState) -> Router {
                 .patch(QnAController::update)
                 .delete(QnAController::delete),
         )
-        .layer(middleware::from_fn(check_jwt))
+        .route(
+            "/{id}/tags",
+            get(QnAController::get_tags).put(QnAController::set_tags),
+        )
+        .route("/{id}/revisions", get(QnAController::get_revisions))
+        .route(
+            "/{id}/revisions/{revision_id}/rollback",
+            post(QnAController::rollback),
+        )
+        .route(
+            "/{id}/note",
+            get(NoteController::get_question_note)
+                .put(NoteController::save_question_note)
+                .delete(NoteController::delete_question_note),
+        )
+        .route(
+            "/{id}/comments",
+            get(CommentController::get_question_comments)
+                .post(CommentController::create_question_comment),
+        )
+        .route(
+            "/{id}/change-requests",
+            get(ChangeRequestController::get_all_of_question).post(ChangeRequestController::create),
+        )
+        .layer(middleware::from_fn_with_state(state.clone(), check_jwt))
         .with_state(state.clone())
 }
@@ -1,6 +1,9 @@
 use {
     crate::{
-        controllers::quiz_question_controller::QuizQuestionController, middleware::jwt::check_jwt,
+        controllers::{
+            note_controller::NoteController, quiz_question_controller::QuizQuestionController,
+        },
+        middleware::jwt::check_jwt,
         server::AppState,
     },
     axum::{
@@ -22,10 +25,24 @@ pub fn quiz_question_router(state: &AppState) -> Router {
             "/{quiz_id}/questions/{quiz_question_id}",
             get(QuizQuestionController::get_by_id).delete(QuizQuestionController::delete),
         )
+        .route(
+            "/{quiz_id}/questions/{quiz_question_id}/note",
+            get(NoteController::get_quiz_question_note)
+                .put(NoteController::save_quiz_question_note)
+                .delete(NoteController::delete_quiz_question_note),
+        )
         .route(
             "/create-from-questions",
             post(QuizQuestionController::create_from_questions),
         )
-        .layer(middleware::from_fn(check_jwt))
+        .route(
+            "/create-from-tags",
+            post(QuizQuestionController::create_from_tags),
+        )
+        .route(
+            "/create-from-mistakes",
+            post(QuizQuestionController::create_from_mistakes),
+        )
+        .layer(middleware::from_fn_with_state(state.clone(), check_jwt))
         .with_state(state.clone())
 }
@@ -1,7 +1,9 @@
 use {
     super::quiz_question_route::quiz_question_router,
     crate::{
-        controllers::quiz_controller::QuizController, middleware::jwt::check_jwt, server::AppState,
+        controllers::{ownership_controller::OwnershipController, quiz_controller::QuizController},
+        middleware::jwt::check_jwt,
+        server::AppState,
     },
     axum::{
         middleware,
@@ -20,7 +22,7 @@ pub fn quiz_router(state: &AppState) -> Router {
                 .patch(QuizController::update)
                 .delete(QuizController::delete),
         )
-        .layer(middleware::from_fn(check_jwt))
+        .layer(middleware::from_fn_with_state(state.clone(), check_jwt))
         .with_state(state.clone());
 
     let share_quiz_router = Router::new()
@@ -29,7 +31,12 @@ pub fn quiz_router(state: &AppState) -> Router {
             "/{id}/shared_users",
             get(QuizController::get_all_shared_users_of_quiz),
         )
-        .layer(middleware::from_fn(check_jwt))
+        .route("/{id}/transfer", post(OwnershipController::transfer_quiz))
+        .route(
+            "/{id}/transfers",
+            get(OwnershipController::get_quiz_history),
+        )
+        .layer(middleware::from_fn_with_state(state.clone(), check_jwt))
         .with_state(state.clone());
 
     // let quiz_question_router: Router = Router::new()
@@ -1,6 +1,12 @@
 use {
     crate::{
-        controllers::set_controller::SetController, middleware::jwt::check_jwt, server::AppState,
+        controllers::{
+            change_request_controller::ChangeRequestController,
+            comment_controller::CommentController, ownership_controller::OwnershipController,
+            set_controller::SetController,
+        },
+        middleware::jwt::check_jwt,
+        server::AppState,
     },
     axum::{
         middleware,
@@ -19,6 +25,22 @@ pub fn get_set_router(state: &AppState) -> Router {
                 .delete(SetController::delete),
         )
         .route("/{id}/share", post(SetController::share))
-        .layer(middleware::from_fn(check_jwt))
+        .route("/{id}/invite", post(SetController::invite))
+        .route(
+            "/{id}/tags",
+            get(SetController::get_tags).put(SetController::set_tags),
+        )
+        .route(
+            "/{id}/comments",
+            get(CommentController::get_set_comments).post(CommentController::create_set_comment),
+        )
+        .route(
+            "/{id}/change-requests",
+            get(ChangeRequestController::get_all_of_set),
+        )
+        .route("/{id}/transfer", post(OwnershipController::transfer_set))
+        .route("/{id}/transfers", get(OwnershipController::get_set_history))
+        .route("/accept-invite", post(SetController::accept_invite))
+        .layer(middleware::from_fn_with_state(state.clone(), check_jwt))
         .with_state(state.clone())
 }
@@ -0,0 +1,22 @@
+use {
+    crate::{
+        controllers::tag_controller::TagController, middleware::jwt::check_jwt, server::AppState,
+    },
+    axum::{
+        middleware,
+        routing::{get, patch},
+        Router,
+    },
+};
+
+pub fn get_tag_router(state: &AppState) -> Router {
+    Router::new()
+        .route("/", get(TagController::get_all).post(TagController::create))
+        .route(
+            "/{id}",
+            patch(TagController::update).delete(TagController::delete),
+        )
+        .route("/accuracy", get(TagController::accuracy))
+        .layer(middleware::from_fn_with_state(state.clone(), check_jwt))
+        .with_state(state.clone())
+}
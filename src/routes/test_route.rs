@@ -25,12 +25,21 @@ pub fn get_test_router(state: &AppState) -> Router {
             "/{test_id}/question/{quiz_question_id}",
             get(TestController::get_testing_question).post(TestController::resolve_test_question),
         )
+        .route(
+            "/{test_id}/question/{quiz_question_id}/flag",
+            patch(TestController::flag_question),
+        )
+        .route("/{test_id}/take-over", patch(TestController::take_over))
         .route("/{test_id}/submit", patch(TestController::submit))
-        .route("/{test_id/result}", get(TestController::result))
+        .route("/{test_id}/result", get(TestController::result))
         .route(
-            "/{test_id/review/{quiz_question_id}",
+            "/{test_id}/review/{quiz_question_id}",
             get(TestController::review_solution),
         )
-        .layer(middleware::from_fn(check_jwt))
+        .route(
+            "/{test_id}/adaptive-path",
+            get(TestController::get_adaptive_path),
+        )
+        .layer(middleware::from_fn_with_state(state.clone(), check_jwt))
         .with_state(state.clone())
 }
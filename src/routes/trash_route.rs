@@ -0,0 +1,24 @@
+use {
+    crate::{
+        controllers::trash_controller::TrashController, middleware::jwt::check_jwt,
+        server::AppState,
+    },
+    axum::{
+        middleware,
+        routing::{get, post},
+        Router,
+    },
+};
+
+pub fn get_trash_router(state: &AppState) -> Router {
+    Router::new()
+        .route("/", get(TrashController::get_all))
+        .route("/sets/{id}/restore", post(TrashController::restore_set))
+        .route(
+            "/questions/{id}/restore",
+            post(TrashController::restore_question),
+        )
+        .route("/quizzes/{id}/restore", post(TrashController::restore_quiz))
+        .layer(middleware::from_fn_with_state(state.clone(), check_jwt))
+        .with_state(state.clone())
+}
@@ -1,8 +1,13 @@
 use {
-    crate::{controllers::user_controller::*, middleware::jwt::check_jwt, server::AppState},
+    crate::{
+        controllers::{ownership_controller::OwnershipController, user_controller::*},
+        middleware::jwt::check_jwt,
+        server::AppState,
+    },
     axum::{
+        extract::DefaultBodyLimit,
         middleware,
-        routing::{get, patch},
+        routing::{delete, get, patch, post},
         Router,
     },
 };
@@ -15,8 +20,32 @@ pub fn get_user_router(state: &AppState) -> Router {
                 .delete(UserController::delete)
                 .patch(UserController::update_self),
         )
+        .route(
+            "/avatar",
+            post(UserController::upload_avatar).layer(DefaultBodyLimit::max(
+                state.media_service.max_upload_bytes(),
+            )),
+        )
         .route("/update-password", patch(UserController::update_password))
         .route("/update-role", patch(UserController::update_role))
-        .layer(middleware::from_fn(check_jwt))
+        .route("/reset-totp", patch(UserController::reset_totp))
+        .route("/totp/setup", post(UserController::setup_totp))
+        .route("/totp/activate", post(UserController::activate_totp))
+        .route("/totp/disable", post(UserController::disable_totp))
+        .route("/invite-staff", post(UserController::invite_staff))
+        .route(
+            "/accept-staff-invite",
+            post(UserController::accept_staff_invite),
+        )
+        .route(
+            "/tokens",
+            get(UserController::get_all_tokens).post(UserController::create_token),
+        )
+        .route("/tokens/{id}", delete(UserController::revoke_token))
+        .route(
+            "/{id}/transfer-content",
+            post(OwnershipController::transfer_all),
+        )
+        .layer(middleware::from_fn_with_state(state.clone(), check_jwt))
         .with_state(state.clone())
 }
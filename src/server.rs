@@ -1,16 +1,20 @@
 use {
     crate::{
-        config::Config,
+        config::{Config, DifficultyConfig, RateLimitConfig, TrashConfig},
         db::db_connection::Database,
         enums::error::{Error, Result},
         error,
+        mailer::init_mailer,
+        oidc::OidcClient,
+        rate_limit::{init_rate_limiter, RateLimiter},
         routes::setup_routing,
         services::{
             implements::{init_service_implements, ServiceImpls},
             traits::prelude::*,
         },
+        storage::init_blob_store,
     },
-    std::sync::Arc,
+    std::{net::SocketAddr, sync::Arc, time::Duration},
     tokio::net::TcpListener,
     tracing::info,
 };
@@ -23,6 +27,18 @@ pub struct AppState {
     pub quiz_service: Arc<dyn QuizService>,
     pub quiz_question_service: Arc<dyn QuizQuestionService>,
     pub test_service: Arc<dyn TestService>,
+    pub oidc_service: Arc<dyn OidcService>,
+    pub api_token_service: Arc<dyn ApiTokenService>,
+    pub audit_service: Arc<dyn AuditService>,
+    pub trash_service: Arc<dyn TrashService>,
+    pub media_service: Arc<dyn MediaService>,
+    pub tag_service: Arc<dyn TagService>,
+    pub difficulty_service: Arc<dyn DifficultyService>,
+    pub note_service: Arc<dyn NoteService>,
+    pub comment_service: Arc<dyn CommentService>,
+    pub change_request_service: Arc<dyn ChangeRequestService>,
+    pub ownership_service: Arc<dyn OwnershipService>,
+    pub rate_limiter: Arc<RateLimiter>,
 }
 
 impl AppState {
@@ -32,6 +48,11 @@ impl AppState {
             Error::Anyhow(e.into())
         })?);
 
+        let mailer = init_mailer(&cfg.mailer)?;
+        let rate_limiter = init_rate_limiter(&cfg.rate_limit, Arc::clone(&db));
+        let oidc_client = cfg.oidc.clone().map(OidcClient::try_new).transpose()?;
+        let blob_store = init_blob_store(&cfg.storage)?;
+
         let ServiceImpls {
             user_service,
             set_service,
@@ -39,7 +60,26 @@ impl AppState {
             quiz_service,
             quiz_question_service,
             test_service,
-        } = init_service_implements(db).await;
+            oidc_service,
+            api_token_service,
+            audit_service,
+            trash_service,
+            media_service,
+            tag_service,
+            difficulty_service,
+            note_service,
+            comment_service,
+            change_request_service,
+            ownership_service,
+        } = init_service_implements(
+            db,
+            mailer,
+            rate_limiter.clone(),
+            oidc_client,
+            blob_store,
+            &cfg,
+        )
+        .await;
 
         Ok(Self {
             user_service,
@@ -48,13 +88,86 @@ impl AppState {
             quiz_service,
             quiz_question_service,
             test_service,
+            oidc_service,
+            api_token_service,
+            audit_service,
+            trash_service,
+            media_service,
+            tag_service,
+            difficulty_service,
+            note_service,
+            comment_service,
+            change_request_service,
+            ownership_service,
+            rate_limiter,
         })
     }
 }
 
+// Định kỳ xoá hẳn nội dung nằm trong thùng rác quá hạn
+fn spawn_trash_purge(trash_service: Arc<dyn TrashService>, cfg: &TrashConfig) {
+    let period = Duration::from_secs(cfg.purge_interval_secs.max(1));
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(period);
+        loop {
+            interval.tick().await;
+            match trash_service.purge_expired().await {
+                Ok(report) => info!("Trash purge: {report:?}"),
+                Err(e) => error!("Trash purge failed: {}", e),
+            }
+        }
+    });
+}
+
+// Dọn bucket và lần login sai đã cũ để bảng không lớn mãi
+fn spawn_rate_limit_prune(rate_limiter: Arc<RateLimiter>, cfg: &RateLimitConfig) {
+    let period = Duration::from_secs(cfg.prune_interval_secs.max(1));
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(period);
+        loop {
+            interval.tick().await;
+            match rate_limiter.prune().await {
+                Ok(pruned) => info!("Rate limit prune: {pruned} keys"),
+                Err(e) => error!("Rate limit prune failed: {}", e),
+            }
+        }
+    });
+}
+
+// Định kỳ ước lượng lại độ khó câu hỏi từ các bài đã nộp
+fn spawn_difficulty_calibration(
+    difficulty_service: Arc<dyn DifficultyService>,
+    cfg: &DifficultyConfig,
+) {
+    let period = Duration::from_secs(cfg.calibration_interval_secs.max(1));
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(period);
+        loop {
+            interval.tick().await;
+            match difficulty_service.calibrate().await {
+                Ok(report) => info!("Difficulty calibration: {report:?}"),
+                Err(e) => error!("Difficulty calibration failed: {}", e),
+            }
+        }
+    });
+}
+
 pub async fn run_server(cfg: Config) -> Result<()> {
     let state = AppState::init(cfg.clone()).await?;
 
+    if cfg.trash.purge_enabled {
+        spawn_trash_purge(state.trash_service.clone(), &cfg.trash);
+    }
+    if cfg.difficulty.calibration_enabled {
+        spawn_difficulty_calibration(state.difficulty_service.clone(), &cfg.difficulty);
+    }
+    if cfg.rate_limit.enabled {
+        spawn_rate_limit_prune(state.rate_limiter.clone(), &cfg.rate_limit);
+    }
+
     let app = setup_routing(state);
     let listener = TcpListener::bind(format!("{}:{}", cfg.http.host, cfg.http.port))
         .await
@@ -64,7 +177,13 @@ pub async fn run_server(cfg: Config) -> Result<()> {
         })?;
 
     info!("Server running: {}:{}", cfg.http.host, cfg.http.port);
-    axum::serve(listener, app).await.map_err(|e| {
+    // cần địa chỉ client cho rate limit theo IP
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await
+    .map_err(|e| {
         error!("{}", e.to_string());
         Error::Anyhow(e.into())
     })?;
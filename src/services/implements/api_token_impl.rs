@@ -0,0 +1,126 @@
+use {
+    crate::{
+        enums::error::*,
+        models::api_token::{
+            ApiTokenModel, ApiTokenScope, CreateApiTokenRequest, CreatedApiTokenResponse,
+        },
+        repositories::api_token::ApiTokenRepository,
+        services::traits::api_token_trait::ApiTokenService,
+        utils::{helpers::sha256_hex, jwt::Claims},
+        warn,
+    },
+    async_trait::async_trait,
+    chrono::{Duration, Utc},
+    std::sync::Arc,
+    uuid::Uuid,
+};
+
+pub const API_TOKEN_PREFIX: &str = "fcp_";
+const DISPLAY_PREFIX_LEN: usize = 12;
+
+pub struct ApiTokenServiceImpl {
+    api_token_repository: Arc<ApiTokenRepository>,
+}
+
+impl ApiTokenServiceImpl {
+    pub fn new(api_token_repository: Arc<ApiTokenRepository>) -> Self {
+        Self {
+            api_token_repository,
+        }
+    }
+}
+
+// 244 bit ngẫu nhiên từ hai uuid v4 (mỗi uuid v4 có 122 bit ngẫu nhiên)
+fn generate_token() -> String {
+    format!(
+        "{API_TOKEN_PREFIX}{}{}",
+        Uuid::new_v4().simple(),
+        Uuid::new_v4().simple()
+    )
+}
+
+#[async_trait]
+impl ApiTokenService for ApiTokenServiceImpl {
+    async fn create(
+        &self,
+        caller_id: Uuid,
+        payload: CreateApiTokenRequest,
+    ) -> Result<CreatedApiTokenResponse> {
+        let CreateApiTokenRequest {
+            name,
+            mut scopes,
+            expires_in_days,
+        } = payload;
+
+        if scopes.is_empty() {
+            scopes.push(ApiTokenScope::Read);
+        }
+        scopes.sort();
+        scopes.dedup();
+
+        let token = generate_token();
+        let expires_at = expires_in_days
+            .filter(|days| *days > 0)
+            .map(|days| Utc::now().naive_utc() + Duration::days(days));
+
+        let api_token = self
+            .api_token_repository
+            .create_one(
+                caller_id,
+                name,
+                sha256_hex(&token),
+                token[..DISPLAY_PREFIX_LEN].to_string(),
+                serde_json::to_value(&scopes).map_err(|e| Error::Anyhow(e.into()))?,
+                expires_at,
+            )
+            .await?;
+
+        Ok(CreatedApiTokenResponse {
+            token,
+            api_token: api_token.into(),
+        })
+    }
+
+    async fn get_all_of_user(&self, caller_id: Uuid) -> Result<Vec<ApiTokenModel>> {
+        Ok(self
+            .api_token_repository
+            .get_all_of_user(caller_id)
+            .await?
+            .into_iter()
+            .map(ApiTokenModel::from)
+            .collect())
+    }
+
+    async fn revoke(&self, caller_id: Uuid, token_id: Uuid) -> Result<()> {
+        self.api_token_repository.revoke(caller_id, token_id).await
+    }
+
+    async fn authenticate(&self, token: String) -> Result<(Claims, Vec<ApiTokenScope>)> {
+        if !token.starts_with(API_TOKEN_PREFIX) {
+            return Err(Error::InvalidCredentials);
+        }
+
+        let (api_token, user) = self
+            .api_token_repository
+            .find_active_by_hash(sha256_hex(&token))
+            .await?
+            .ok_or(Error::InvalidCredentials)?;
+
+        if let Err(e) = self.api_token_repository.touch(api_token.id).await {
+            warn!("Update last_used_at of api token failed: {}", e);
+        }
+
+        let claims = Claims {
+            id: user.id,
+            role: user.role.to_string(),
+            jti: api_token.id,
+            exp: api_token
+                .expires_at
+                .map(|t| t.and_utc().timestamp() as usize)
+                .unwrap_or(usize::MAX),
+        };
+        let scopes = serde_json::from_value(api_token.scopes).unwrap_or_default();
+
+        Ok((claims, scopes))
+    }
+}
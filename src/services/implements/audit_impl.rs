@@ -0,0 +1,46 @@
+use {
+    crate::{
+        entities::audit_events,
+        enums::{error::*, generic::PaginatedResponse},
+        error,
+        models::audit::{AuditEvent, QueryAuditParams},
+        repositories::audit::AuditRepository,
+        services::traits::audit_trait::AuditService,
+    },
+    async_trait::async_trait,
+    std::sync::Arc,
+};
+
+pub struct AuditServiceImpl {
+    audit_repository: Arc<AuditRepository>,
+}
+
+impl AuditServiceImpl {
+    pub fn new(audit_repository: Arc<AuditRepository>) -> Self {
+        Self { audit_repository }
+    }
+}
+
+#[async_trait]
+impl AuditService for AuditServiceImpl {
+    async fn record(&self, event: AuditEvent) {
+        let summary = format!(
+            "{} {} {:?} by {:?}",
+            event.action.as_str(),
+            event.resource_type.as_str(),
+            event.resource_id,
+            event.actor_id
+        );
+
+        if let Err(e) = self.audit_repository.create_one(event).await {
+            error!("Write audit event ({summary}) failed: {}", e);
+        }
+    }
+
+    async fn get_all(
+        &self,
+        params: QueryAuditParams,
+    ) -> Result<PaginatedResponse<audit_events::Model>> {
+        self.audit_repository.get_all(params).await
+    }
+}
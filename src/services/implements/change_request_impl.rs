@@ -0,0 +1,355 @@
+use {
+    crate::{
+        entities::{change_requests, questions, sea_orm_active_enums::ChangeRequestStatusEnum},
+        enums::{error::*, generic::PaginatedResponse},
+        models::{
+            audit::{AuditAction, AuditEvent, AuditResource},
+            change_request::{
+                ChangeRequestResponse, CreateChangeRequest, QueryChangeRequestParams,
+            },
+            qna::{ContentFormatChange, QuestionDiff, TextChange, UpdateQuestionRequest},
+            set::SharedPermission,
+        },
+        repositories::change_request::ChangeRequestRepository,
+        services::traits::{
+            audit_trait::AuditService, change_request_trait::ChangeRequestService,
+            qna_trait::QnAService, set_trait::SetService,
+        },
+        utils::{
+            diff::{diff_answers, parse_answers},
+            rich_text::sanitize,
+            validator::validate_answers_of_type,
+        },
+    },
+    async_trait::async_trait,
+    sea_orm::{ColumnTrait, Condition, Set},
+    std::sync::Arc,
+    uuid::Uuid,
+};
+
+pub struct ChangeRequestServiceImpl {
+    change_request_repository: Arc<ChangeRequestRepository>,
+    set_service: Arc<dyn SetService>,
+    qna_service: Arc<dyn QnAService>,
+    audit_service: Arc<dyn AuditService>,
+}
+
+impl ChangeRequestServiceImpl {
+    pub fn new(
+        change_request_repository: Arc<ChangeRequestRepository>,
+        set_service: Arc<dyn SetService>,
+        qna_service: Arc<dyn QnAService>,
+        audit_service: Arc<dyn AuditService>,
+    ) -> Self {
+        Self {
+            change_request_repository,
+            set_service,
+            qna_service,
+            audit_service,
+        }
+    }
+
+    // Người sửa được câu hỏi: creator của câu hỏi, creator của set hoặc được
+    // share với quyền Edit
+    async fn check_can_review(&self, caller_id: Uuid, question: &questions::Model) -> Result<()> {
+        let can_review = question.creator_id == caller_id
+            || self
+                .set_service
+                .is_creator(question.set_id, caller_id)
+                .await?
+            || self
+                .set_service
+                .check_share_permission(question.set_id, caller_id, SharedPermission::Edit)
+                .await?;
+        if !can_review {
+            return Err(Error::PermissionDenied);
+        }
+
+        Ok(())
+    }
+
+    async fn get_pending(&self, id: Uuid) -> Result<change_requests::Model> {
+        let change_request = self.change_request_repository.get_by_id(id).await?;
+        if change_request.status != ChangeRequestStatusEnum::Pending {
+            return Err(Error::ChangeRequestClosed);
+        }
+
+        Ok(change_request)
+    }
+}
+
+fn diff_of(change_request: &change_requests::Model) -> QuestionDiff {
+    QuestionDiff {
+        content: change_request
+            .content
+            .as_ref()
+            .filter(|content| **content != change_request.base_content)
+            .map(|content| {
+                TextChange {
+                    before: change_request.base_content.clone(),
+                    after: content.clone(),
+                }
+            }),
+        content_format: change_request
+            .content_format
+            .filter(|format| *format != change_request.base_content_format)
+            .map(|format| {
+                ContentFormatChange {
+                    before: change_request.base_content_format,
+                    after: format,
+                }
+            }),
+        answers: change_request.answers.as_ref().map(|answers| {
+            diff_answers(
+                &parse_answers(&change_request.base_answers),
+                &parse_answers(answers),
+            )
+        }),
+    }
+}
+
+fn invalid(field: &str, code: &str, message: &str) -> Error {
+    Error::Validation(vec![FieldError::new(field, code, message)])
+}
+
+#[async_trait]
+impl ChangeRequestService for ChangeRequestServiceImpl {
+    // Chỉ creator của set hoặc người được share set mới đề xuất được
+    async fn create(
+        &self,
+        caller_id: Uuid,
+        question_id: Uuid,
+        payload: CreateChangeRequest,
+    ) -> Result<change_requests::Model> {
+        let question = self.qna_service.get_by_id(caller_id, question_id).await?;
+        let is_collaborator = self
+            .set_service
+            .is_creator(question.set_id, caller_id)
+            .await?
+            || self
+                .set_service
+                .check_share_permission(question.set_id, caller_id, SharedPermission::View)
+                .await?;
+        if !is_collaborator {
+            return Err(Error::PermissionDenied);
+        }
+
+        let base_answers = parse_answers(&question.answers);
+
+        let content = match (&payload.content, payload.content_format) {
+            (None, None) => None,
+            (content, format) => {
+                let format = format.unwrap_or(question.content_format);
+                let content = content.as_deref().unwrap_or(&question.content);
+                Some(sanitize(format, "content", content)?)
+            }
+        };
+
+        let answers = match payload.answers {
+            Some(answers) => {
+                if !validate_answers_of_type(&question.r#type, &answers) {
+                    return Err(invalid(
+                        "answers",
+                        "invalid_answers",
+                        "Answers do not match the question type",
+                    ));
+                }
+                // người đề xuất không gắn media mới, chỉ giữ lại media đã có
+                let attached = base_answers
+                    .iter()
+                    .filter_map(|a| a.media_id)
+                    .chain(question.media_id)
+                    .collect::<Vec<_>>();
+                if answers
+                    .iter()
+                    .filter_map(|a| a.media_id)
+                    .any(|id| !attached.contains(&id))
+                {
+                    return Err(invalid(
+                        "answers",
+                        "media_not_attached",
+                        "Only media already attached to the question can be used",
+                    ));
+                }
+                Some(answers)
+            }
+            None => None,
+        };
+
+        let unchanged = content.as_ref().is_none_or(|c| *c == question.content)
+            && payload
+                .content_format
+                .is_none_or(|f| f == question.content_format)
+            && answers.as_ref().is_none_or(|a| *a == base_answers);
+        if unchanged {
+            return Err(invalid(
+                "body",
+                "no_changes",
+                "The change request does not change the question",
+            ));
+        }
+
+        let answers = answers
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| Error::Anyhow(e.into()))?;
+
+        self.change_request_repository
+            .create_one(change_requests::ActiveModel {
+                question_id: Set(question.id),
+                set_id: Set(question.set_id),
+                proposer_id: Set(caller_id),
+                message: Set(payload.message),
+                content: Set(content),
+                content_format: Set(payload.content_format),
+                answers: Set(answers),
+                base_content: Set(question.content),
+                base_content_format: Set(question.content_format),
+                base_answers: Set(question.answers),
+                ..Default::default()
+            })
+            .await
+    }
+
+    async fn get_all_of_question(
+        &self,
+        caller_id: Uuid,
+        question_id: Uuid,
+        params: QueryChangeRequestParams,
+    ) -> Result<PaginatedResponse<change_requests::Model>> {
+        self.qna_service.get_by_id(caller_id, question_id).await?;
+
+        self.change_request_repository
+            .get_all(
+                Condition::all().add(change_requests::Column::QuestionId.eq(question_id)),
+                params.status,
+                params.page.unwrap_or(1),
+                params.page_size.unwrap_or(10).clamp(1, 100),
+            )
+            .await
+    }
+
+    async fn get_all_of_set(
+        &self,
+        caller_id: Uuid,
+        set_id: Uuid,
+        params: QueryChangeRequestParams,
+    ) -> Result<PaginatedResponse<change_requests::Model>> {
+        self.set_service.get_by_id(caller_id, set_id).await?;
+
+        self.change_request_repository
+            .get_all(
+                Condition::all().add(change_requests::Column::SetId.eq(set_id)),
+                params.status,
+                params.page.unwrap_or(1),
+                params.page_size.unwrap_or(10).clamp(1, 100),
+            )
+            .await
+    }
+
+    async fn get_by_id(&self, caller_id: Uuid, id: Uuid) -> Result<ChangeRequestResponse> {
+        let change_request = self.change_request_repository.get_by_id(id).await?;
+        let question = self
+            .qna_service
+            .get_by_id(caller_id, change_request.question_id)
+            .await?;
+
+        let is_outdated = change_request.status == ChangeRequestStatusEnum::Pending
+            && (question.content != change_request.base_content
+                || question.content_format != change_request.base_content_format
+                || question.answers != change_request.base_answers);
+
+        Ok(ChangeRequestResponse {
+            diff: diff_of(&change_request),
+            is_outdated,
+            change_request,
+        })
+    }
+
+    async fn accept(
+        &self,
+        caller_id: Uuid,
+        id: Uuid,
+        review_note: Option<String>,
+    ) -> Result<change_requests::Model> {
+        let change_request = self.get_pending(id).await?;
+        let question = self
+            .qna_service
+            .get_by_id(caller_id, change_request.question_id)
+            .await?;
+        self.check_can_review(caller_id, &question).await?;
+
+        let answers = change_request.answers.as_ref().map(parse_answers);
+        let (before, payload) = self
+            .qna_service
+            .prepare_update(
+                caller_id,
+                question.id,
+                UpdateQuestionRequest {
+                    content: change_request.content,
+                    content_format: change_request.content_format,
+                    answers,
+                    media_id: None,
+                },
+            )
+            .await?;
+
+        // đóng đề xuất và sửa question cùng một transaction để accept/withdraw
+        // đồng thời không cùng áp dụng
+        let (accepted, after) = self
+            .change_request_repository
+            .accept(id, question.id, caller_id, review_note, payload)
+            .await?;
+
+        if let Some(after) = &after {
+            self.audit_service
+                .record(
+                    AuditEvent::new(
+                        Some(caller_id),
+                        AuditAction::Update,
+                        AuditResource::Question,
+                        Some(question.id),
+                    )
+                    .before(&before)
+                    .after(after),
+                )
+                .await;
+        }
+
+        Ok(accepted)
+    }
+
+    async fn reject(
+        &self,
+        caller_id: Uuid,
+        id: Uuid,
+        review_note: Option<String>,
+    ) -> Result<change_requests::Model> {
+        let change_request = self.get_pending(id).await?;
+        let question = self
+            .qna_service
+            .get_by_id(caller_id, change_request.question_id)
+            .await?;
+        self.check_can_review(caller_id, &question).await?;
+
+        self.change_request_repository
+            .close(
+                id,
+                ChangeRequestStatusEnum::Rejected,
+                Some(caller_id),
+                review_note,
+            )
+            .await
+    }
+
+    async fn withdraw(&self, caller_id: Uuid, id: Uuid) -> Result<change_requests::Model> {
+        let change_request = self.get_pending(id).await?;
+        if change_request.proposer_id != caller_id {
+            return Err(Error::PermissionDenied);
+        }
+
+        self.change_request_repository
+            .close(id, ChangeRequestStatusEnum::Withdrawn, None, None)
+            .await
+    }
+}
@@ -0,0 +1,398 @@
+use {
+    crate::{
+        entities::comments,
+        enums::{error::*, generic::PaginatedResponse},
+        models::{
+            comment::{
+                CommentResponse, CommentTarget, CommentThread, CreateCommentRequest,
+                QueryCommentParams, UpdateCommentRequest,
+            },
+            set::SharedPermission,
+        },
+        repositories::comment::CommentRepository,
+        services::traits::{
+            comment_trait::CommentService, qna_trait::QnAService, set_trait::SetService,
+        },
+    },
+    async_trait::async_trait,
+    sea_orm::Set,
+    std::{collections::HashSet, sync::Arc},
+    uuid::Uuid,
+};
+
+pub struct CommentServiceImpl {
+    comment_repository: Arc<CommentRepository>,
+    set_service: Arc<dyn SetService>,
+    qna_service: Arc<dyn QnAService>,
+}
+
+impl CommentServiceImpl {
+    pub fn new(
+        comment_repository: Arc<CommentRepository>,
+        set_service: Arc<dyn SetService>,
+        qna_service: Arc<dyn QnAService>,
+    ) -> Self {
+        Self {
+            comment_repository,
+            set_service,
+            qna_service,
+        }
+    }
+
+    // Set chứa nơi bình luận, đồng thời kiểm tra caller xem được
+    async fn set_of(&self, caller_id: Uuid, target: CommentTarget) -> Result<Uuid> {
+        match target {
+            CommentTarget::Set(set_id) => {
+                Ok(self.set_service.get_by_id(caller_id, set_id).await?.id)
+            }
+            CommentTarget::Question(question_id) => {
+                Ok(self
+                    .qna_service
+                    .get_by_id(caller_id, question_id)
+                    .await?
+                    .set_id)
+            }
+        }
+    }
+
+    async fn has_permission(
+        &self,
+        caller_id: Uuid,
+        set_id: Uuid,
+        permission: SharedPermission,
+    ) -> Result<bool> {
+        Ok(self.set_service.is_creator(set_id, caller_id).await?
+            || self
+                .set_service
+                .check_share_permission(set_id, caller_id, permission)
+                .await?)
+    }
+
+    async fn check_can_comment(&self, caller_id: Uuid, set_id: Uuid) -> Result<()> {
+        if !self
+            .has_permission(caller_id, set_id, SharedPermission::Comment)
+            .await?
+        {
+            return Err(Error::PermissionDenied);
+        }
+
+        Ok(())
+    }
+
+    // Chỉ nhắc tới được người xem được set, bỏ trùng
+    async fn check_mentions(&self, set_id: Uuid, mention_ids: Vec<Uuid>) -> Result<Vec<Uuid>> {
+        let mut seen = HashSet::new();
+        let mention_ids = mention_ids
+            .into_iter()
+            .filter(|id| seen.insert(*id))
+            .collect::<Vec<_>>();
+
+        for user_id in mention_ids.iter() {
+            if self.set_service.get_by_id(*user_id, set_id).await.is_err() {
+                return Err(Error::Validation(vec![FieldError::new(
+                    "mention_ids",
+                    "mention_without_access",
+                    "Mentioned users must be able to view the set",
+                )]));
+            }
+        }
+
+        Ok(mention_ids)
+    }
+
+    async fn get_own(&self, caller_id: Uuid, comment_id: Uuid) -> Result<comments::Model> {
+        let comment = self.comment_repository.get_by_id(comment_id).await?;
+        check_author(&comment, caller_id)?;
+
+        Ok(comment)
+    }
+}
+
+// Chỉ người viết mới sửa, xoá được bình luận
+fn check_author(comment: &comments::Model, caller_id: Uuid) -> Result<()> {
+    if comment.author_id != caller_id {
+        return Err(Error::PermissionDenied);
+    }
+
+    Ok(())
+}
+
+fn page_of(params: &QueryCommentParams) -> (u64, u64) {
+    (
+        params.page.unwrap_or(1).max(1),
+        params.page_size.unwrap_or(10).clamp(1, 100),
+    )
+}
+
+// Trả lời luôn gắn vào bình luận gốc nên thread chỉ có một cấp
+fn root_of(
+    parent: &comments::Model,
+    set_id: Uuid,
+    question_id: Option<Uuid>,
+    is_suggestion: bool,
+) -> Result<Uuid> {
+    if parent.set_id != set_id || parent.question_id != question_id {
+        return Err(Error::Validation(vec![FieldError::new(
+            "parent_id",
+            "parent_not_in_thread",
+            "The parent comment belongs to another set or question",
+        )]));
+    }
+    if is_suggestion {
+        return Err(Error::Validation(vec![FieldError::new(
+            "is_suggestion",
+            "suggestion_must_be_root",
+            "A reply cannot be a suggestion",
+        )]));
+    }
+
+    Ok(parent.parent_id.unwrap_or(parent.id))
+}
+
+// Người góp ý đóng được góp ý của mình khi còn quyền Comment, người khác cần
+// quyền Edit
+fn permission_to_resolve(comment: &comments::Model, caller_id: Uuid) -> Result<SharedPermission> {
+    if !comment.is_suggestion {
+        return Err(Error::Validation(vec![FieldError::new(
+            "is_resolved",
+            "not_a_suggestion",
+            "Only suggestions can be resolved",
+        )]));
+    }
+
+    Ok(if comment.author_id == caller_id {
+        SharedPermission::Comment
+    } else {
+        SharedPermission::Edit
+    })
+}
+
+#[async_trait]
+impl CommentService for CommentServiceImpl {
+    async fn get_threads(
+        &self,
+        caller_id: Uuid,
+        target: CommentTarget,
+        params: QueryCommentParams,
+    ) -> Result<PaginatedResponse<CommentThread>> {
+        self.set_of(caller_id, target).await?;
+
+        let (page, page_size) = page_of(&params);
+        self.comment_repository
+            .get_threads(target, page, page_size)
+            .await
+    }
+
+    async fn create(
+        &self,
+        caller_id: Uuid,
+        target: CommentTarget,
+        payload: CreateCommentRequest,
+    ) -> Result<CommentResponse> {
+        let set_id = self.set_of(caller_id, target).await?;
+        self.check_can_comment(caller_id, set_id).await?;
+
+        let question_id = match target {
+            CommentTarget::Set(_) => None,
+            CommentTarget::Question(question_id) => Some(question_id),
+        };
+
+        let parent_id = match payload.parent_id {
+            Some(parent_id) => {
+                let parent = self.comment_repository.get_by_id(parent_id).await?;
+                Some(root_of(
+                    &parent,
+                    set_id,
+                    question_id,
+                    payload.is_suggestion,
+                )?)
+            }
+            None => None,
+        };
+
+        let mention_ids = self.check_mentions(set_id, payload.mention_ids).await?;
+
+        self.comment_repository
+            .create_one(
+                comments::ActiveModel {
+                    set_id: Set(set_id),
+                    question_id: Set(question_id),
+                    parent_id: Set(parent_id),
+                    author_id: Set(caller_id),
+                    content: Set(payload.content),
+                    is_suggestion: Set(payload.is_suggestion),
+                    ..Default::default()
+                },
+                mention_ids,
+            )
+            .await
+    }
+
+    async fn update(
+        &self,
+        caller_id: Uuid,
+        comment_id: Uuid,
+        payload: UpdateCommentRequest,
+    ) -> Result<CommentResponse> {
+        let comment = self.get_own(caller_id, comment_id).await?;
+        // bị bỏ share thì không sửa được nữa
+        self.check_can_comment(caller_id, comment.set_id).await?;
+
+        let mention_ids = match payload.mention_ids {
+            Some(mention_ids) => Some(self.check_mentions(comment.set_id, mention_ids).await?),
+            None => None,
+        };
+
+        self.comment_repository
+            .update_one(comment_id, payload.content, mention_ids)
+            .await
+    }
+
+    async fn delete(&self, caller_id: Uuid, comment_id: Uuid) -> Result<()> {
+        self.get_own(caller_id, comment_id).await?;
+
+        self.comment_repository.delete_one(comment_id).await
+    }
+
+    async fn resolve(
+        &self,
+        caller_id: Uuid,
+        comment_id: Uuid,
+        is_resolved: bool,
+    ) -> Result<CommentResponse> {
+        let comment = self.comment_repository.get_by_id(comment_id).await?;
+        let permission = permission_to_resolve(&comment, caller_id)?;
+        let can_resolve = self
+            .has_permission(caller_id, comment.set_id, permission)
+            .await?;
+        if !can_resolve {
+            return Err(Error::PermissionDenied);
+        }
+
+        self.comment_repository
+            .set_resolved(comment_id, is_resolved.then_some(caller_id))
+            .await
+    }
+
+    async fn get_mentions(
+        &self,
+        caller_id: Uuid,
+        params: QueryCommentParams,
+    ) -> Result<PaginatedResponse<CommentResponse>> {
+        let (page, page_size) = page_of(&params);
+        self.comment_repository
+            .get_mentions(caller_id, page, page_size)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, chrono::Utc};
+
+    fn comment(author_id: Uuid, set_id: Uuid, question_id: Option<Uuid>) -> comments::Model {
+        let now = Utc::now().naive_utc();
+        comments::Model {
+            id: Uuid::new_v4(),
+            set_id,
+            question_id,
+            parent_id: None,
+            author_id,
+            content: "Đáp án câu này chưa đúng".to_owned(),
+            is_suggestion: false,
+            resolved_at: None,
+            resolved_by: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    fn code_of(e: Error) -> String {
+        match e {
+            Error::Validation(errors) => errors[0].code.clone(),
+            e => panic!("expected a validation error, got {e:?}"),
+        }
+    }
+
+    #[test]
+    fn reply_to_a_reply_joins_the_root_thread() {
+        let (set_id, question_id) = (Uuid::new_v4(), Some(Uuid::new_v4()));
+        let root = comment(Uuid::new_v4(), set_id, question_id);
+        let mut reply = comment(Uuid::new_v4(), set_id, question_id);
+        reply.parent_id = Some(root.id);
+
+        assert_eq!(root_of(&root, set_id, question_id, false).unwrap(), root.id);
+        assert_eq!(
+            root_of(&reply, set_id, question_id, false).unwrap(),
+            root.id
+        );
+    }
+
+    #[test]
+    fn reply_must_stay_in_the_parent_thread() {
+        let set_id = Uuid::new_v4();
+        let parent = comment(Uuid::new_v4(), set_id, Some(Uuid::new_v4()));
+
+        let on_set = root_of(&parent, set_id, None, false).unwrap_err();
+        let on_other_set = root_of(&parent, Uuid::new_v4(), parent.question_id, false).unwrap_err();
+
+        assert_eq!(code_of(on_set), "parent_not_in_thread");
+        assert_eq!(code_of(on_other_set), "parent_not_in_thread");
+    }
+
+    #[test]
+    fn reply_cannot_be_a_suggestion() {
+        let parent = comment(Uuid::new_v4(), Uuid::new_v4(), None);
+
+        let e = root_of(&parent, parent.set_id, None, true).unwrap_err();
+
+        assert_eq!(code_of(e), "suggestion_must_be_root");
+    }
+
+    #[test]
+    fn only_author_edits_or_deletes() {
+        let author_id = Uuid::new_v4();
+        let comment = comment(author_id, Uuid::new_v4(), None);
+
+        assert!(check_author(&comment, author_id).is_ok());
+        assert!(matches!(
+            check_author(&comment, Uuid::new_v4()),
+            Err(Error::PermissionDenied)
+        ));
+    }
+
+    #[test]
+    fn only_suggestions_can_be_resolved() {
+        let author_id = Uuid::new_v4();
+        let comment = comment(author_id, Uuid::new_v4(), None);
+
+        let e = permission_to_resolve(&comment, author_id).unwrap_err();
+
+        assert_eq!(code_of(e), "not_a_suggestion");
+    }
+
+    #[test]
+    fn author_resolves_with_comment_others_need_edit() {
+        let author_id = Uuid::new_v4();
+        let mut suggestion = comment(author_id, Uuid::new_v4(), None);
+        suggestion.is_suggestion = true;
+
+        assert_eq!(
+            permission_to_resolve(&suggestion, author_id).unwrap(),
+            SharedPermission::Comment
+        );
+        assert_eq!(
+            permission_to_resolve(&suggestion, Uuid::new_v4()).unwrap(),
+            SharedPermission::Edit
+        );
+    }
+
+    #[test]
+    fn page_size_is_clamped() {
+        let page = |page, page_size| page_of(&QueryCommentParams { page, page_size });
+
+        assert_eq!(page(None, None), (1, 10));
+        assert_eq!(page(Some(0), Some(0)), (1, 1));
+        assert_eq!(page(Some(3), Some(1000)), (3, 100));
+    }
+}
@@ -0,0 +1,65 @@
+use {
+    crate::{
+        enums::error::*, models::difficulty::CalibrationReport,
+        repositories::difficulty::DifficultyRepository,
+        services::traits::difficulty_trait::DifficultyService, utils::difficulty::calibrate,
+    },
+    async_trait::async_trait,
+    std::sync::Arc,
+};
+
+pub struct DifficultyServiceImpl {
+    difficulty_repository: Arc<DifficultyRepository>,
+    iterations: usize,
+}
+
+impl DifficultyServiceImpl {
+    pub fn new(difficulty_repository: Arc<DifficultyRepository>, iterations: usize) -> Self {
+        Self {
+            difficulty_repository,
+            iterations,
+        }
+    }
+}
+
+#[async_trait]
+impl DifficultyService for DifficultyServiceImpl {
+    async fn calibrate(&self) -> Result<CalibrationReport> {
+        let attempts = self.difficulty_repository.get_graded_attempts().await?;
+
+        let by_quiz_question: Vec<_> = attempts
+            .iter()
+            .map(|(user_id, quiz_question_id, _, is_correct)| {
+                (*user_id, *quiz_question_id, *is_correct)
+            })
+            .collect();
+        // câu hỏi trong set gom lượt làm của mọi quiz question tạo từ nó
+        let by_question: Vec<_> = attempts
+            .iter()
+            .filter_map(|(user_id, _, sample_id, is_correct)| {
+                sample_id.map(|sample_id| (*user_id, sample_id, *is_correct))
+            })
+            .collect();
+
+        // tính toán thuần CPU, không giữ worker của runtime
+        let iterations = self.iterations;
+        let (questions, quiz_questions) = tokio::task::spawn_blocking(move || {
+            (
+                calibrate(&by_question, iterations),
+                calibrate(&by_quiz_question, iterations),
+            )
+        })
+        .await
+        .map_err(|e| Error::Anyhow(e.into()))?;
+
+        self.difficulty_repository
+            .save_estimates(&questions, &quiz_questions)
+            .await?;
+
+        Ok(CalibrationReport {
+            attempts: attempts.len() as u64,
+            questions: questions.len() as u64,
+            quiz_questions: quiz_questions.len() as u64,
+        })
+    }
+}
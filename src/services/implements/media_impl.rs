@@ -0,0 +1,192 @@
+use {
+    crate::{
+        config::StorageConfig,
+        entities::media,
+        enums::error::*,
+        models::{
+            media::{MediaContent, MediaKind, MediaResponse, SignedUrlParams},
+            user::UserModel,
+        },
+        repositories::media::MediaRepository,
+        services::traits::{
+            media_trait::MediaService, prelude::SetService, user_trait::UserService,
+        },
+        storage::{sniff_content_type, BlobStore},
+        utils::media_url::{signed_url, verify},
+    },
+    async_trait::async_trait,
+    std::sync::Arc,
+    uuid::Uuid,
+};
+
+pub struct MediaServiceImpl {
+    media_repository: Arc<MediaRepository>,
+    blob_store: Arc<dyn BlobStore>,
+    set_service: Arc<dyn SetService>,
+    user_service: Arc<dyn UserService>,
+    cfg: StorageConfig,
+}
+
+impl MediaServiceImpl {
+    pub fn new(
+        media_repository: Arc<MediaRepository>,
+        blob_store: Arc<dyn BlobStore>,
+        set_service: Arc<dyn SetService>,
+        user_service: Arc<dyn UserService>,
+        cfg: StorageConfig,
+    ) -> Self {
+        Self {
+            media_repository,
+            blob_store,
+            set_service,
+            user_service,
+            cfg,
+        }
+    }
+
+    fn to_response(&self, media: media::Model) -> Result<MediaResponse> {
+        let (url, expires) = signed_url(media.id)?;
+
+        Ok(MediaResponse {
+            url,
+            id: media.id,
+            kind: media.kind,
+            content_type: media.content_type,
+            size: media.size,
+            expires,
+        })
+    }
+
+    async fn store(
+        &self,
+        caller_id: Uuid,
+        data: Vec<u8>,
+        allowed: &[MediaKind],
+    ) -> Result<media::Model> {
+        let content_type = sniff_content_type(&data).ok_or(Error::FileTypeInvalid)?;
+        let kind = MediaKind::from_content_type(content_type)
+            .filter(|kind| allowed.contains(kind))
+            .ok_or(Error::FileTypeInvalid)?;
+
+        let limit = match kind {
+            MediaKind::Image => self.cfg.max_image_bytes,
+            MediaKind::Audio => self.cfg.max_audio_bytes,
+        };
+        if data.len() > limit {
+            return Err(Error::FileTooLarge(limit));
+        }
+
+        let id = Uuid::new_v4();
+        let storage_key = format!("{}/{id}", kind.as_str());
+        let size = data.len() as i64;
+        self.blob_store
+            .put(&storage_key, content_type, data)
+            .await?;
+
+        match self
+            .media_repository
+            .create_one(
+                id,
+                caller_id,
+                kind.as_str(),
+                content_type,
+                size,
+                storage_key.clone(),
+            )
+            .await
+        {
+            Ok(media) => Ok(media),
+            Err(e) => {
+                // không để lại file mồ côi
+                let _ = self.blob_store.delete(&storage_key).await;
+                Err(e)
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl MediaService for MediaServiceImpl {
+    async fn upload(&self, caller_id: Uuid, data: Vec<u8>) -> Result<MediaResponse> {
+        let media = self
+            .store(caller_id, data, &[MediaKind::Image, MediaKind::Audio])
+            .await?;
+
+        self.to_response(media)
+    }
+
+    async fn get(&self, caller_id: Uuid, media_id: Uuid) -> Result<MediaResponse> {
+        let media = self.media_repository.get_by_id(media_id).await?;
+
+        if media.owner_id != caller_id {
+            let mut can_view = false;
+            for set_id in self.media_repository.get_set_ids_using(media_id).await? {
+                if self.set_service.get_by_id(caller_id, set_id).await.is_ok() {
+                    can_view = true;
+                    break;
+                }
+            }
+
+            if !can_view {
+                return Err(Error::AccessDenied);
+            }
+        }
+
+        self.to_response(media)
+    }
+
+    async fn download(&self, media_id: Uuid, params: SignedUrlParams) -> Result<MediaContent> {
+        if !verify(media_id, &params)? {
+            return Err(Error::AccessDenied);
+        }
+
+        let media = self.media_repository.get_by_id(media_id).await?;
+        let data = self.blob_store.get(&media.storage_key).await?;
+
+        Ok(MediaContent {
+            content_type: media.content_type,
+            data,
+        })
+    }
+
+    async fn delete(&self, caller_id: Uuid, media_id: Uuid) -> Result<()> {
+        let media = self.media_repository.get_by_id(media_id).await?;
+        if media.owner_id != caller_id {
+            return Err(Error::PermissionDenied);
+        }
+
+        // câu hỏi đang dùng media sẽ tự bỏ liên kết (ON DELETE SET NULL)
+        self.media_repository.delete_one(media_id).await?;
+        self.blob_store.delete(&media.storage_key).await
+    }
+
+    // Chỉ lưu id của media, link được ký lại mỗi lần đọc user
+    async fn upload_avatar(&self, caller_id: Uuid, data: Vec<u8>) -> Result<Option<UserModel>> {
+        let media = self.store(caller_id, data, &[MediaKind::Image]).await?;
+
+        self.user_service
+            .set_avatar_media(caller_id, media.id)
+            .await
+            .map(Some)
+    }
+
+    async fn check_owned(&self, caller_id: Uuid, media_ids: Vec<Uuid>) -> Result<()> {
+        if media_ids.is_empty() {
+            return Ok(());
+        }
+
+        let not_owned = self
+            .media_repository
+            .find_not_owned(media_ids, caller_id)
+            .await?;
+        if !not_owned.is_empty() {
+            return Err(Error::PermissionDenied);
+        }
+
+        Ok(())
+    }
+
+    fn max_upload_bytes(&self) -> usize {
+        self.cfg.max_image_bytes.max(self.cfg.max_audio_bytes)
+    }
+}
@@ -1,24 +1,56 @@
 use {
     super::{implements::prelude::*, traits::prelude::*},
     crate::{
+        config::Config,
         db::db_connection::Database,
+        mailer::Mailer,
+        oidc::OidcClient,
+        rate_limit::RateLimiter,
         repositories::{
-            question::QnARepository, quiz::QuizRepository, quiz_question::QuizQuestionRepository,
-            set::SetRepository, test::TestRepository, user::UserRepository,
+            api_token::ApiTokenRepository, audit::AuditRepository,
+            change_request::ChangeRequestRepository, comment::CommentRepository,
+            difficulty::DifficultyRepository, media::MediaRepository, note::NoteRepository,
+            oidc::OidcRepository, ownership::OwnershipRepository, question::QnARepository,
+            quiz::QuizRepository, quiz_question::QuizQuestionRepository, set::SetRepository,
+            tag::TagRepository, test::TestRepository, trash::TrashRepository, user::UserRepository,
+            user_token::UserTokenRepository,
         },
+        storage::BlobStore,
     },
+    api_token_impl::ApiTokenServiceImpl,
+    audit_impl::AuditServiceImpl,
+    change_request_impl::ChangeRequestServiceImpl,
+    comment_impl::CommentServiceImpl,
+    difficulty_impl::DifficultyServiceImpl,
+    media_impl::MediaServiceImpl,
+    note_impl::NoteServiceImpl,
+    oidc_impl::OidcServiceImpl,
+    ownership_impl::OwnershipServiceImpl,
     quiz_impl::QuizServiceImpl,
     quiz_question_impl::QuizQuestionServiceImpl,
     std::sync::Arc,
+    tag_impl::TagServiceImpl,
     test_impl::TestServiceImpl,
+    trash_impl::TrashServiceImpl,
 };
 
+pub mod api_token_impl;
+pub mod audit_impl;
+pub mod change_request_impl;
+pub mod comment_impl;
+pub mod difficulty_impl;
+pub mod media_impl;
+pub mod note_impl;
+pub mod oidc_impl;
+pub mod ownership_impl;
 pub mod prelude;
 pub mod qna_impl;
 pub mod quiz_impl;
 pub mod quiz_question_impl;
 pub mod set_impl;
+pub mod tag_impl;
 pub mod test_impl;
+pub mod trash_impl;
 pub mod user_impl;
 
 pub struct ServiceImpls {
@@ -28,31 +60,130 @@ pub struct ServiceImpls {
     pub quiz_service: Arc<dyn QuizService>,
     pub quiz_question_service: Arc<dyn QuizQuestionService>,
     pub test_service: Arc<dyn TestService>,
+    pub oidc_service: Arc<dyn OidcService>,
+    pub api_token_service: Arc<dyn ApiTokenService>,
+    pub audit_service: Arc<dyn AuditService>,
+    pub trash_service: Arc<dyn TrashService>,
+    pub media_service: Arc<dyn MediaService>,
+    pub tag_service: Arc<dyn TagService>,
+    pub difficulty_service: Arc<dyn DifficultyService>,
+    pub note_service: Arc<dyn NoteService>,
+    pub comment_service: Arc<dyn CommentService>,
+    pub change_request_service: Arc<dyn ChangeRequestService>,
+    pub ownership_service: Arc<dyn OwnershipService>,
 }
 
-pub async fn init_service_implements(db: Arc<Database>) -> ServiceImpls {
-    let user_service = Arc::new(UserServiceImpl::new(Arc::new(UserRepository::new(
+pub async fn init_service_implements(
+    db: Arc<Database>,
+    mailer: Arc<dyn Mailer>,
+    rate_limiter: Arc<RateLimiter>,
+    oidc_client: Option<OidcClient>,
+    blob_store: Arc<dyn BlobStore>,
+    cfg: &Config,
+) -> ServiceImpls {
+    let user_token_repository = Arc::new(UserTokenRepository::new(Arc::clone(&db)));
+    let audit_service = Arc::new(AuditServiceImpl::new(Arc::new(AuditRepository::new(
         Arc::clone(&db),
     ))));
-    let set_service = Arc::new(SetServiceImpl::new(Arc::new(SetRepository::new(
+    let tag_service = Arc::new(TagServiceImpl::new(Arc::new(TagRepository::new(
         Arc::clone(&db),
     ))));
+
+    let user_service = Arc::new(UserServiceImpl::new(
+        Arc::new(UserRepository::new(Arc::clone(&db))),
+        user_token_repository.clone(),
+        mailer.clone(),
+        rate_limiter,
+        audit_service.clone(),
+        cfg.mailer.app_url.clone(),
+        cfg.totp.clone(),
+        cfg.auth.password_login,
+    ));
+    let set_service = Arc::new(SetServiceImpl::new(
+        Arc::new(SetRepository::new(Arc::clone(&db))),
+        user_token_repository.clone(),
+        user_service.clone(),
+        tag_service.clone(),
+        mailer.clone(),
+        audit_service.clone(),
+        cfg.mailer.app_url.clone(),
+    ));
+    let media_service = Arc::new(MediaServiceImpl::new(
+        Arc::new(MediaRepository::new(Arc::clone(&db))),
+        blob_store,
+        set_service.clone(),
+        user_service.clone(),
+        cfg.storage.clone(),
+    ));
     let qna_service = Arc::new(QnAServiceImpl::new(
         Arc::new(QnARepository::new(Arc::clone(&db))),
         set_service.clone(),
+        media_service.clone(),
+        tag_service.clone(),
+        audit_service.clone(),
+    ));
+    let quiz_service = Arc::new(QuizServiceImpl::new(
+        Arc::new(QuizRepository::new(Arc::clone(&db))),
+        audit_service.clone(),
     ));
-    let quiz_service = Arc::new(QuizServiceImpl::new(Arc::new(QuizRepository::new(
-        Arc::clone(&db),
-    ))));
     let quiz_question_service = Arc::new(QuizQuestionServiceImpl::new(
         Arc::new(QuizQuestionRepository::new(Arc::clone(&db))),
         quiz_service.clone(),
         qna_service.clone(),
+        audit_service.clone(),
+        cfg.review.mastery_streak,
     ));
     let test_service = Arc::new(TestServiceImpl::new(
         Arc::new(TestRepository::new(Arc::clone(&db))),
         quiz_service.clone(),
         quiz_question_service.clone(),
+        cfg.adaptive.clone(),
+    ));
+
+    let note_service = Arc::new(NoteServiceImpl::new(
+        Arc::new(NoteRepository::new(Arc::clone(&db))),
+        qna_service.clone(),
+        quiz_question_service.clone(),
+    ));
+
+    let comment_service = Arc::new(CommentServiceImpl::new(
+        Arc::new(CommentRepository::new(Arc::clone(&db))),
+        set_service.clone(),
+        qna_service.clone(),
+    ));
+
+    let change_request_service = Arc::new(ChangeRequestServiceImpl::new(
+        Arc::new(ChangeRequestRepository::new(Arc::clone(&db))),
+        set_service.clone(),
+        qna_service.clone(),
+        audit_service.clone(),
+    ));
+
+    let ownership_service = Arc::new(OwnershipServiceImpl::new(
+        Arc::new(OwnershipRepository::new(Arc::clone(&db))),
+        user_service.clone(),
+        audit_service.clone(),
+    ));
+
+    let oidc_service = Arc::new(OidcServiceImpl::new(
+        oidc_client,
+        Arc::new(OidcRepository::new(Arc::clone(&db))),
+        user_service.clone(),
+    ));
+
+    let api_token_service = Arc::new(ApiTokenServiceImpl::new(Arc::new(ApiTokenRepository::new(
+        Arc::clone(&db),
+    ))));
+
+    let trash_service = Arc::new(TrashServiceImpl::new(
+        Arc::new(TrashRepository::new(Arc::clone(&db))),
+        audit_service.clone(),
+        cfg.trash.retention_days,
+    ));
+
+    let difficulty_service = Arc::new(DifficultyServiceImpl::new(
+        Arc::new(DifficultyRepository::new(Arc::clone(&db))),
+        cfg.difficulty.iterations,
     ));
 
     ServiceImpls {
@@ -62,5 +193,16 @@ pub async fn init_service_implements(db: Arc<Database>) -> ServiceImpls {
         quiz_service,
         quiz_question_service,
         test_service,
+        oidc_service,
+        api_token_service,
+        audit_service,
+        trash_service,
+        media_service,
+        tag_service,
+        difficulty_service,
+        note_service,
+        comment_service,
+        change_request_service,
+        ownership_service,
     }
 }
@@ -0,0 +1,87 @@
+use {
+    crate::{
+        entities::notes,
+        enums::{error::*, generic::PaginatedResponse},
+        models::note::{NoteTarget, QueryNoteParams},
+        repositories::note::NoteRepository,
+        services::traits::{
+            note_trait::NoteService, qna_trait::QnAService,
+            quiz_question_trait::QuizQuestionService,
+        },
+    },
+    async_trait::async_trait,
+    std::sync::Arc,
+    uuid::Uuid,
+};
+
+pub struct NoteServiceImpl {
+    note_repository: Arc<NoteRepository>,
+    qna_service: Arc<dyn QnAService>,
+    quiz_question_service: Arc<dyn QuizQuestionService>,
+}
+
+impl NoteServiceImpl {
+    pub fn new(
+        note_repository: Arc<NoteRepository>,
+        qna_service: Arc<dyn QnAService>,
+        quiz_question_service: Arc<dyn QuizQuestionService>,
+    ) -> Self {
+        Self {
+            note_repository,
+            qna_service,
+            quiz_question_service,
+        }
+    }
+}
+
+#[async_trait]
+impl NoteService for NoteServiceImpl {
+    async fn get_all(
+        &self,
+        caller_id: Uuid,
+        params: QueryNoteParams,
+    ) -> Result<PaginatedResponse<notes::Model>> {
+        self.note_repository.get_all(caller_id, params).await
+    }
+
+    async fn get_one(&self, caller_id: Uuid, target: NoteTarget) -> Result<notes::Model> {
+        self.note_repository.get_one(caller_id, target).await
+    }
+
+    async fn save_question_note(
+        &self,
+        caller_id: Uuid,
+        question_id: Uuid,
+        content: String,
+    ) -> Result<notes::Model> {
+        self.qna_service.get_by_id(caller_id, question_id).await?;
+
+        self.note_repository
+            .upsert(caller_id, NoteTarget::Question(question_id), content)
+            .await
+    }
+
+    async fn save_quiz_question_note(
+        &self,
+        caller_id: Uuid,
+        quiz_id: Uuid,
+        quiz_question_id: Uuid,
+        content: String,
+    ) -> Result<notes::Model> {
+        self.quiz_question_service
+            .get_by_id(caller_id, quiz_id, quiz_question_id)
+            .await?;
+
+        self.note_repository
+            .upsert(
+                caller_id,
+                NoteTarget::QuizQuestion(quiz_question_id),
+                content,
+            )
+            .await
+    }
+
+    async fn delete(&self, caller_id: Uuid, target: NoteTarget) -> Result<()> {
+        self.note_repository.delete_one(caller_id, target).await
+    }
+}
@@ -0,0 +1,126 @@
+use {
+    crate::{
+        enums::error::*,
+        models::user::{LoginResponse, RegisterUserRequest},
+        oidc::{ExternalIdentity, OidcClient},
+        repositories::oidc::OidcRepository,
+        services::traits::{oidc_trait::OidcService, user_trait::UserService},
+    },
+    async_trait::async_trait,
+    bcrypt::{hash, DEFAULT_COST},
+    chrono::{Duration, Utc},
+    std::sync::Arc,
+    uuid::Uuid,
+};
+
+const STATE_TTL_MINUTES: i64 = 10;
+
+pub struct OidcServiceImpl {
+    oidc_client: Option<OidcClient>,
+    oidc_repository: Arc<OidcRepository>,
+    user_service: Arc<dyn UserService>,
+}
+
+impl OidcServiceImpl {
+    pub fn new(
+        oidc_client: Option<OidcClient>,
+        oidc_repository: Arc<OidcRepository>,
+        user_service: Arc<dyn UserService>,
+    ) -> Self {
+        Self {
+            oidc_client,
+            oidc_repository,
+            user_service,
+        }
+    }
+
+    fn client(&self) -> Result<&OidcClient> {
+        self.oidc_client.as_ref().ok_or(Error::OidcNotConfigured)
+    }
+
+    // Tìm user của danh tính, liên kết theo email đã xác minh hoặc tạo user mới
+    async fn resolve_user(&self, identity: ExternalIdentity) -> Result<Uuid> {
+        if let Some(user_id) = self
+            .oidc_repository
+            .find_user_id(&identity.issuer, &identity.subject)
+            .await?
+        {
+            return Ok(user_id);
+        }
+
+        let email = identity
+            .email
+            .clone()
+            .ok_or(Error::OidcFailed("email claim is required".into()))?;
+
+        match self.user_service.get_my_info(email.clone()).await {
+            Ok(user) => {
+                // chỉ tin email khi IdP đã xác minh, tránh chiếm tài khoản có sẵn
+                if !identity.email_verified {
+                    return Err(Error::UserAlreadyExists);
+                }
+                self.oidc_repository
+                    .link_identity(user.id, &identity)
+                    .await?;
+
+                Ok(user.id)
+            }
+            Err(Error::RecordNotFound) => {
+                // user OIDC không có mật khẩu dùng được
+                let password = hash(Uuid::new_v4().to_string(), DEFAULT_COST)
+                    .map_err(|_| Error::HashingFailed)?;
+                let name = identity
+                    .name
+                    .clone()
+                    .unwrap_or_else(|| email.split('@').next().unwrap_or(&email).to_string());
+
+                let user = self
+                    .oidc_repository
+                    .create_user_with_identity(
+                        RegisterUserRequest {
+                            email,
+                            password,
+                            name,
+                            avatar_url: None,
+                        },
+                        identity.email_verified,
+                        &identity,
+                    )
+                    .await?;
+
+                Ok(user.id)
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[async_trait]
+impl OidcService for OidcServiceImpl {
+    async fn authorize(&self) -> Result<String> {
+        let request = self.client()?.authorize().await?;
+
+        self.oidc_repository
+            .create_state(
+                request.state,
+                request.nonce,
+                request.pkce_verifier,
+                (Utc::now() + Duration::minutes(STATE_TTL_MINUTES)).naive_utc(),
+            )
+            .await?;
+
+        Ok(request.url)
+    }
+
+    async fn callback(&self, code: String, state: String) -> Result<LoginResponse> {
+        let client = self.client()?;
+        let saved = self.oidc_repository.consume_state(state).await?;
+
+        let identity = client
+            .exchange(code, saved.pkce_verifier, saved.nonce)
+            .await?;
+        let user_id = self.resolve_user(identity).await?;
+
+        self.user_service.login_external(user_id).await
+    }
+}
@@ -0,0 +1,262 @@
+use {
+    crate::{
+        entities::{ownership_transfers, sea_orm_active_enums::RoleEnum},
+        enums::{error::*, generic::PaginatedResponse},
+        models::{
+            audit::{AuditAction, AuditEvent, AuditResource},
+            ownership::{QueryTransferParams, TransferAllResponse, TransferOwnershipRequest},
+        },
+        repositories::ownership::OwnershipRepository,
+        services::traits::{
+            audit_trait::AuditService, ownership_trait::OwnershipService, user_trait::UserService,
+        },
+    },
+    async_trait::async_trait,
+    std::sync::Arc,
+    uuid::Uuid,
+};
+
+pub struct OwnershipServiceImpl {
+    ownership_repository: Arc<OwnershipRepository>,
+    user_service: Arc<dyn UserService>,
+    audit_service: Arc<dyn AuditService>,
+}
+
+impl OwnershipServiceImpl {
+    pub fn new(
+        ownership_repository: Arc<OwnershipRepository>,
+        user_service: Arc<dyn UserService>,
+        audit_service: Arc<dyn AuditService>,
+    ) -> Self {
+        Self {
+            ownership_repository,
+            user_service,
+            audit_service,
+        }
+    }
+
+    async fn check_owner_or_staff(&self, caller_id: Uuid, owner_id: Uuid) -> Result<()> {
+        if caller_id == owner_id {
+            return Ok(());
+        }
+
+        check_staff(&self.user_service.get_by_id(caller_id).await?.role)
+    }
+
+    async fn check_target(&self, owner_id: Uuid, to_user_id: Uuid) -> Result<()> {
+        check_not_owner(owner_id, to_user_id)?;
+
+        check_target_found(self.user_service.get_by_id(to_user_id).await)
+    }
+
+    async fn transfer_one(
+        &self,
+        caller_id: Uuid,
+        resource: AuditResource,
+        resource_id: Uuid,
+        payload: TransferOwnershipRequest,
+    ) -> Result<ownership_transfers::Model> {
+        let owner_id = self
+            .ownership_repository
+            .get_owner(resource, resource_id)
+            .await?;
+        self.check_owner_or_staff(caller_id, owner_id).await?;
+        self.check_target(owner_id, payload.to_user_id).await?;
+
+        let (set_ids, quiz_ids) = match resource {
+            AuditResource::Set => (vec![resource_id], vec![]),
+            _ => (vec![], vec![resource_id]),
+        };
+        let transfers = self
+            .ownership_repository
+            .transfer(
+                &set_ids,
+                &quiz_ids,
+                owner_id,
+                payload.to_user_id,
+                caller_id,
+                payload.keep_previous_owner,
+            )
+            .await?;
+        self.record(caller_id, &transfers).await;
+
+        transfers.into_iter().next().ok_or(Error::RecordNotFound)
+    }
+
+    async fn record(&self, caller_id: Uuid, transfers: &[ownership_transfers::Model]) {
+        for transfer in transfers {
+            let resource = if transfer.resource_type == AuditResource::Set.as_str() {
+                AuditResource::Set
+            } else {
+                AuditResource::Quiz
+            };
+            self.audit_service
+                .record(
+                    AuditEvent::new(
+                        Some(caller_id),
+                        AuditAction::Transfer,
+                        resource,
+                        Some(transfer.resource_id),
+                    )
+                    .after(transfer),
+                )
+                .await;
+        }
+    }
+}
+
+fn invalid(field: &str, code: &str, message: &str) -> Error {
+    Error::Validation(vec![FieldError::new(field, code, message)])
+}
+
+// Người không phải chủ chỉ chuyển được khi là Staff
+fn check_staff(caller_role: &RoleEnum) -> Result<()> {
+    if *caller_role != RoleEnum::Staff {
+        return Err(Error::PermissionDenied);
+    }
+
+    Ok(())
+}
+
+fn check_not_owner(owner_id: Uuid, to_user_id: Uuid) -> Result<()> {
+    if owner_id == to_user_id {
+        return Err(invalid(
+            "to_user_id",
+            "already_owner",
+            "User already owns this content",
+        ));
+    }
+
+    Ok(())
+}
+
+fn check_target_found<T>(target: Result<T>) -> Result<()> {
+    match target {
+        Err(Error::RecordNotFound) => {
+            Err(invalid(
+                "to_user_id",
+                "user_not_found",
+                "User does not exist",
+            ))
+        }
+        res => res.map(|_| ()),
+    }
+}
+
+#[async_trait]
+impl OwnershipService for OwnershipServiceImpl {
+    async fn transfer_set(
+        &self,
+        caller_id: Uuid,
+        set_id: Uuid,
+        payload: TransferOwnershipRequest,
+    ) -> Result<ownership_transfers::Model> {
+        self.transfer_one(caller_id, AuditResource::Set, set_id, payload)
+            .await
+    }
+
+    async fn transfer_quiz(
+        &self,
+        caller_id: Uuid,
+        quiz_id: Uuid,
+        payload: TransferOwnershipRequest,
+    ) -> Result<ownership_transfers::Model> {
+        self.transfer_one(caller_id, AuditResource::Quiz, quiz_id, payload)
+            .await
+    }
+
+    async fn transfer_all(
+        &self,
+        caller_id: Uuid,
+        from_user_id: Uuid,
+        payload: TransferOwnershipRequest,
+    ) -> Result<TransferAllResponse> {
+        self.check_owner_or_staff(caller_id, from_user_id).await?;
+        self.check_target(from_user_id, payload.to_user_id).await?;
+
+        let (set_ids, quiz_ids) = self.ownership_repository.get_owned(from_user_id).await?;
+        let transfers = self
+            .ownership_repository
+            .transfer(
+                &set_ids,
+                &quiz_ids,
+                from_user_id,
+                payload.to_user_id,
+                caller_id,
+                payload.keep_previous_owner,
+            )
+            .await?;
+        self.record(caller_id, &transfers).await;
+
+        Ok(TransferAllResponse { set_ids, quiz_ids })
+    }
+
+    async fn get_history(
+        &self,
+        caller_id: Uuid,
+        resource: AuditResource,
+        resource_id: Uuid,
+        params: QueryTransferParams,
+    ) -> Result<PaginatedResponse<ownership_transfers::Model>> {
+        let owner_id = self
+            .ownership_repository
+            .get_owner(resource, resource_id)
+            .await?;
+        self.check_owner_or_staff(caller_id, owner_id).await?;
+
+        self.ownership_repository
+            .get_history(
+                resource,
+                resource_id,
+                params.page.unwrap_or(1),
+                params.page_size.unwrap_or(10).clamp(1, 100),
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, sea_orm::DbErr};
+
+    fn code_of(res: Result<()>) -> String {
+        match res {
+            Err(Error::Validation(errors)) => errors[0].code.clone(),
+            res => panic!("expected a validation error, got {res:?}"),
+        }
+    }
+
+    #[test]
+    fn non_owner_must_be_staff() {
+        assert!(matches!(
+            check_staff(&RoleEnum::User),
+            Err(Error::PermissionDenied)
+        ));
+        assert!(check_staff(&RoleEnum::Staff).is_ok());
+    }
+
+    #[test]
+    fn cannot_transfer_to_current_owner() {
+        let owner_id = Uuid::new_v4();
+
+        assert_eq!(
+            code_of(check_not_owner(owner_id, owner_id)),
+            "already_owner"
+        );
+        assert!(check_not_owner(owner_id, Uuid::new_v4()).is_ok());
+    }
+
+    #[test]
+    fn target_user_must_exist() {
+        assert_eq!(
+            code_of(check_target_found::<()>(Err(Error::RecordNotFound))),
+            "user_not_found"
+        );
+        assert!(check_target_found(Ok(())).is_ok());
+        // lỗi khác của DB giữ nguyên để trả 500
+        assert!(matches!(
+            check_target_found::<()>(Err(Error::QueryFailed(DbErr::Custom("down".into())))),
+            Err(Error::QueryFailed(_))
+        ));
+    }
+}
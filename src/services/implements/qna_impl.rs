@@ -1,13 +1,23 @@
 use {
     crate::{
-        entities::questions,
+        entities::{question_revisions, questions, sea_orm_active_enums::QuestionTypeEnum, tags},
         enums::{error::*, generic::PaginatedResponse},
         models::{
-            qna::{CreateQnARequest, QueryQuestionParams, UpdateQuestionRequest},
+            audit::{AuditAction, AuditEvent, AuditResource},
+            difficulty::DifficultyRange,
+            qna::{
+                AnswerDTO, CreateQnARequest, QueryQuestionParams, QueryRevisionParams,
+                QuestionRevisionResponse, UpdateQuestionRequest,
+            },
             set::SharedPermission,
         },
         repositories::question::QnARepository,
-        services::traits::{prelude::SetService, qna_trait::QnAService},
+        services::traits::{
+            audit_trait::AuditService,
+            prelude::{MediaService, SetService, TagService},
+            qna_trait::QnAService,
+        },
+        utils::{diff::diff_revisions, rich_text::sanitize},
     },
     async_trait::async_trait,
     std::sync::Arc,
@@ -17,22 +27,115 @@ use {
 pub struct QnAServiceImpl {
     qna_repository: Arc<QnARepository>,
     set_service: Arc<dyn SetService>,
+    media_service: Arc<dyn MediaService>,
+    tag_service: Arc<dyn TagService>,
+    audit_service: Arc<dyn AuditService>,
 }
 
 impl QnAServiceImpl {
-    pub fn new(qna_repository: Arc<QnARepository>, set_service: Arc<dyn SetService>) -> Self {
+    pub fn new(
+        qna_repository: Arc<QnARepository>,
+        set_service: Arc<dyn SetService>,
+        media_service: Arc<dyn MediaService>,
+        tag_service: Arc<dyn TagService>,
+        audit_service: Arc<dyn AuditService>,
+    ) -> Self {
         Self {
             qna_repository,
             set_service,
+            media_service,
+            tag_service,
+            audit_service,
+        }
+    }
+
+    // Caller là creator của question || creator của set chứa question || được
+    // share set với edit permission
+    async fn check_can_edit(&self, caller_id: Uuid, qna_id: Uuid, set_id: Uuid) -> Result<()> {
+        // creator của question
+        let is_creator_of_qna = self
+            .qna_repository
+            .is_creator_of_question(qna_id, caller_id)
+            .await?;
+        //creator của set
+        let is_creator_of_set = self.set_service.is_creator(set_id, caller_id).await?;
+        // được share set với edit permission
+        let is_shared_in_edit_permission = self
+            .set_service
+            .check_share_permission(set_id, caller_id, SharedPermission::Edit)
+            .await?;
+
+        if !is_creator_of_qna && !is_creator_of_set && !is_shared_in_edit_permission {
+            return Err(Error::PermissionDenied);
         }
+
+        Ok(())
+    }
+}
+
+// Media được gắn vào câu hỏi và các đáp án
+fn media_ids_of(media_id: Option<Uuid>, answers: &[AnswerDTO]) -> Vec<Uuid> {
+    media_id
+        .into_iter()
+        .chain(answers.iter().filter_map(|a| a.media_id))
+        .collect()
+}
+
+// Khôi phục revision giống hệt nội dung hiện tại chỉ tạo revision rỗng
+fn check_not_current(
+    question: &questions::Model,
+    revision: &question_revisions::Model,
+) -> Result<()> {
+    let is_current = revision.content == question.content
+        && revision.content_format == question.content_format
+        && revision.answers == question.answers
+        && revision.media_id == question.media_id;
+    if is_current {
+        return Err(Error::Validation(vec![FieldError::new(
+            "revision_id",
+            "already_current",
+            "The question already matches this revision",
+        )]));
     }
+
+    Ok(())
+}
+
+// Trang theo revision mới nhất trước, mỗi revision so với revision liền sau nó
+// trong danh sách hoặc `previous` với revision cuối trang
+fn with_diffs(
+    revisions: Vec<question_revisions::Model>,
+    previous: Option<question_revisions::Model>,
+) -> Vec<QuestionRevisionResponse> {
+    let befores = revisions
+        .iter()
+        .skip(1)
+        .cloned()
+        .map(Some)
+        .chain([previous])
+        .collect::<Vec<_>>();
+
+    revisions
+        .into_iter()
+        .zip(befores)
+        .map(|(revision, before)| {
+            QuestionRevisionResponse {
+                diff: before.map(|before| diff_revisions(&before, &revision)),
+                revision,
+            }
+        })
+        .collect()
 }
 
 #[async_trait]
 impl QnAService for QnAServiceImpl {
     // tạo qna trong set thì caller phải là creator của set hoặc được share set với
     // edit permission
-    async fn create(&self, caller_id: Uuid, payload: CreateQnARequest) -> Result<questions::Model> {
+    async fn create(
+        &self,
+        caller_id: Uuid,
+        mut payload: CreateQnARequest,
+    ) -> Result<questions::Model> {
         let is_creator_of_set = self
             .set_service
             .is_creator(payload.set_id, caller_id)
@@ -46,52 +149,190 @@ impl QnAService for QnAServiceImpl {
             return Err(Error::PermissionDenied);
         }
 
-        self.qna_repository.create_one(payload, caller_id).await
+        self.media_service
+            .check_owned(caller_id, media_ids_of(payload.media_id, &payload.answers))
+            .await?;
+
+        payload.content = sanitize(payload.content_format, "content", &payload.content)?;
+
+        let question = self.qna_repository.create_one(payload, caller_id).await?;
+
+        self.audit_service
+            .record(
+                AuditEvent::new(
+                    Some(caller_id),
+                    AuditAction::Create,
+                    AuditResource::Question,
+                    Some(question.id),
+                )
+                .after(&question),
+            )
+            .await;
+
+        Ok(question)
     }
 
     // Để update được thì caller phải là creator của question || creator của set
     // chứa question || được share set với edit permission
+    async fn prepare_update(
+        &self,
+        caller_id: Uuid,
+        qna_id: Uuid,
+        mut payload: UpdateQuestionRequest,
+    ) -> Result<(questions::Model, UpdateQuestionRequest)> {
+        let before = self.qna_repository.get_by_id(qna_id).await?;
+        self.check_can_edit(caller_id, qna_id, before.set_id)
+            .await?;
+
+        // media đã gắn sẵn trên câu hỏi thì giữ lại được, media mới phải của caller
+        let before_answers: Vec<AnswerDTO> =
+            serde_json::from_value(before.answers.clone()).unwrap_or_default();
+        let attached = media_ids_of(before.media_id, &before_answers);
+        let new_media_ids =
+            media_ids_of(payload.media_id, payload.answers.as_deref().unwrap_or(&[]))
+                .into_iter()
+                .filter(|id| !attached.contains(id))
+                .collect();
+        self.media_service
+            .check_owned(caller_id, new_media_ids)
+            .await?;
+
+        // đổi format thì nội dung cũ cũng phải qua sanitize theo format mới
+        if payload.content.is_some() || payload.content_format.is_some() {
+            let format = payload.content_format.unwrap_or(before.content_format);
+            let content = payload.content.take().unwrap_or(before.content.clone());
+            payload.content = Some(sanitize(format, "content", &content)?);
+        }
+
+        Ok((before, payload))
+    }
+
     async fn update(
         &self,
         caller_id: Uuid,
         qna_id: Uuid,
         payload: UpdateQuestionRequest,
     ) -> Result<Option<questions::Model>> {
-        let set_id = self.qna_repository.get_by_id(qna_id).await?.set_id;
+        let (before, payload) = self.prepare_update(caller_id, qna_id, payload).await?;
 
-        // creator của question
-        let is_creator_of_qna = self
+        let question = self
             .qna_repository
-            .is_creator_of_question(qna_id, caller_id)
-            .await?;
-        //creator của set
-        let is_creator_of_set = self.set_service.is_creator(set_id, caller_id).await?;
-        // được share set với edit permission
-        let is_shared_in_edit_permission = self
-            .set_service
-            .check_share_permission(set_id, caller_id, SharedPermission::Edit)
+            .update_question(qna_id, payload, caller_id)
             .await?;
 
-        if is_creator_of_qna || is_creator_of_set || is_shared_in_edit_permission {
-            self.qna_repository
-                .update_question(qna_id, payload, caller_id)
-                .await
-        } else {
-            Err(Error::PermissionDenied)
+        if let Some(after) = &question {
+            self.audit_service
+                .record(
+                    AuditEvent::new(
+                        Some(caller_id),
+                        AuditAction::Update,
+                        AuditResource::Question,
+                        Some(qna_id),
+                    )
+                    .before(&before)
+                    .after(after),
+                )
+                .await;
         }
+
+        Ok(question)
+    }
+
+    async fn get_revisions(
+        &self,
+        caller_id: Uuid,
+        qna_id: Uuid,
+        params: QueryRevisionParams,
+    ) -> Result<PaginatedResponse<QuestionRevisionResponse>> {
+        self.get_by_id(caller_id, qna_id).await?;
+
+        let (revisions, previous) = self
+            .qna_repository
+            .get_revisions(
+                qna_id,
+                params.page.unwrap_or(1),
+                params.page_size.unwrap_or(10).clamp(1, 100),
+            )
+            .await?;
+
+        let PaginatedResponse {
+            total_pages,
+            current_page,
+            page_size,
+            data,
+        } = revisions;
+
+        Ok(PaginatedResponse {
+            total_pages,
+            current_page,
+            page_size,
+            data: with_diffs(data, previous),
+        })
+    }
+
+    async fn rollback(
+        &self,
+        caller_id: Uuid,
+        qna_id: Uuid,
+        revision_id: Uuid,
+    ) -> Result<questions::Model> {
+        let before = self.qna_repository.get_by_id(qna_id).await?;
+        self.check_can_edit(caller_id, qna_id, before.set_id)
+            .await?;
+
+        let revision = self
+            .qna_repository
+            .get_revision(qna_id, revision_id)
+            .await?;
+        check_not_current(&before, &revision)?;
+
+        let question = self
+            .qna_repository
+            .restore_revision(&revision, caller_id)
+            .await?;
+
+        self.audit_service
+            .record(
+                AuditEvent::new(
+                    Some(caller_id),
+                    AuditAction::Update,
+                    AuditResource::Question,
+                    Some(qna_id),
+                )
+                .before(&before)
+                .after(&question),
+            )
+            .await;
+
+        Ok(question)
     }
 
     // Chỉ có người tạo qna mới xoá được
     async fn delete(&self, caller_id: Uuid, qna_id: Uuid) -> Result<()> {
-        if self
+        if !self
             .qna_repository
             .is_creator_of_question(qna_id, caller_id)
             .await?
         {
-            self.qna_repository.delete_question(qna_id).await
-        } else {
-            Err(Error::PermissionDenied)
+            return Err(Error::PermissionDenied);
         }
+
+        let before = self.qna_repository.get_by_id(qna_id).await?;
+        self.qna_repository.delete_question(qna_id).await?;
+
+        self.audit_service
+            .record(
+                AuditEvent::new(
+                    Some(caller_id),
+                    AuditAction::Delete,
+                    AuditResource::Question,
+                    Some(qna_id),
+                )
+                .before(&before),
+            )
+            .await;
+
+        Ok(())
     }
 
     // Lấy qna với điều kiện caller là creator của question hoặc là creator của set
@@ -142,4 +383,142 @@ impl QnAService for QnAServiceImpl {
         }
         Err(Error::AccessDenied)
     }
+    // Ai xem được question thì xem được tag
+    async fn get_tags(&self, caller_id: Uuid, qna_id: Uuid) -> Result<Vec<tags::Model>> {
+        self.get_by_id(caller_id, qna_id).await?;
+
+        self.tag_service.get_question_tags(qna_id).await
+    }
+
+    async fn set_tags(
+        &self,
+        caller_id: Uuid,
+        qna_id: Uuid,
+        tag_ids: Vec<Uuid>,
+    ) -> Result<Vec<tags::Model>> {
+        let question = self.qna_repository.get_by_id(qna_id).await?;
+        self.check_can_edit(caller_id, qna_id, question.set_id)
+            .await?;
+
+        self.tag_service
+            .replace_question_tags(qna_id, tag_ids)
+            .await
+    }
+
+    async fn get_random_by_tags(
+        &self,
+        caller_id: Uuid,
+        tag_ids: Vec<Uuid>,
+        r#type: Option<QuestionTypeEnum>,
+        difficulty: DifficultyRange,
+        count: u64,
+    ) -> Result<Vec<Uuid>> {
+        let sets = self.set_service.get_all(caller_id).await?;
+        let set_ids = sets
+            .own_sets
+            .into_iter()
+            .chain(sets.shared_sets.into_iter().map(|shared| shared.set))
+            .chain(sets.public_sets)
+            .map(|set| set.id)
+            .collect();
+
+        self.tag_service
+            .random_question_ids(tag_ids, set_ids, r#type, difficulty, count)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*, crate::entities::sea_orm_active_enums::ContentFormatEnum, chrono::Utc,
+        serde_json::json,
+    };
+
+    fn revision(number: i32, content: &str) -> question_revisions::Model {
+        question_revisions::Model {
+            id: Uuid::new_v4(),
+            question_id: Uuid::nil(),
+            revision: number,
+            author_id: None,
+            content: content.to_owned(),
+            content_format: ContentFormatEnum::Plain,
+            answers: json!([]),
+            media_id: None,
+            restored_from: None,
+            created_at: Utc::now().naive_utc(),
+        }
+    }
+
+    fn question_at(revision: &question_revisions::Model) -> questions::Model {
+        questions::Model {
+            id: revision.question_id,
+            r#type: QuestionTypeEnum::MultipleChoice,
+            content: revision.content.clone(),
+            content_format: revision.content_format,
+            answers: revision.answers.clone(),
+            set_id: Uuid::new_v4(),
+            creator_id: Uuid::new_v4(),
+            latest_updater_id: None,
+            created_at: revision.created_at,
+            updated_at: revision.created_at,
+            is_deleted: false,
+            deleted_at: None,
+            difficulty: None,
+            difficulty_confidence: None,
+            difficulty_attempts: 0,
+            media_id: revision.media_id,
+        }
+    }
+
+    fn content_change(response: &QuestionRevisionResponse) -> Option<(String, String)> {
+        let diff = response.diff.as_ref()?;
+        diff.content
+            .as_ref()
+            .map(|change| (change.before.clone(), change.after.clone()))
+    }
+
+    #[test]
+    fn rollback_to_current_revision_is_rejected() {
+        let current = revision(3, "v3");
+        let question = question_at(&current);
+
+        match check_not_current(&question, &current) {
+            Err(Error::Validation(errors)) => assert_eq!(errors[0].code, "already_current"),
+            res => panic!("expected already_current, got {res:?}"),
+        }
+    }
+
+    #[test]
+    fn rollback_to_older_revision_is_allowed() {
+        let question = question_at(&revision(3, "v3"));
+
+        assert!(check_not_current(&question, &revision(1, "v1")).is_ok());
+    }
+
+    #[test]
+    fn last_revision_of_page_is_diffed_against_previous() {
+        // trang 2 với page_size 2 của 5 revision: [3, 2], revision 1 nằm ở trang sau
+        let page = with_diffs(
+            vec![revision(3, "v3"), revision(2, "v2")],
+            Some(revision(1, "v1")),
+        );
+
+        assert_eq!(
+            content_change(&page[0]),
+            Some(("v2".to_owned(), "v3".to_owned()))
+        );
+        assert_eq!(
+            content_change(&page[1]),
+            Some(("v1".to_owned(), "v2".to_owned()))
+        );
+    }
+
+    #[test]
+    fn first_revision_has_no_diff() {
+        let page = with_diffs(vec![revision(2, "v2"), revision(1, "v1")], None);
+
+        assert!(page[0].diff.is_some());
+        assert!(page[1].diff.is_none());
+    }
 }
@@ -3,11 +3,12 @@ use {
         entities::{quizes, shared_quizes},
         enums::{error::*, generic::PaginatedResponse},
         models::{
+            audit::{AuditAction, AuditEvent, AuditResource},
             quiz::{CreateQuizRequest, FilterQuizParams, QuizWithVisibility, UpdateQuizRequest},
             user::UserModel,
         },
         repositories::quiz::QuizRepository,
-        services::traits::quiz_trait::QuizService,
+        services::traits::{audit_trait::AuditService, quiz_trait::QuizService},
     },
     async_trait::async_trait,
     std::sync::Arc,
@@ -16,18 +17,36 @@ use {
 
 pub struct QuizServiceImpl {
     quiz_repository: Arc<QuizRepository>,
+    audit_service: Arc<dyn AuditService>,
 }
 
 impl QuizServiceImpl {
-    pub fn new(quiz_repository: Arc<QuizRepository>) -> Self {
-        Self { quiz_repository }
+    pub fn new(quiz_repository: Arc<QuizRepository>, audit_service: Arc<dyn AuditService>) -> Self {
+        Self {
+            quiz_repository,
+            audit_service,
+        }
     }
 }
 
 #[async_trait]
 impl QuizService for QuizServiceImpl {
     async fn create(&self, creator_id: Uuid, payload: CreateQuizRequest) -> Result<quizes::Model> {
-        self.quiz_repository.create_one(payload, creator_id).await
+        let quiz = self.quiz_repository.create_one(payload, creator_id).await?;
+
+        self.audit_service
+            .record(
+                AuditEvent::new(
+                    Some(creator_id),
+                    AuditAction::Create,
+                    AuditResource::Quiz,
+                    Some(quiz.id),
+                )
+                .after(&quiz),
+            )
+            .await;
+
+        Ok(quiz)
     }
 
     async fn update(
@@ -44,14 +63,48 @@ impl QuizService for QuizServiceImpl {
             return Err(Error::Published);
         }
 
-        self.quiz_repository.update_one(quiz_id, payload).await
+        let updated = self.quiz_repository.update_one(quiz_id, payload).await?;
+
+        if let Some(after) = &updated {
+            // lần đầu publish được ghi riêng để dễ tra cứu
+            let action = if after.is_published && !quiz.is_published {
+                AuditAction::Publish
+            } else {
+                AuditAction::Update
+            };
+            self.audit_service
+                .record(
+                    AuditEvent::new(Some(caller_id), action, AuditResource::Quiz, Some(quiz_id))
+                        .before(&quiz)
+                        .after(after),
+                )
+                .await;
+        }
+
+        Ok(updated)
     }
 
     async fn delete(&self, caller_id: Uuid, quiz_id: Uuid) -> Result<()> {
-        if self.is_created_by(quiz_id, caller_id).await? {
-            return self.quiz_repository.delete_one(quiz_id).await;
+        if !self.is_created_by(quiz_id, caller_id).await? {
+            return Err(Error::PermissionDenied);
         }
-        Err(Error::PermissionDenied)
+
+        let before = self.quiz_repository.get_by_id(caller_id, quiz_id).await?;
+        self.quiz_repository.delete_one(quiz_id).await?;
+
+        self.audit_service
+            .record(
+                AuditEvent::new(
+                    Some(caller_id),
+                    AuditAction::Delete,
+                    AuditResource::Quiz,
+                    Some(quiz_id),
+                )
+                .before(&before),
+            )
+            .await;
+
+        Ok(())
     }
 
     async fn get_by_id(&self, caller_id: Uuid, quiz_id: Uuid) -> Result<quizes::Model> {
@@ -77,9 +130,24 @@ impl QuizService for QuizServiceImpl {
             return Err(Error::AccessDenied);
         }
 
-        self.quiz_repository
+        let shared = self
+            .quiz_repository
             .create_share(quiz_id, new_participants)
-            .await
+            .await?;
+
+        self.audit_service
+            .record(
+                AuditEvent::new(
+                    Some(caller_id),
+                    AuditAction::Share,
+                    AuditResource::Quiz,
+                    Some(quiz_id),
+                )
+                .after(&shared),
+            )
+            .await;
+
+        Ok(shared)
     }
 
     async fn get_all_shared_users_of_quiz(
@@ -3,20 +3,27 @@ use {
         entities::sea_orm_active_enums::QuestionTypeEnum,
         enums::error::*,
         models::{
+            audit::{AuditAction, AuditEvent, AuditResource},
+            difficulty::DifficultyRange,
             quiz::{QuestionCounts, UpdateQuizRequest},
             quiz_question::{
-                CreateQuizQuestionFromQuestion, CreateQuizQuestionRequest, QuizQuestionResponse,
+                CreateQuizQuestionFromMistakes, CreateQuizQuestionFromQuestion,
+                CreateQuizQuestionFromTags, CreateQuizQuestionRequest, QuizQuestionResponse,
                 UpdateQuizQuestionRequest,
             },
         },
         repositories::quiz_question::QuizQuestionRepository,
         services::traits::{
-            prelude::QnAService, quiz_question_trait::QuizQuestionService, quiz_trait::QuizService,
+            audit_trait::AuditService, prelude::QnAService,
+            quiz_question_trait::QuizQuestionService, quiz_trait::QuizService,
+        },
+        utils::{
+            helpers::total_question_count, mistakes::mistake_pool, rich_text::sanitize,
+            validator::validate_answer,
         },
-        utils::{helpers::total_question_count, validator::validate_answer},
     },
     async_trait::async_trait,
-    std::sync::Arc,
+    std::{collections::HashMap, sync::Arc},
     uuid::Uuid,
 };
 
@@ -24,6 +31,8 @@ pub struct QuizQuestionServiceImpl {
     quiz_question_repository: Arc<QuizQuestionRepository>,
     quiz_service: Arc<dyn QuizService>,
     qna_service: Arc<dyn QnAService>,
+    audit_service: Arc<dyn AuditService>,
+    mastery_streak: usize,
 }
 
 impl QuizQuestionServiceImpl {
@@ -31,11 +40,31 @@ impl QuizQuestionServiceImpl {
         quiz_question_repository: Arc<QuizQuestionRepository>,
         quiz_service: Arc<dyn QuizService>,
         qna_service: Arc<dyn QnAService>,
+        audit_service: Arc<dyn AuditService>,
+        mastery_streak: usize,
     ) -> Self {
         Self {
             quiz_question_repository,
             quiz_service,
             qna_service,
+            audit_service,
+            mastery_streak,
+        }
+    }
+
+    async fn record_created(&self, caller_id: Uuid, created: &[QuizQuestionResponse]) {
+        for quiz_question in created {
+            self.audit_service
+                .record(
+                    AuditEvent::new(
+                        Some(caller_id),
+                        AuditAction::Create,
+                        AuditResource::QuizQuestion,
+                        Some(quiz_question.question.id),
+                    )
+                    .after(quiz_question),
+                )
+                .await;
         }
     }
 }
@@ -46,7 +75,7 @@ impl QuizQuestionService for QuizQuestionServiceImpl {
         &self,
         caller_id: Uuid,
         quiz_id: Uuid,
-        payloads: Vec<CreateQuizQuestionRequest>,
+        mut payloads: Vec<CreateQuizQuestionRequest>,
     ) -> Result<Vec<QuizQuestionResponse>> {
         let quiz = self.quiz_service.get_by_id(caller_id, quiz_id).await?;
         if quiz.creator_id != caller_id {
@@ -56,10 +85,23 @@ impl QuizQuestionService for QuizQuestionServiceImpl {
             return Err(Error::PermissionDenied);
         }
 
-        for payload in payloads.iter() {
+        for (i, payload) in payloads.iter_mut().enumerate() {
             if !validate_answer(&payload.r#type, &payload.answers) {
                 return Err(Error::InvalidAnswer);
             }
+
+            payload.question_content = sanitize(
+                payload.content_format,
+                &format!("[{i}].question_content"),
+                &payload.question_content,
+            )?;
+            if let Some(explaination) = &payload.explaination {
+                payload.explaination = Some(sanitize(
+                    payload.content_format,
+                    &format!("[{i}].explaination"),
+                    explaination,
+                )?);
+            }
         }
 
         let res = self
@@ -100,6 +142,8 @@ impl QuizQuestionService for QuizQuestionServiceImpl {
             )
             .await?;
 
+        self.record_created(caller_id, &res).await;
+
         Ok(res)
     }
 
@@ -151,14 +195,113 @@ impl QuizQuestionService for QuizQuestionServiceImpl {
             })
             .collect::<Vec<_>>();
 
+        self.record_created(caller_id, &res).await;
+
         Ok(res)
     }
 
+    async fn create_from_tags(
+        &self,
+        caller_id: Uuid,
+        payload: CreateQuizQuestionFromTags,
+    ) -> Result<Vec<QuizQuestionResponse>> {
+        let question_ids = self
+            .qna_service
+            .get_random_by_tags(
+                caller_id,
+                payload.tag_ids,
+                payload.r#type,
+                DifficultyRange {
+                    min: payload.min_difficulty,
+                    max: payload.max_difficulty,
+                },
+                payload.count,
+            )
+            .await?;
+        if question_ids.is_empty() {
+            return Err(Error::RecordNotFound);
+        }
+
+        self.create_from_question(
+            caller_id,
+            CreateQuizQuestionFromQuestion {
+                quiz_id: payload.quiz_id,
+                question_ids,
+            },
+        )
+        .await
+    }
+
+    async fn create_from_mistakes(
+        &self,
+        caller_id: Uuid,
+        payload: CreateQuizQuestionFromMistakes,
+    ) -> Result<Vec<QuizQuestionResponse>> {
+        let quiz = self
+            .quiz_service
+            .get_by_id(caller_id, payload.quiz_id)
+            .await?;
+        if quiz.creator_id != caller_id {
+            return Err(Error::PermissionDenied);
+        }
+
+        let history = self
+            .quiz_question_repository
+            .get_answer_history(caller_id, payload.set_id)
+            .await?;
+        let mut pool = mistake_pool(
+            &history,
+            payload.from,
+            payload.to,
+            self.mastery_streak.max(1),
+        );
+        if let Some(count) = payload.count {
+            pool.truncate(count);
+        }
+        if pool.is_empty() {
+            return Err(Error::RecordNotFound);
+        }
+
+        let mut sources: HashMap<Uuid, _> = self
+            .quiz_question_repository
+            .get_by_ids(pool.clone())
+            .await?
+            .into_iter()
+            .map(|(question, answers)| (question.id, (question, answers)))
+            .collect();
+
+        // bản sao giữ `sample_id` để lần làm sau vẫn tính vào cùng câu hỏi gốc
+        let first_index = total_question_count(
+            serde_json::from_value::<QuestionCounts>(quiz.question_counts)
+                .map_err(|e| Error::Anyhow(e.into()))?,
+        );
+        let creating_questions = pool
+            .into_iter()
+            .filter_map(|id| sources.remove(&id))
+            .zip(first_index..)
+            .map(|((question, answers), index)| {
+                CreateQuizQuestionRequest {
+                    question_content: question.question_content,
+                    content_format: question.content_format,
+                    answers: answers.into_iter().map(Into::into).collect(),
+                    r#type: question.r#type,
+                    sample_id: question.sample_id,
+                    point: question.point,
+                    index,
+                    explaination: question.explanation,
+                }
+            })
+            .collect();
+
+        self.create(caller_id, payload.quiz_id, creating_questions)
+            .await
+    }
+
     async fn update(
         &self,
         caller_id: Uuid,
         quiz_id: Uuid,
-        payloads: Vec<UpdateQuizQuestionRequest>,
+        mut payloads: Vec<UpdateQuizQuestionRequest>,
     ) -> Result<Vec<QuizQuestionResponse>> {
         // Need to validate answers
 
@@ -170,6 +313,49 @@ impl QuizQuestionService for QuizQuestionServiceImpl {
             return Err(Error::PermissionDenied);
         }
 
+        let mut befores = Vec::with_capacity(payloads.len());
+        for payload in payloads.iter() {
+            befores.push(
+                self.quiz_question_repository
+                    .get_by_id(payload.question_id, quiz_id)
+                    .await
+                    .ok()
+                    .map(|(question, answers)| QuizQuestionResponse { question, answers }),
+            );
+        }
+
+        // đổi format thì nội dung cũ cũng phải qua sanitize theo format mới
+        for (i, (payload, before)) in payloads.iter_mut().zip(befores.iter()).enumerate() {
+            let content = &mut payload.content;
+            let before = before.as_ref().map(|b| &b.question);
+            let format = content
+                .content_format
+                .or(before.map(|b| b.content_format))
+                .unwrap_or_default();
+
+            if content.content_format.is_some() && content.question_content.is_none() {
+                content.question_content = before.map(|b| b.question_content.clone());
+            }
+            if content.content_format.is_some() && content.explaination.is_none() {
+                content.explaination = before.and_then(|b| b.explanation.clone());
+            }
+
+            if let Some(question_content) = &content.question_content {
+                content.question_content = Some(sanitize(
+                    format,
+                    &format!("[{i}].content.question_content"),
+                    question_content,
+                )?);
+            }
+            if let Some(explaination) = &content.explaination {
+                content.explaination = Some(sanitize(
+                    format,
+                    &format!("[{i}].content.explaination"),
+                    explaination,
+                )?);
+            }
+        }
+
         let res = self
             .quiz_question_repository
             .update_many(payloads)
@@ -208,6 +394,19 @@ impl QuizQuestionService for QuizQuestionServiceImpl {
             )
             .await?;
 
+        for (before, after) in befores.iter().zip(res.iter()) {
+            let mut event = AuditEvent::new(
+                Some(caller_id),
+                AuditAction::Update,
+                AuditResource::QuizQuestion,
+                Some(after.question.id),
+            );
+            if let Some(before) = before {
+                event = event.before(before);
+            }
+            self.audit_service.record(event.after(after)).await;
+        }
+
         Ok(res)
     }
 
@@ -216,9 +415,27 @@ impl QuizQuestionService for QuizQuestionServiceImpl {
             return Err(Error::PermissionDenied);
         }
 
+        let (question, answers) = self
+            .quiz_question_repository
+            .get_by_id(quiz_question_id, quiz_id)
+            .await?;
         self.quiz_question_repository
             .delete(quiz_question_id, quiz_id)
-            .await
+            .await?;
+
+        self.audit_service
+            .record(
+                AuditEvent::new(
+                    Some(caller_id),
+                    AuditAction::Delete,
+                    AuditResource::QuizQuestion,
+                    Some(quiz_question_id),
+                )
+                .before(&QuizQuestionResponse { question, answers }),
+            )
+            .await;
+
+        Ok(())
     }
 
     async fn get_by_id(
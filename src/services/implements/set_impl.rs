@@ -1,26 +1,59 @@
 use {
     crate::{
-        entities::{sets, shared_sets},
+        entities::{sea_orm_active_enums::TokenPurposeEnum, sets, shared_sets, tags},
         enums::error::*,
-        models::set::{
-            AllSetsOfUserResponse, CreateSetRequest, ShareSetForUser, SharedPermission,
-            UpdateSetRequest,
+        mailer::{templates::MailTemplate, Mailer},
+        models::{
+            audit::{AuditAction, AuditEvent, AuditResource},
+            set::{
+                AllSetsOfUserResponse, CreateSetRequest, InvitePayload, InviteToSetRequest,
+                ShareSetForUser, SharedPermission, UpdateSetRequest,
+            },
         },
-        repositories::set::SetRepository,
-        services::traits::set_trait::SetService,
+        repositories::{set::SetRepository, user_token::UserTokenRepository},
+        services::traits::{
+            audit_trait::AuditService, set_trait::SetService, tag_trait::TagService,
+            user_trait::UserService,
+        },
+        utils::jwt::{decode_action_token, encode_action_token},
     },
     async_trait::async_trait,
+    chrono::{Duration, Utc},
     std::sync::Arc,
     uuid::Uuid,
 };
 
+const INVITE_TOKEN_TTL_DAYS: i64 = 7;
+
 pub struct SetServiceImpl {
     set_repository: Arc<SetRepository>,
+    user_token_repository: Arc<UserTokenRepository>,
+    user_service: Arc<dyn UserService>,
+    tag_service: Arc<dyn TagService>,
+    mailer: Arc<dyn Mailer>,
+    audit_service: Arc<dyn AuditService>,
+    app_url: String,
 }
 
 impl SetServiceImpl {
-    pub fn new(set_repository: Arc<SetRepository>) -> Self {
-        Self { set_repository }
+    pub fn new(
+        set_repository: Arc<SetRepository>,
+        user_token_repository: Arc<UserTokenRepository>,
+        user_service: Arc<dyn UserService>,
+        tag_service: Arc<dyn TagService>,
+        mailer: Arc<dyn Mailer>,
+        audit_service: Arc<dyn AuditService>,
+        app_url: String,
+    ) -> Self {
+        Self {
+            set_repository,
+            user_token_repository,
+            user_service,
+            tag_service,
+            mailer,
+            audit_service,
+            app_url,
+        }
     }
 }
 
@@ -33,9 +66,24 @@ impl SetService for SetServiceImpl {
             public_or_not,
         } = payload;
 
-        self.set_repository
+        let set = self
+            .set_repository
             .create_one(caller_id, name, description, public_or_not)
-            .await
+            .await?;
+
+        self.audit_service
+            .record(
+                AuditEvent::new(
+                    Some(caller_id),
+                    AuditAction::Create,
+                    AuditResource::Set,
+                    Some(set.id),
+                )
+                .after(&set),
+            )
+            .await;
+
+        Ok(set)
     }
 
     async fn get_by_id(&self, caller_id: Uuid, set_id: Uuid) -> Result<sets::Model> {
@@ -68,16 +116,50 @@ impl SetService for SetServiceImpl {
             public_or_not,
         } = payload;
 
-        self.set_repository
+        let before = self.set_repository.get_by_id(caller_id, set_id).await?;
+        let set = self
+            .set_repository
             .update_one(set_id, name, description, public_or_not, caller_id)
-            .await
+            .await?;
+
+        if let Some(after) = &set {
+            self.audit_service
+                .record(
+                    AuditEvent::new(
+                        Some(caller_id),
+                        AuditAction::Update,
+                        AuditResource::Set,
+                        Some(set_id),
+                    )
+                    .before(&before)
+                    .after(after),
+                )
+                .await;
+        }
+
+        Ok(set)
     }
 
     async fn delete(&self, caller_id: Uuid, set_id: Uuid) -> Result<()> {
         if !self.set_repository.is_owner(set_id, caller_id).await? {
             return Err(Error::PermissionDenied);
         }
-        self.set_repository.delete_one(set_id).await
+        let before = self.set_repository.get_by_id(caller_id, set_id).await?;
+        self.set_repository.delete_one(set_id).await?;
+
+        self.audit_service
+            .record(
+                AuditEvent::new(
+                    Some(caller_id),
+                    AuditAction::Delete,
+                    AuditResource::Set,
+                    Some(set_id),
+                )
+                .before(&before),
+            )
+            .await;
+
+        Ok(())
     }
 
     async fn share(
@@ -92,12 +174,113 @@ impl SetService for SetServiceImpl {
             .await
             .is_ok()
         {
-            return self.set_repository.create_share_set(set_id, payload).await;
+            let shared = self
+                .set_repository
+                .create_share_set(set_id, payload)
+                .await?;
+
+            self.audit_service
+                .record(
+                    AuditEvent::new(
+                        Some(caller_id),
+                        AuditAction::Share,
+                        AuditResource::Set,
+                        Some(set_id),
+                    )
+                    .after(&shared),
+                )
+                .await;
+
+            return Ok(shared);
         }
 
         Err(Error::PermissionDenied)
     }
 
+    async fn invite(
+        &self,
+        caller_id: Uuid,
+        set_id: Uuid,
+        payload: InviteToSetRequest,
+    ) -> Result<()> {
+        if !self.set_repository.is_owner(set_id, caller_id).await? {
+            return Err(Error::PermissionDenied);
+        }
+        let set = self.set_repository.get_by_id(caller_id, set_id).await?;
+
+        let InviteToSetRequest { email, permission } = payload;
+        let invite_payload = InvitePayload {
+            set_id,
+            permission: permission.unwrap_or_default() as i32,
+        };
+        let expires_in = Duration::days(INVITE_TOKEN_TTL_DAYS);
+
+        let token = self
+            .user_token_repository
+            .create_one(
+                None,
+                email.clone(),
+                TokenPurposeEnum::InviteToSet,
+                Some(serde_json::to_value(invite_payload).map_err(|e| Error::Anyhow(e.into()))?),
+                (Utc::now() + expires_in).naive_utc(),
+            )
+            .await?;
+        let token = encode_action_token(
+            token.id,
+            email.clone(),
+            TokenPurposeEnum::InviteToSet,
+            expires_in,
+        )?;
+        let link = format!("{}/invite?token={token}", self.app_url);
+
+        self.mailer
+            .send(
+                MailTemplate::InviteToSet {
+                    set_name: &set.name,
+                    link: &link,
+                }
+                .into_mail(email),
+            )
+            .await
+    }
+
+    async fn accept_invite(&self, caller_id: Uuid, token: String) -> Result<shared_sets::Model> {
+        let claims = decode_action_token(&token, TokenPurposeEnum::InviteToSet)?;
+
+        // invite chỉ dành cho đúng email được mời
+        let caller = self.user_service.get_by_id(caller_id).await?;
+        if !caller.email.eq_ignore_ascii_case(&claims.sub) {
+            return Err(Error::AccessDenied);
+        }
+
+        let user_token = self
+            .user_token_repository
+            .consume(claims.jti, TokenPurposeEnum::InviteToSet)
+            .await?;
+        let InvitePayload { set_id, permission } =
+            serde_json::from_value(user_token.payload.ok_or(Error::InvalidToken)?)
+                .map_err(|_| Error::InvalidToken)?;
+
+        let shared = self
+            .set_repository
+            .upsert_share(set_id, caller_id, permission)
+            .await?;
+
+        self.audit_service
+            .record(
+                AuditEvent::new(
+                    Some(caller_id),
+                    AuditAction::Share,
+                    AuditResource::Set,
+                    Some(set_id),
+                )
+                .after(&shared),
+            )
+            .await;
+
+        Ok(shared)
+    }
+
     async fn check_share_permission(
         &self,
         set_id: Uuid,
@@ -112,4 +295,28 @@ impl SetService for SetServiceImpl {
     async fn is_creator(&self, set_id: Uuid, caller_id: Uuid) -> Result<bool> {
         self.set_repository.is_owner(set_id, caller_id).await
     }
+
+    async fn get_tags(&self, caller_id: Uuid, set_id: Uuid) -> Result<Vec<tags::Model>> {
+        self.set_repository.get_by_id(caller_id, set_id).await?;
+
+        self.tag_service.get_set_tags(set_id).await
+    }
+
+    async fn set_tags(
+        &self,
+        caller_id: Uuid,
+        set_id: Uuid,
+        tag_ids: Vec<Uuid>,
+    ) -> Result<Vec<tags::Model>> {
+        if !self.set_repository.is_owner(set_id, caller_id).await?
+            && !self
+                .set_repository
+                .check_share_permission(set_id, caller_id, SharedPermission::Edit)
+                .await?
+        {
+            return Err(Error::PermissionDenied);
+        }
+
+        self.tag_service.replace_set_tags(set_id, tag_ids).await
+    }
 }
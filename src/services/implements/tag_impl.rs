@@ -0,0 +1,325 @@
+use {
+    crate::{
+        entities::{sea_orm_active_enums::QuestionTypeEnum, tags},
+        enums::error::*,
+        models::{
+            difficulty::DifficultyRange,
+            tag::{CreateTagRequest, TagAccuracy, UpdateTagRequest},
+        },
+        repositories::tag::TagRepository,
+        services::traits::tag_trait::TagService,
+    },
+    async_trait::async_trait,
+    std::{
+        collections::{HashMap, HashSet},
+        sync::Arc,
+    },
+    uuid::Uuid,
+};
+
+pub struct TagServiceImpl {
+    tag_repository: Arc<TagRepository>,
+}
+
+impl TagServiceImpl {
+    pub fn new(tag_repository: Arc<TagRepository>) -> Self {
+        Self { tag_repository }
+    }
+
+    // Bỏ id trùng, lỗi nếu có tag không tồn tại
+    async fn check_tag_ids(&self, tag_ids: Vec<Uuid>) -> Result<Vec<Uuid>> {
+        let tag_ids: Vec<Uuid> = tag_ids
+            .into_iter()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        if self.tag_repository.count_by_ids(tag_ids.clone()).await? != tag_ids.len() as u64 {
+            return Err(Error::RecordNotFound);
+        }
+
+        Ok(tag_ids)
+    }
+}
+
+// Tag cùng các tag tổ tiên của nó
+fn with_ancestors(tag_id: Uuid, parents: &HashMap<Uuid, Option<Uuid>>) -> Vec<Uuid> {
+    let mut res = vec![];
+    let mut current = Some(tag_id);
+    while let Some(id) = current {
+        // dữ liệu lỗi có vòng lặp thì dừng
+        if res.contains(&id) {
+            break;
+        }
+        res.push(id);
+        current = parents.get(&id).copied().flatten();
+    }
+    res
+}
+
+// Mỗi câu trả lời được tính một lần cho mỗi tag của câu hỏi (trực tiếp hoặc
+// qua set), rồi cộng dồn lên các tag cha
+fn rollup(
+    results: Vec<(bool, Uuid, Uuid)>,
+    question_links: Vec<(Uuid, Uuid)>,
+    set_links: Vec<(Uuid, Uuid)>,
+    all: Vec<tags::Model>,
+) -> Vec<TagAccuracy> {
+    let mut tags_of_question: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for (question_id, tag_id) in question_links {
+        tags_of_question
+            .entry(question_id)
+            .or_default()
+            .push(tag_id);
+    }
+    let mut tags_of_set: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for (set_id, tag_id) in set_links {
+        tags_of_set.entry(set_id).or_default().push(tag_id);
+    }
+
+    let parents: HashMap<Uuid, Option<Uuid>> = all.iter().map(|t| (t.id, t.parent_id)).collect();
+
+    // tag_id -> (answered, correct)
+    let mut counts: HashMap<Uuid, (u64, u64)> = HashMap::new();
+    for (is_correct, question_id, set_id) in results {
+        let tag_ids: HashSet<Uuid> = tags_of_question
+            .get(&question_id)
+            .into_iter()
+            .chain(tags_of_set.get(&set_id))
+            .flatten()
+            .flat_map(|tag_id| with_ancestors(*tag_id, &parents))
+            .collect();
+
+        for tag_id in tag_ids {
+            let entry = counts.entry(tag_id).or_default();
+            entry.0 += 1;
+            entry.1 += is_correct as u64;
+        }
+    }
+
+    let mut res: Vec<TagAccuracy> = all
+        .into_iter()
+        .filter_map(|tag| {
+            let (answered, correct) = counts.get(&tag.id).copied()?;
+            Some(TagAccuracy {
+                tag_id: tag.id,
+                name: tag.name,
+                parent_id: tag.parent_id,
+                answered,
+                correct,
+                accuracy: correct as f64 / answered as f64,
+            })
+        })
+        .collect();
+    // tag yếu nhất lên đầu
+    res.sort_by(|a, b| a.accuracy.total_cmp(&b.accuracy));
+
+    res
+}
+
+#[async_trait]
+impl TagService for TagServiceImpl {
+    async fn get_all(&self) -> Result<Vec<tags::Model>> {
+        self.tag_repository.get_all().await
+    }
+
+    async fn create(&self, caller_id: Uuid, payload: CreateTagRequest) -> Result<tags::Model> {
+        let name = payload.name.trim().to_string();
+        if let Some(parent_id) = payload.parent_id {
+            self.tag_repository.get_by_id(parent_id).await?;
+        }
+        if self
+            .tag_repository
+            .name_exists(payload.parent_id, &name, None)
+            .await?
+        {
+            return Err(Error::TagAlreadyExists);
+        }
+
+        self.tag_repository
+            .create_one(name, payload.parent_id, caller_id)
+            .await
+    }
+
+    async fn update(&self, tag_id: Uuid, payload: UpdateTagRequest) -> Result<tags::Model> {
+        let before = self.tag_repository.get_by_id(tag_id).await?;
+
+        if let Some(Some(parent_id)) = payload.parent_id {
+            let all = self.tag_repository.get_all().await?;
+            let parents: HashMap<Uuid, Option<Uuid>> =
+                all.into_iter().map(|t| (t.id, t.parent_id)).collect();
+            if !parents.contains_key(&parent_id) {
+                return Err(Error::RecordNotFound);
+            }
+            if with_ancestors(parent_id, &parents).contains(&tag_id) {
+                return Err(Error::Validation(vec![FieldError::new(
+                    "parent_id",
+                    "tag_cycle",
+                    "A tag cannot be moved under itself or its descendants",
+                )]));
+            }
+        }
+
+        let name = payload.name.map(|name| name.trim().to_string());
+        let parent_id = payload.parent_id.unwrap_or(before.parent_id);
+        if self
+            .tag_repository
+            .name_exists(
+                parent_id,
+                name.as_deref().unwrap_or(&before.name),
+                Some(tag_id),
+            )
+            .await?
+        {
+            return Err(Error::TagAlreadyExists);
+        }
+
+        self.tag_repository
+            .update_one(tag_id, name, payload.parent_id)
+            .await
+    }
+
+    async fn delete(&self, tag_id: Uuid) -> Result<()> {
+        self.tag_repository.delete_one(tag_id).await
+    }
+
+    async fn get_question_tags(&self, question_id: Uuid) -> Result<Vec<tags::Model>> {
+        self.tag_repository.get_tags_of_question(question_id).await
+    }
+
+    async fn replace_question_tags(
+        &self,
+        question_id: Uuid,
+        tag_ids: Vec<Uuid>,
+    ) -> Result<Vec<tags::Model>> {
+        let tag_ids = self.check_tag_ids(tag_ids).await?;
+        self.tag_repository
+            .replace_question_tags(question_id, tag_ids)
+            .await?;
+
+        self.tag_repository.get_tags_of_question(question_id).await
+    }
+
+    async fn get_set_tags(&self, set_id: Uuid) -> Result<Vec<tags::Model>> {
+        self.tag_repository.get_tags_of_set(set_id).await
+    }
+
+    async fn replace_set_tags(&self, set_id: Uuid, tag_ids: Vec<Uuid>) -> Result<Vec<tags::Model>> {
+        let tag_ids = self.check_tag_ids(tag_ids).await?;
+        self.tag_repository
+            .replace_set_tags(set_id, tag_ids)
+            .await?;
+
+        self.tag_repository.get_tags_of_set(set_id).await
+    }
+
+    async fn random_question_ids(
+        &self,
+        tag_ids: Vec<Uuid>,
+        set_ids: Vec<Uuid>,
+        r#type: Option<QuestionTypeEnum>,
+        difficulty: DifficultyRange,
+        count: u64,
+    ) -> Result<Vec<Uuid>> {
+        self.tag_repository
+            .random_question_ids(tag_ids, set_ids, r#type, difficulty, count)
+            .await
+    }
+
+    async fn accuracy(&self, caller_id: Uuid) -> Result<Vec<TagAccuracy>> {
+        let results = self.tag_repository.get_graded_results(caller_id).await?;
+        if results.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let question_ids: HashSet<Uuid> = results.iter().map(|(_, q, _)| *q).collect();
+        let set_ids: HashSet<Uuid> = results.iter().map(|(_, _, s)| *s).collect();
+        let (question_links, set_links) = self
+            .tag_repository
+            .get_tag_links(
+                question_ids.into_iter().collect(),
+                set_ids.into_iter().collect(),
+            )
+            .await?;
+
+        let all = self.tag_repository.get_all().await?;
+
+        Ok(rollup(results, question_links, set_links, all))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, chrono::Utc};
+
+    fn tag(id: Uuid, name: &str, parent_id: Option<Uuid>) -> tags::Model {
+        tags::Model {
+            id,
+            name: name.to_owned(),
+            parent_id,
+            creator_id: None,
+            created_at: Utc::now().naive_utc(),
+        }
+    }
+
+    #[test]
+    fn ancestors_include_the_tag_and_stop_on_cycles() {
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let parents = HashMap::from([(a, None), (b, Some(a)), (c, Some(b))]);
+
+        assert_eq!(with_ancestors(c, &parents), vec![c, b, a]);
+
+        let cyclic = HashMap::from([(a, Some(b)), (b, Some(a))]);
+        assert_eq!(with_ancestors(a, &cyclic), vec![a, b]);
+    }
+
+    #[test]
+    fn accuracy_rolls_up_to_parents_counting_each_answer_once() {
+        let (math, algebra, geometry) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let (q1, q2, q3) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let set_id = Uuid::new_v4();
+        let all = vec![
+            tag(math, "Toán", None),
+            tag(algebra, "Đại số", Some(math)),
+            tag(geometry, "Hình học", Some(math)),
+        ];
+
+        let res = rollup(
+            vec![(true, q1, set_id), (false, q2, set_id), (true, q3, set_id)],
+            // q1 gắn cả Đại số lẫn Toán, vẫn chỉ tính một lần cho Toán
+            vec![(q1, algebra), (q1, math), (q2, algebra), (q3, geometry)],
+            vec![],
+            all,
+        );
+        let of = |id| res.iter().find(|t| t.tag_id == id).unwrap();
+
+        assert_eq!((of(algebra).answered, of(algebra).correct), (2, 1));
+        assert_eq!((of(geometry).answered, of(geometry).correct), (1, 1));
+        assert_eq!((of(math).answered, of(math).correct), (3, 2));
+        // tag yếu nhất lên đầu
+        assert_eq!(res[0].tag_id, algebra);
+    }
+
+    #[test]
+    fn set_tags_count_for_every_question_in_the_set() {
+        let (history, q1, q2, set_id) = (
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        );
+
+        let res = rollup(
+            vec![(true, q1, set_id), (false, q2, set_id)],
+            vec![],
+            vec![(set_id, history)],
+            vec![
+                tag(history, "Lịch sử", None),
+                tag(Uuid::new_v4(), "Địa lý", None),
+            ],
+        );
+
+        assert_eq!(res.len(), 1);
+        assert_eq!((res[0].answered, res[0].correct), (2, 1));
+        assert_eq!(res[0].accuracy, 0.5);
+    }
+}
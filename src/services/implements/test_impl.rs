@@ -1,17 +1,19 @@
 use {
     crate::{
+        config::AdaptiveConfig,
         debug,
         entities::{
             sea_orm_active_enums::{QuestionTypeEnum, StatusEnum},
-            test_question_results, tests,
+            test_adaptive_steps, test_question_results, tests,
         },
         enums::{error::*, generic::PaginatedResponse},
         error,
         models::{
             quiz::QuestionCounts,
+            quiz_question::QuizQuestionResponse,
             test::{
-                CreateTest, QueryTestParams, ResolveTestRequest, ResultResponse, SolutionResponse,
-                TestingQuestion, UpdateTest,
+                AdaptiveStep, CreateTest, QueryTestParams, ResolveTestRequest, ResolveTestResponse,
+                ResultResponse, SolutionResponse, TestingQuestion, UpdateTest,
             },
         },
         repositories::test::TestRepository,
@@ -19,11 +21,17 @@ use {
             quiz_question_trait::QuizQuestionService, quiz_trait::QuizService,
             test_trait::TestService,
         },
-        utils::helpers::total_question_count,
+        utils::{
+            difficulty::{estimate_ability, pick_next},
+            helpers::total_question_count,
+        },
     },
     async_trait::async_trait,
     chrono::Utc,
-    std::{collections::HashSet, sync::Arc},
+    std::{
+        collections::{HashMap, HashSet},
+        sync::Arc,
+    },
     uuid::Uuid,
 };
 
@@ -32,6 +40,7 @@ pub struct TestServiceImpl {
     quiz_service: Arc<dyn QuizService>,
     quiz_question_service: Arc<dyn QuizQuestionService>,
     // set_service: Arc<dyn SetService>,
+    adaptive: AdaptiveConfig,
 }
 
 impl TestServiceImpl {
@@ -40,19 +49,261 @@ impl TestServiceImpl {
         quiz_service: Arc<dyn QuizService>,
         quiz_question_service: Arc<dyn QuizQuestionService>,
         // set_service: Arc<dyn SetService>,
+        adaptive: AdaptiveConfig,
     ) -> Self {
         Self {
             test_repository,
             quiz_service,
             // set_service,
             quiz_question_service,
+            adaptive,
+        }
+    }
+
+    // Độ khó dùng để chọn câu: của quiz question, không có thì lấy của câu hỏi
+    // gốc, chưa ước lượng thì coi là trung bình
+    async fn question_pool(&self, quiz_id: Uuid) -> Result<HashMap<Uuid, f64>> {
+        Ok(self
+            .test_repository
+            .get_quiz_question_difficulties(quiz_id)
+            .await?
+            .into_iter()
+            .map(|(id, own, sample)| (id, own.or(sample).unwrap_or(0.0)))
+            .collect())
+    }
+
+    // Chỉ nhận câu trả lời cho câu hiện tại, cập nhật năng lực rồi chọn câu tiếp
+    // theo; dừng và nộp bài khi đủ chính xác hoặc hết số câu
+    async fn resolve_adaptive_question(
+        &self,
+        caller_id: Uuid,
+        test: tests::Model,
+        quiz_question_id: Uuid,
+        payloads: ResolveTestRequest,
+    ) -> Result<tests::Model> {
+        if quiz_question_id != test.current_quiz_question_id {
+            return Err(Error::Validation(vec![FieldError::new(
+                "quiz_question_id",
+                "not_current_question",
+                "Adaptive tests only accept an answer to the current question",
+            )]));
         }
+
+        let quiz_qna = self
+            .quiz_question_service
+            .get_by_id(caller_id, test.quiz_id, quiz_question_id)
+            .await?;
+        let selected_answer_ids = payloads
+            .save_test_answers
+            .iter()
+            .filter_map(|a| a.selected_answer_id)
+            .collect();
+        let is_correct = grade(
+            &quiz_qna,
+            selected_answer_ids,
+            payloads.save_test_answers.len(),
+        )
+        .unwrap_or(false);
+
+        let pool = self.question_pool(test.quiz_id).await?;
+        let steps = self.test_repository.get_adaptive_steps(test.id).await?;
+        let difficulty = pool.get(&quiz_question_id).copied().unwrap_or(0.0);
+
+        let mut responses: Vec<(f64, bool)> =
+            steps.iter().map(|s| (s.difficulty, s.is_correct)).collect();
+        responses.push((difficulty, is_correct));
+        let (ability, ability_se) = estimate_ability(&responses);
+
+        let answered: HashSet<Uuid> = steps
+            .iter()
+            .map(|s| s.quiz_question_id)
+            .chain([quiz_question_id])
+            .collect();
+        let next_quiz_question_id = if ability_se <= self.adaptive.target_se
+            || answered.len() as i32 >= test.total_question
+        {
+            None
+        } else {
+            pick_next(
+                ability,
+                pool.into_iter().filter(|(id, _)| !answered.contains(id)),
+            )
+        };
+
+        let updated_test = self
+            .test_repository
+            .resolve_test_answers(
+                caller_id,
+                test.id,
+                quiz_question_id,
+                payloads.revision,
+                payloads.session_id,
+                payloads.save_test_answers,
+                UpdateTest {
+                    current_testing_quiz_question: next_quiz_question_id,
+                    resolved_count: Some(answered.len() as i32),
+                    remaining_time: Some(payloads.remaining_time),
+                    ability: Some(ability),
+                    ability_se: Some(ability_se),
+                    ..Default::default()
+                },
+                Some(AdaptiveStep {
+                    step: steps.len() as i32 + 1,
+                    quiz_question_id,
+                    difficulty,
+                    is_correct,
+                    ability,
+                    ability_se,
+                    next_quiz_question_id,
+                }),
+            )
+            .await
+            .inspect_err(|e| {
+                error!("{}", e.to_string());
+            })?;
+
+        if next_quiz_question_id.is_none() {
+            self.submit_one(caller_id, test.id).await?;
+            return self.get_by_id(caller_id, test.id).await;
+        }
+
+        Ok(updated_test)
+    }
+
+    // Lưu câu trả lời như bài thường rồi chấm ngay, trả về đáp án đúng và lời
+    // giải; trả lời lại thì chấm lại và tăng số lần làm
+    async fn resolve_practice_question(
+        &self,
+        caller_id: Uuid,
+        test: tests::Model,
+        quiz_question_id: Uuid,
+        payloads: ResolveTestRequest,
+    ) -> Result<ResolveTestResponse> {
+        let quiz_qna = self
+            .quiz_question_service
+            .get_by_id(caller_id, test.quiz_id, quiz_question_id)
+            .await?;
+        let results = self
+            .test_repository
+            .get_all_test_question_result(test.id)
+            .await?;
+        let answered = answered_count(&results, quiz_question_id);
+
+        let selected_answer_ids: Vec<Uuid> = payloads
+            .save_test_answers
+            .iter()
+            .filter_map(|a| a.selected_answer_id)
+            .collect();
+        let text_answer = payloads
+            .save_test_answers
+            .iter()
+            .find_map(|a| a.text_answer.clone());
+        let spent_time = payloads
+            .save_test_answers
+            .iter()
+            .filter_map(|a| a.spent_time_in_second)
+            .max()
+            .unwrap_or_default();
+        let is_correct = grade(
+            &quiz_qna,
+            selected_answer_ids.iter().copied().collect(),
+            payloads.save_test_answers.len(),
+        );
+
+        let updated_test = self
+            .test_repository
+            .resolve_test_answers(
+                caller_id,
+                test.id,
+                quiz_question_id,
+                payloads.revision,
+                payloads.session_id,
+                payloads.save_test_answers,
+                UpdateTest {
+                    current_testing_quiz_question: Some(quiz_question_id),
+                    resolved_count: Some(answered as i32),
+                    remaining_time: Some(payloads.remaining_time),
+                    ..Default::default()
+                },
+                None,
+            )
+            .await
+            .inspect_err(|e| {
+                error!("{}", e.to_string());
+            })?;
+        self.test_repository
+            .record_practice_attempt(test.id, quiz_question_id, is_correct)
+            .await?;
+
+        Ok(ResolveTestResponse {
+            test: updated_test,
+            feedback: Some(SolutionResponse {
+                solution: quiz_qna,
+                text_answer,
+                selected_answer_ids,
+                is_correct,
+                spent_time,
+            }),
+        })
+    }
+}
+
+// Trắc nghiệm: đúng khi tập đáp án chọn trùng tập đáp án đúng. Tự điền: chưa
+// chấm nội dung, có đúng một câu trả lời là đúng, không có thì chưa chấm (None)
+fn grade(
+    quiz_qna: &QuizQuestionResponse,
+    selected_answer_ids: HashSet<Uuid>,
+    answer_count: usize,
+) -> Option<bool> {
+    if quiz_qna.question.r#type != QuestionTypeEnum::TextFill {
+        let correct_answer_ids = quiz_qna
+            .answers
+            .iter()
+            .filter(|a| a.is_answer)
+            .map(|a| a.id)
+            .collect::<HashSet<Uuid>>();
+
+        Some(correct_answer_ids == selected_answer_ids)
+    } else {
+        // todo: implement check text fill
+        (answer_count == 1).then_some(true)
+    }
+}
+
+// Test đã nộp hoặc bỏ dở thì không trả lời, gắn cờ hay take-over được nữa
+fn check_not_ended(status: &StatusEnum) -> Result<()> {
+    match status {
+        StatusEnum::Submitted | StatusEnum::Abandoned => Err(Error::TestEnded),
+        _ => Ok(()),
+    }
+}
+
+fn check_mode(is_adaptive: bool, is_practice: bool) -> Result<()> {
+    if is_adaptive && is_practice {
+        return Err(Error::Validation(vec![FieldError::new(
+            "is_practice",
+            "practice_not_adaptive",
+            "A practice test cannot be adaptive",
+        )]));
     }
+
+    Ok(())
+}
+
+// Số câu đã trả lời của bài luyện tập tính cả câu đang trả lời, làm lại một câu
+// không tính thêm
+fn answered_count(results: &[test_question_results::Model], quiz_question_id: Uuid) -> usize {
+    results
+        .iter()
+        .filter(|r| r.attempts > 0 || r.quiz_question_id == quiz_question_id)
+        .count()
 }
 
 #[async_trait]
 impl TestService for TestServiceImpl {
     async fn create_one(&self, caller_id: Uuid, payload: CreateTest) -> Result<tests::Model> {
+        check_mode(payload.is_adaptive, payload.is_practice)?;
+
         let quiz = self
             .quiz_service
             .get_by_id(caller_id, payload.quiz_id)
@@ -69,14 +320,28 @@ impl TestService for TestServiceImpl {
             serde_json::from_value::<QuestionCounts>(quiz.question_counts)
                 .map_err(|e| Error::Anyhow(e.into()))?,
         );
+
+        // bài thích ứng bắt đầu từ câu có độ khó gần mức trung bình nhất, các câu
+        // sau được chọn dần nên chỉ tạo kết quả cho câu đầu
+        let (first_quiz_question_id, total_questions, quiz_question_ids) = if payload.is_adaptive {
+            let pool = self.question_pool(quiz.id).await?;
+            let first = pick_next(0.0, pool.clone()).ok_or(Error::RecordNotFound)?;
+            let total = (pool.len() as i32).min(self.adaptive.max_questions.max(1));
+            (first, total, vec![first])
+        } else {
+            (quiz_question_ids[0], total_questions, quiz_question_ids)
+        };
+
         let res = self
             .test_repository
             .create_one(
                 quiz.id,
                 caller_id,
                 quiz.duration,
-                quiz_question_ids[0],
+                first_quiz_question_id,
                 total_questions,
+                payload.is_adaptive,
+                payload.is_practice,
             )
             .await?;
 
@@ -108,9 +373,7 @@ impl TestService for TestServiceImpl {
         // trả về current test question
 
         let test = self.get_by_id(caller_id, test_id).await?;
-        if test.status == StatusEnum::Submitted || test.status == StatusEnum::Abandoned {
-            return Err(Error::TestEnded);
-        }
+        check_not_ended(&test.status)?;
 
         let test = self
             .test_repository
@@ -120,6 +383,7 @@ impl TestService for TestServiceImpl {
                 UpdateTest {
                     started_at: Some(Utc::now().naive_utc()),
                     status: Some(StatusEnum::InProgess),
+                    session_id: test.session_id.or(Some(Uuid::new_v4())),
                     ..Default::default()
                 },
             )
@@ -137,8 +401,18 @@ impl TestService for TestServiceImpl {
         quiz_question_id: Uuid,
     ) -> Result<TestingQuestion> {
         let test = self.test_repository.get_by_id(caller_id, test_id).await?;
-        if test.status == StatusEnum::Submitted || test.status == StatusEnum::Abandoned {
-            return Err(Error::TestEnded);
+        check_not_ended(&test.status)?;
+        // bài thích ứng không cho xem trước các câu chưa được chọn
+        if test.is_adaptive
+            && quiz_question_id != test.current_quiz_question_id
+            && !self
+                .test_repository
+                .get_adaptive_steps(test_id)
+                .await?
+                .iter()
+                .any(|s| s.quiz_question_id == quiz_question_id)
+        {
+            return Err(Error::PermissionDenied);
         }
         let test_answers = self
             .test_repository
@@ -174,37 +448,61 @@ impl TestService for TestServiceImpl {
         test_id: Uuid,
         quiz_question_id: Uuid,
         payloads: ResolveTestRequest,
-    ) -> Result<Option<tests::Model>> {
+    ) -> Result<ResolveTestResponse> {
         // kiểm tra status => nếu submitted/abandoned thì trả về lỗi
         let test = self.get_by_id(caller_id, test_id).await?;
-        if test.status == StatusEnum::Submitted || test.status == StatusEnum::Abandoned {
-            return Err(Error::TestEnded);
+        check_not_ended(&test.status)?;
+        if test.is_adaptive {
+            let test = self
+                .resolve_adaptive_question(caller_id, test, quiz_question_id, payloads)
+                .await?;
+            return Ok(ResolveTestResponse {
+                test,
+                feedback: None,
+            });
+        }
+        if test.is_practice {
+            return self
+                .resolve_practice_question(caller_id, test, quiz_question_id, payloads)
+                .await;
         }
-
-        let updated_test_result = self
-            .test_repository
-            .save_test_answers(test_id, quiz_question_id, payloads.save_test_answers)
-            .await
-            .inspect_err(|e| {
-                error!("{}", e.to_string());
-            })?;
-        debug!("updated test result {:?}", updated_test_result);
 
         let updated_test = self
             .test_repository
-            .update_one(
+            .resolve_test_answers(
                 caller_id,
                 test_id,
+                quiz_question_id,
+                payloads.revision,
+                payloads.session_id,
+                payloads.save_test_answers,
                 UpdateTest {
                     current_testing_quiz_question: Some(quiz_question_id),
                     resolved_count: Some(test.completed_questions + 1),
                     remaining_time: Some(payloads.remaining_time),
                     ..Default::default()
                 },
+                None,
             )
-            .await?;
+            .await
+            .inspect_err(|e| {
+                error!("{}", e.to_string());
+            })?;
+        debug!("updated test {:?}", updated_test);
 
-        Ok(updated_test)
+        Ok(ResolveTestResponse {
+            test: updated_test,
+            feedback: None,
+        })
+    }
+
+    async fn take_over_session(&self, caller_id: Uuid, test_id: Uuid) -> Result<tests::Model> {
+        let test = self.get_by_id(caller_id, test_id).await?;
+        check_not_ended(&test.status)?;
+
+        self.test_repository
+            .take_over_session(caller_id, test_id, test.revision)
+            .await
     }
 
     async fn submit_one(
@@ -214,9 +512,7 @@ impl TestService for TestServiceImpl {
     ) -> Result<Vec<test_question_results::Model>> {
         // kiểm tra status => nếu submitted/abandoned thì trả về lỗi
         let test = self.get_by_id(caller_id, test_id).await?;
-        if test.status == StatusEnum::Submitted || test.status == StatusEnum::Abandoned {
-            return Err(Error::TestEnded);
-        }
+        check_not_ended(&test.status)?;
 
         let quiz_qnas = self
             .quiz_question_service
@@ -230,37 +526,31 @@ impl TestService for TestServiceImpl {
         let mut results = Vec::new();
         let mut total_score = 0;
 
-        for quiz_qna in quiz_qnas.into_iter() {
-            if quiz_qna.question.r#type != QuestionTypeEnum::TextFill {
-                let correct_answer_ids = quiz_qna
-                    .answers
-                    .iter()
-                    .filter(|a| a.is_answer)
-                    .map(|a| a.id)
-                    .collect::<HashSet<Uuid>>();
-                let selected_answer_ids = self
-                    .test_repository
-                    .get_test_answers(test_id, quiz_qna.question.id)
-                    .await?
-                    .iter()
-                    .map(|s| s.selected_answer_id.unwrap())
-                    .collect::<HashSet<Uuid>>();
-
-                results.push((
-                    quiz_qna.question.id,
-                    correct_answer_ids == selected_answer_ids,
-                ));
-                if correct_answer_ids == selected_answer_ids {
-                    total_score += quiz_qna.question.point;
-                }
-            } else {
-                // todo: implement check text fill
-                let answers = self
-                    .test_repository
-                    .get_test_answers(test_id, quiz_qna.question.id)
-                    .await?;
-                if answers.len() == 1 {
-                    results.push((quiz_qna.question.id, true));
+        // bài thích ứng chỉ chấm các câu đã được hỏi
+        let asked_ids = self
+            .test_repository
+            .get_all_test_question_result(test_id)
+            .await?
+            .into_iter()
+            .map(|r| r.quiz_question_id)
+            .collect::<HashSet<Uuid>>();
+
+        for quiz_qna in quiz_qnas
+            .into_iter()
+            .filter(|q| asked_ids.contains(&q.question.id))
+        {
+            let answers = self
+                .test_repository
+                .get_test_answers(test_id, quiz_qna.question.id)
+                .await?;
+            let selected_answer_ids = answers
+                .iter()
+                .filter_map(|s| s.selected_answer_id)
+                .collect::<HashSet<Uuid>>();
+
+            if let Some(is_correct) = grade(&quiz_qna, selected_answer_ids, answers.len()) {
+                results.push((quiz_qna.question.id, is_correct));
+                if is_correct {
                     total_score += quiz_qna.question.point;
                 }
             }
@@ -355,4 +645,85 @@ impl TestService for TestServiceImpl {
             spent_time,
         })
     }
+
+    async fn get_adaptive_path(
+        &self,
+        caller_id: Uuid,
+        test_id: Uuid,
+    ) -> Result<Vec<test_adaptive_steps::Model>> {
+        let test = self.test_repository.get_by_id(caller_id, test_id).await?;
+        if test.status != StatusEnum::Submitted && test.status != StatusEnum::Abandoned {
+            return Err(Error::TestNotEnd);
+        }
+
+        self.test_repository.get_adaptive_steps(test_id).await
+    }
+
+    async fn flag_question(
+        &self,
+        caller_id: Uuid,
+        test_id: Uuid,
+        quiz_question_id: Uuid,
+        is_flagged: bool,
+    ) -> Result<test_question_results::Model> {
+        // kiểm tra test thuộc về caller và chưa kết thúc
+        let test = self.get_by_id(caller_id, test_id).await?;
+        check_not_ended(&test.status)?;
+
+        self.test_repository
+            .set_flag(test_id, quiz_question_id, is_flagged)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod status_tests {
+    use super::*;
+
+    fn result(quiz_question_id: Uuid, attempts: i32) -> test_question_results::Model {
+        test_question_results::Model {
+            id: Uuid::new_v4(),
+            test_id: Uuid::nil(),
+            quiz_question_id,
+            index: Uuid::new_v4(),
+            is_correct: None,
+            attempts,
+            is_flagged: false,
+        }
+    }
+
+    #[test]
+    fn practice_cannot_be_adaptive() {
+        assert!(check_mode(true, false).is_ok());
+        assert!(check_mode(false, true).is_ok());
+        assert!(matches!(
+            check_mode(true, true),
+            Err(Error::Validation(errors)) if errors[0].code == "practice_not_adaptive"
+        ));
+    }
+
+    #[test]
+    fn retrying_a_practice_question_does_not_count_it_twice() {
+        let (q1, q2, q3) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let results = vec![result(q1, 2), result(q2, 0), result(q3, 0)];
+
+        // làm lại q1
+        assert_eq!(answered_count(&results, q1), 1);
+        // trả lời lần đầu q2
+        assert_eq!(answered_count(&results, q2), 2);
+    }
+
+    #[test]
+    fn ended_tests_cannot_be_flagged_or_answered() {
+        assert!(check_not_ended(&StatusEnum::NotStart).is_ok());
+        assert!(check_not_ended(&StatusEnum::InProgess).is_ok());
+        assert!(matches!(
+            check_not_ended(&StatusEnum::Submitted),
+            Err(Error::TestEnded)
+        ));
+        assert!(matches!(
+            check_not_ended(&StatusEnum::Abandoned),
+            Err(Error::TestEnded)
+        ));
+    }
 }
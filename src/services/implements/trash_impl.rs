@@ -0,0 +1,139 @@
+use {
+    crate::{
+        entities::{questions, quizes, sets},
+        enums::error::*,
+        models::{
+            audit::{AuditAction, AuditEvent, AuditResource},
+            trash::{PurgeReport, TrashResponse},
+        },
+        repositories::trash::TrashRepository,
+        services::traits::{audit_trait::AuditService, trash_trait::TrashService},
+    },
+    async_trait::async_trait,
+    chrono::{Duration, Utc},
+    std::sync::Arc,
+    uuid::Uuid,
+};
+
+pub struct TrashServiceImpl {
+    trash_repository: Arc<TrashRepository>,
+    audit_service: Arc<dyn AuditService>,
+    retention_days: i64,
+}
+
+impl TrashServiceImpl {
+    pub fn new(
+        trash_repository: Arc<TrashRepository>,
+        audit_service: Arc<dyn AuditService>,
+        retention_days: i64,
+    ) -> Self {
+        Self {
+            trash_repository,
+            audit_service,
+            retention_days,
+        }
+    }
+}
+
+#[async_trait]
+impl TrashService for TrashServiceImpl {
+    async fn get_all(&self, caller_id: Uuid) -> Result<TrashResponse> {
+        Ok(TrashResponse {
+            retention_days: self.retention_days,
+            sets: self.trash_repository.get_deleted_sets(caller_id).await?,
+            questions: self
+                .trash_repository
+                .get_deleted_questions(caller_id)
+                .await?,
+            quizzes: self.trash_repository.get_deleted_quizzes(caller_id).await?,
+        })
+    }
+
+    async fn restore_set(&self, caller_id: Uuid, set_id: Uuid) -> Result<sets::Model> {
+        let set = self.trash_repository.restore_set(caller_id, set_id).await?;
+
+        self.audit_service
+            .record(
+                AuditEvent::new(
+                    Some(caller_id),
+                    AuditAction::Restore,
+                    AuditResource::Set,
+                    Some(set_id),
+                )
+                .after(&set),
+            )
+            .await;
+
+        Ok(set)
+    }
+
+    async fn restore_question(
+        &self,
+        caller_id: Uuid,
+        question_id: Uuid,
+    ) -> Result<questions::Model> {
+        let question = self
+            .trash_repository
+            .restore_question(caller_id, question_id)
+            .await?;
+
+        self.audit_service
+            .record(
+                AuditEvent::new(
+                    Some(caller_id),
+                    AuditAction::Restore,
+                    AuditResource::Question,
+                    Some(question_id),
+                )
+                .after(&question),
+            )
+            .await;
+
+        Ok(question)
+    }
+
+    async fn restore_quiz(&self, caller_id: Uuid, quiz_id: Uuid) -> Result<quizes::Model> {
+        let quiz = self
+            .trash_repository
+            .restore_quiz(caller_id, quiz_id)
+            .await?;
+
+        self.audit_service
+            .record(
+                AuditEvent::new(
+                    Some(caller_id),
+                    AuditAction::Restore,
+                    AuditResource::Quiz,
+                    Some(quiz_id),
+                )
+                .after(&quiz),
+            )
+            .await;
+
+        Ok(quiz)
+    }
+
+    async fn purge_expired(&self) -> Result<PurgeReport> {
+        let cutoff = (Utc::now() - Duration::days(self.retention_days)).naive_utc();
+        let report = self.trash_repository.purge(cutoff).await?;
+
+        // chỉ ghi số lượng, nội dung đã có trong audit event lúc xoá
+        for (resource_type, count) in [
+            (AuditResource::Set, report.sets),
+            (AuditResource::Question, report.questions),
+            (AuditResource::Quiz, report.quizzes),
+            (AuditResource::QuizQuestion, report.quiz_questions),
+        ] {
+            if count > 0 {
+                self.audit_service
+                    .record(
+                        AuditEvent::new(None, AuditAction::Purge, resource_type, None)
+                            .after(&serde_json::json!({ "count": count, "before": cutoff })),
+                    )
+                    .await;
+            }
+        }
+
+        Ok(report)
+    }
+}
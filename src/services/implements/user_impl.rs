@@ -1,25 +1,177 @@
 use {
     crate::{
-        entities::{sea_orm_active_enums::RoleEnum, users},
+        config::TotpConfig,
+        entities::{
+            sea_orm_active_enums::{RoleEnum, TokenPurposeEnum},
+            users,
+        },
         enums::error::*,
-        models::user::{LoginRequest, RegisterUserRequest, UpdateUserRequest, UserModel},
-        repositories::user::UserRepository,
-        services::traits::user_trait::UserService,
-        utils::jwt::encode_jwt,
+        error,
+        mailer::{templates::MailTemplate, Mailer},
+        models::{
+            audit::{AuditAction, AuditEvent, AuditResource},
+            user::{
+                LoginRequest, LoginResponse, RegisterUserRequest, StaffInvitePayload,
+                TotpSetupResponse, UpdateUserRequest, UserModel,
+            },
+        },
+        rate_limit::RateLimiter,
+        repositories::{user::UserRepository, user_token::UserTokenRepository},
+        services::traits::{audit_trait::AuditService, user_trait::UserService},
+        utils::{
+            jwt::{
+                decode_action_token, decode_challenge_token, encode_action_token,
+                encode_challenge_token, encode_jwt,
+            },
+            totp::{
+                build_totp, generate_recovery_codes, generate_secret, hash_recovery_code,
+                verify_code,
+            },
+        },
     },
     async_trait::async_trait,
     bcrypt::{hash, verify, DEFAULT_COST},
+    chrono::{Duration, Utc},
     std::sync::Arc,
     uuid::Uuid,
 };
 
+const VERIFY_EMAIL_TOKEN_TTL_HOURS: i64 = 24;
+const RESET_PASSWORD_TOKEN_TTL_HOURS: i64 = 1;
+const STAFF_INVITE_TOKEN_TTL_DAYS: i64 = 3;
+
 pub struct UserServiceImpl {
     user_repository: Arc<UserRepository>,
+    user_token_repository: Arc<UserTokenRepository>,
+    mailer: Arc<dyn Mailer>,
+    rate_limiter: Arc<RateLimiter>,
+    audit_service: Arc<dyn AuditService>,
+    app_url: String,
+    totp_cfg: TotpConfig,
+    password_login: bool,
 }
 
 impl UserServiceImpl {
-    pub fn new(user_repository: Arc<UserRepository>) -> Self {
-        Self { user_repository }
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        user_repository: Arc<UserRepository>,
+        user_token_repository: Arc<UserTokenRepository>,
+        mailer: Arc<dyn Mailer>,
+        rate_limiter: Arc<RateLimiter>,
+        audit_service: Arc<dyn AuditService>,
+        app_url: String,
+        totp_cfg: TotpConfig,
+        password_login: bool,
+    ) -> Self {
+        Self {
+            user_repository,
+            user_token_repository,
+            mailer,
+            rate_limiter,
+            audit_service,
+            app_url,
+            totp_cfg,
+            password_login,
+        }
+    }
+
+    fn check_password_login(&self) -> Result<()> {
+        if self.password_login {
+            Ok(())
+        } else {
+            Err(Error::PasswordLoginDisabled)
+        }
+    }
+
+    // Bước cuối của login: yêu cầu TOTP nếu cần, ngược lại cấp JWT.
+    // Chỉ reset số lần sai khi đã qua hết các bước xác thực
+    async fn finish_login(&self, user: users::Model) -> Result<LoginResponse> {
+        if user.totp_enabled {
+            return Ok(LoginResponse::TotpRequired {
+                challenge_token: encode_challenge_token(user.id)?,
+            });
+        }
+        if self.totp_required(&user.role) {
+            return Ok(LoginResponse::TotpSetupRequired {
+                challenge_token: encode_challenge_token(user.id)?,
+            });
+        }
+        self.rate_limiter.login_succeeded(&user.email).await?;
+
+        Ok(LoginResponse::Authenticated {
+            token: encode_jwt(user.id, user.role.to_string())?,
+            recovery_codes: None,
+        })
+    }
+
+    fn totp_required(&self, role: &RoleEnum) -> bool {
+        self.totp_cfg.required_roles.contains(role)
+    }
+
+    // Kiểm tra mã TOTP, nếu không khớp thì thử như một recovery code
+    async fn check_second_factor(&self, user: &users::Model, code: &str) -> Result<bool> {
+        let secret = user.totp_secret.as_deref().ok_or(Error::TotpNotEnabled)?;
+        let totp = build_totp(secret, &self.totp_cfg.issuer, &user.email)?;
+
+        match verify_code(
+            &totp,
+            code,
+            Utc::now().timestamp() as u64,
+            user.totp_last_step,
+        ) {
+            Some(step) => self.user_repository.advance_totp_step(user.id, step).await,
+            None => {
+                self.user_repository
+                    .consume_recovery_code(user.id, hash_recovery_code(code))
+                    .await
+            }
+        }
+    }
+
+    // Tạo token một lần dùng cho user và trả về token đã ký
+    async fn issue_token(
+        &self,
+        user: &users::Model,
+        purpose: TokenPurposeEnum,
+        expires_in: Duration,
+    ) -> Result<String> {
+        self.user_token_repository
+            .revoke_all(&user.email, purpose.clone())
+            .await?;
+
+        let token = self
+            .user_token_repository
+            .create_one(
+                Some(user.id),
+                user.email.clone(),
+                purpose.clone(),
+                None,
+                (Utc::now() + expires_in).naive_utc(),
+            )
+            .await?;
+
+        encode_action_token(token.id, user.email.clone(), purpose, expires_in)
+    }
+
+    async fn send_verification(&self, user: &users::Model) -> Result<()> {
+        let token = self
+            .issue_token(
+                user,
+                TokenPurposeEnum::VerifyEmail,
+                Duration::hours(VERIFY_EMAIL_TOKEN_TTL_HOURS),
+            )
+            .await?;
+        let link = format!("{}/verify-email?token={token}", self.app_url);
+
+        self.mailer
+            .send(
+                MailTemplate::VerifyEmail {
+                    name: &user.name,
+                    link: &link,
+                }
+                .into_mail(user.email.clone()),
+            )
+            .await
     }
 }
 
@@ -42,34 +194,107 @@ impl UserService for UserServiceImpl {
 
     // Done ✅
     async fn register_user(&self, mut payload: RegisterUserRequest) -> Result<UserModel> {
+        self.check_password_login()?;
+
         let hashed_password =
             hash(&payload.password, DEFAULT_COST).map_err(|_| Error::HashingFailed)?;
 
         payload.password = hashed_password;
 
-        Ok(self.user_repository.create_user(payload).await?.into())
+        let user = self.user_repository.create_user(payload).await?;
+
+        // account is created anyway, the user can ask for another email later
+        if let Err(e) = self.send_verification(&user).await {
+            error!("send verification email failed: {}", e.to_string());
+        }
+
+        Ok(user.into())
     }
 
     // Done ✅
-    async fn login(&self, payload: LoginRequest) -> Result<String> {
+    async fn login(&self, payload: LoginRequest) -> Result<LoginResponse> {
+        self.check_password_login()?;
         let LoginRequest { email, password } = payload;
 
-        if let Ok(user) = self.user_repository.get_by_email(email).await {
-            if !verify(password, &user.password).map_err(|_| Error::VerifyPasswordFailed)? {
+        self.rate_limiter.check_account(&email).await?;
+
+        let user = match self.user_repository.get_by_email(email.clone()).await {
+            Ok(user)
+                if verify(password, &user.password).map_err(|_| Error::VerifyPasswordFailed)? =>
+            {
+                user
+            }
+            _ => {
+                self.rate_limiter.login_failed(&email).await?;
                 return Err(Error::LoginFailed);
             }
-            encode_jwt(user.id, user.role.to_string())
+        };
+
+        if !user.is_verified {
+            return Err(Error::EmailNotVerified);
+        }
+
+        self.finish_login(user).await
+    }
+
+    async fn login_totp(&self, challenge_token: String, code: String) -> Result<LoginResponse> {
+        let user_id = decode_challenge_token(&challenge_token)?;
+        let user = self.user_repository.get_by_id(user_id).await?;
+
+        self.rate_limiter.check_account(&user.email).await?;
+
+        let recovery_codes = if user.totp_enabled {
+            if !self.check_second_factor(&user, &code).await? {
+                self.rate_limiter.login_failed(&user.email).await?;
+                return Err(Error::InvalidTotpCode);
+            }
+            None
         } else {
-            Err(Error::LoginFailed)
+            // user bị bắt buộc bật TOTP, mã đầu tiên dùng để kích hoạt luôn
+            match self.activate_totp(user.id, code).await {
+                Ok(codes) => Some(codes),
+                Err(Error::InvalidTotpCode) => {
+                    self.rate_limiter.login_failed(&user.email).await?;
+                    return Err(Error::InvalidTotpCode);
+                }
+                Err(e) => return Err(e),
+            }
+        };
+        self.rate_limiter.login_succeeded(&user.email).await?;
+
+        Ok(LoginResponse::Authenticated {
+            token: encode_jwt(user.id, user.role.to_string())?,
+            recovery_codes,
+        })
+    }
+
+    async fn login_totp_setup(&self, challenge_token: String) -> Result<TotpSetupResponse> {
+        let user_id = decode_challenge_token(&challenge_token)?;
+
+        self.setup_totp(user_id).await
+    }
+
+    async fn login_external(&self, user_id: Uuid) -> Result<LoginResponse> {
+        let user = self.user_repository.get_by_id(user_id).await?;
+
+        // tài khoản tạo từ IdP chưa xác minh email cũng bị chặn như login mật khẩu
+        if !user.is_verified {
+            return Err(Error::EmailNotVerified);
         }
+
+        self.finish_login(user).await
     }
 
     // Done ✅
     async fn update_self(
         &self,
         caller_id: Uuid,
-        payload: UpdateUserRequest,
+        mut payload: UpdateUserRequest,
     ) -> Result<Option<UserModel>> {
+        // role và password có luồng riêng, không cho tự sửa ở đây
+        payload.role = None;
+        payload.password = None;
+
         if let Some(updated_user) = self.user_repository.update_user(caller_id, payload).await? {
             Ok(Some(updated_user.into()))
         } else {
@@ -78,6 +303,14 @@ impl UserService for UserServiceImpl {
     }
 
     // Done ✅
+    async fn set_avatar_media(&self, caller_id: Uuid, media_id: Uuid) -> Result<UserModel> {
+        Ok(self
+            .user_repository
+            .set_avatar_media(caller_id, media_id)
+            .await?
+            .into())
+    }
+
     async fn update_password(
         &self,
         caller_id: Uuid,
@@ -85,8 +318,11 @@ impl UserService for UserServiceImpl {
         new_password: String,
     ) -> Result<Option<UserModel>> {
         if let Ok(user) = self.user_repository.get_by_id(caller_id).await {
+            // đoán mật khẩu cũ qua session bị lộ cũng bị khoá như login sai
+            self.rate_limiter.check_account(&user.email).await?;
             if !verify(old_password, &user.password).map_err(|_| Error::VerifyPasswordFailed)? {
-                return Err(Error::VerifyPasswordFailed);
+                self.rate_limiter.login_failed(&user.email).await?;
+                return Err(Error::InvalidCredentials);
             }
 
             let hashed_password =
@@ -120,25 +356,315 @@ impl UserService for UserServiceImpl {
             .user_repository
             .check_role(caller_id, RoleEnum::Staff)
             .await?;
-        if is_staff {
-            Ok(self
-                .user_repository
-                .update_user(
-                    user_id,
-                    UpdateUserRequest {
-                        role: Some(role),
-                        ..Default::default()
-                    },
+        if !is_staff {
+            return Err(Error::PermissionDenied);
+        }
+
+        let before: UserModel = self.user_repository.get_by_id(user_id).await?.into();
+        let user: UserModel = self
+            .user_repository
+            .update_role(user_id, role)
+            .await?
+            .into();
+
+        self.audit_service
+            .record(
+                AuditEvent::new(
+                    Some(caller_id),
+                    AuditAction::RoleChange,
+                    AuditResource::User,
+                    Some(user_id),
                 )
-                .await?
-                .map(Into::into))
-        } else {
-            Err(Error::PermissionDenied)
+                .before(&before)
+                .after(&user),
+            )
+            .await;
+
+        Ok(Some(user))
+    }
+
+    async fn create_staff(&self, mut payload: RegisterUserRequest) -> Result<UserModel> {
+        payload.password =
+            hash(&payload.password, DEFAULT_COST).map_err(|_| Error::HashingFailed)?;
+
+        let user: UserModel = self.user_repository.create_staff(payload).await?.into();
+
+        // tạo từ CLI nên không có actor
+        self.audit_service
+            .record(
+                AuditEvent::new(
+                    None,
+                    AuditAction::Create,
+                    AuditResource::User,
+                    Some(user.id),
+                )
+                .after(&user),
+            )
+            .await;
+
+        Ok(user)
+    }
+
+    async fn invite_staff(&self, caller_id: Uuid, email: String) -> Result<()> {
+        if !self
+            .user_repository
+            .check_role(caller_id, RoleEnum::Staff)
+            .await?
+        {
+            return Err(Error::PermissionDenied);
         }
+
+        let invite_payload = StaffInvitePayload {
+            inviter_id: caller_id,
+        };
+        let expires_in = Duration::days(STAFF_INVITE_TOKEN_TTL_DAYS);
+
+        let token = self
+            .user_token_repository
+            .create_one(
+                None,
+                email.clone(),
+                TokenPurposeEnum::InviteStaff,
+                Some(serde_json::to_value(invite_payload).map_err(|e| Error::Anyhow(e.into()))?),
+                (Utc::now() + expires_in).naive_utc(),
+            )
+            .await?;
+        let token = encode_action_token(
+            token.id,
+            email.clone(),
+            TokenPurposeEnum::InviteStaff,
+            expires_in,
+        )?;
+        let link = format!("{}/staff-invite?token={token}", self.app_url);
+
+        self.mailer
+            .send(MailTemplate::InviteStaff { link: &link }.into_mail(email))
+            .await
+    }
+
+    async fn accept_staff_invite(&self, caller_id: Uuid, token: String) -> Result<UserModel> {
+        let claims = decode_action_token(&token, TokenPurposeEnum::InviteStaff)?;
+
+        // invite chỉ dành cho đúng email được mời
+        let caller = self.user_repository.get_by_id(caller_id).await?;
+        if !caller.email.eq_ignore_ascii_case(&claims.sub) {
+            return Err(Error::AccessDenied);
+        }
+
+        let user_token = self
+            .user_token_repository
+            .consume(claims.jti, TokenPurposeEnum::InviteStaff)
+            .await?;
+        let StaffInvitePayload { inviter_id } =
+            serde_json::from_value(user_token.payload.ok_or(Error::InvalidToken)?)
+                .map_err(|_| Error::InvalidToken)?;
+
+        // người mời bị hạ quyền hoặc bị xoá thì invite không còn hiệu lực
+        if !self
+            .user_repository
+            .check_role(inviter_id, RoleEnum::Staff)
+            .await?
+        {
+            return Err(Error::InvalidToken);
+        }
+
+        let user: UserModel = self
+            .user_repository
+            .update_role(caller_id, RoleEnum::Staff)
+            .await?
+            .into();
+
+        self.audit_service
+            .record(
+                AuditEvent::new(
+                    Some(caller_id),
+                    AuditAction::RoleChange,
+                    AuditResource::User,
+                    Some(caller_id),
+                )
+                .before(&UserModel::from(caller))
+                .after(&user),
+            )
+            .await;
+
+        Ok(user)
     }
 
     // Done ✅
-    async fn delete(&self, caller_id: Uuid) -> Result<()> {
-        self.user_repository.delete_user(caller_id).await
+    async fn delete(&self, caller_id: Uuid, user_id: Uuid) -> Result<()> {
+        let before: UserModel = self.user_repository.get_by_id(user_id).await?.into();
+        self.user_repository.delete_user(user_id).await?;
+
+        self.audit_service
+            .record(
+                AuditEvent::new(
+                    Some(caller_id),
+                    AuditAction::Delete,
+                    AuditResource::User,
+                    Some(user_id),
+                )
+                .before(&before),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn setup_totp(&self, caller_id: Uuid) -> Result<TotpSetupResponse> {
+        let user = self.user_repository.get_by_id(caller_id).await?;
+        if user.totp_enabled {
+            return Err(Error::TotpAlreadyEnabled);
+        }
+
+        let secret = generate_secret();
+        let totp = build_totp(&secret, &self.totp_cfg.issuer, &user.email)?;
+        self.user_repository
+            .set_totp_secret(caller_id, secret.clone())
+            .await?;
+
+        Ok(TotpSetupResponse {
+            secret,
+            provisioning_uri: totp.get_url(),
+        })
+    }
+
+    async fn activate_totp(&self, caller_id: Uuid, code: String) -> Result<Vec<String>> {
+        let user = self.user_repository.get_by_id(caller_id).await?;
+        if user.totp_enabled {
+            return Err(Error::TotpAlreadyEnabled);
+        }
+
+        let secret = user.totp_secret.as_deref().ok_or(Error::TotpNotEnabled)?;
+        let totp = build_totp(secret, &self.totp_cfg.issuer, &user.email)?;
+        let step = verify_code(
+            &totp,
+            &code,
+            Utc::now().timestamp() as u64,
+            user.totp_last_step,
+        )
+        .ok_or(Error::InvalidTotpCode)?;
+
+        let recovery_codes = generate_recovery_codes();
+        self.user_repository
+            .enable_totp(
+                caller_id,
+                step,
+                recovery_codes
+                    .iter()
+                    .map(|c| hash_recovery_code(c))
+                    .collect(),
+            )
+            .await?;
+
+        Ok(recovery_codes)
+    }
+
+    async fn disable_totp(&self, caller_id: Uuid, code: String) -> Result<()> {
+        let user = self.user_repository.get_by_id(caller_id).await?;
+        if !user.totp_enabled {
+            return Err(Error::TotpNotEnabled);
+        }
+        if self.totp_required(&user.role) {
+            return Err(Error::PermissionDenied);
+        }
+        if !self.check_second_factor(&user, &code).await? {
+            return Err(Error::InvalidTotpCode);
+        }
+
+        self.user_repository.reset_totp(caller_id).await
+    }
+
+    // only admin role, dùng khi user mất thiết bị và hết recovery code
+    async fn reset_totp(&self, caller_id: Uuid, user_id: Uuid) -> Result<()> {
+        if !self
+            .user_repository
+            .check_role(caller_id, RoleEnum::Staff)
+            .await?
+        {
+            return Err(Error::PermissionDenied);
+        }
+
+        self.user_repository.reset_totp(user_id).await
+    }
+
+    async fn verify_email(&self, token: String) -> Result<UserModel> {
+        let claims = decode_action_token(&token, TokenPurposeEnum::VerifyEmail)?;
+        let user_token = self
+            .user_token_repository
+            .consume(claims.jti, TokenPurposeEnum::VerifyEmail)
+            .await?;
+        let user_id = user_token.user_id.ok_or(Error::InvalidToken)?;
+
+        Ok(self.user_repository.mark_verified(user_id).await?.into())
+    }
+
+    async fn resend_verification(&self, email: String) -> Result<()> {
+        self.rate_limiter.check_account(&email).await?;
+
+        // không báo lỗi khi email không tồn tại để tránh dò email
+        if let Ok(user) = self.user_repository.get_by_email(email).await {
+            if !user.is_verified {
+                self.send_verification(&user).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn forgot_password(&self, email: String) -> Result<()> {
+        self.check_password_login()?;
+        self.rate_limiter.check_account(&email).await?;
+
+        // không báo lỗi khi email không tồn tại để tránh dò email
+        if let Ok(user) = self.user_repository.get_by_email(email).await {
+            let token = self
+                .issue_token(
+                    &user,
+                    TokenPurposeEnum::ResetPassword,
+                    Duration::hours(RESET_PASSWORD_TOKEN_TTL_HOURS),
+                )
+                .await?;
+            let link = format!("{}/reset-password?token={token}", self.app_url);
+
+            self.mailer
+                .send(
+                    MailTemplate::ResetPassword {
+                        name: &user.name,
+                        link: &link,
+                    }
+                    .into_mail(user.email.clone()),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn reset_password(&self, token: String, new_password: String) -> Result<()> {
+        self.check_password_login()?;
+        let claims = decode_action_token(&token, TokenPurposeEnum::ResetPassword)?;
+        let user_token = self
+            .user_token_repository
+            .consume(claims.jti, TokenPurposeEnum::ResetPassword)
+            .await?;
+        let user_id = user_token.user_id.ok_or(Error::InvalidToken)?;
+
+        let hashed_password =
+            hash(&new_password, DEFAULT_COST).map_err(|_| Error::HashingFailed)?;
+
+        self.user_repository
+            .update_user(
+                user_id,
+                UpdateUserRequest {
+                    password: Some(hashed_password),
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        // resetting through the mailbox also proves the email address
+        self.user_repository.mark_verified(user_id).await?;
+
+        Ok(())
     }
 }
@@ -0,0 +1,24 @@
+use {
+    crate::{
+        enums::error::*,
+        models::api_token::{
+            ApiTokenModel, ApiTokenScope, CreateApiTokenRequest, CreatedApiTokenResponse,
+        },
+        utils::jwt::Claims,
+    },
+    async_trait::async_trait,
+    uuid::Uuid,
+};
+
+#[async_trait]
+pub trait ApiTokenService: Send + Sync {
+    async fn create(
+        &self,
+        caller_id: Uuid,
+        payload: CreateApiTokenRequest,
+    ) -> Result<CreatedApiTokenResponse>;
+    async fn get_all_of_user(&self, caller_id: Uuid) -> Result<Vec<ApiTokenModel>>;
+    async fn revoke(&self, caller_id: Uuid, token_id: Uuid) -> Result<()>;
+    // Đổi token dạng `fcp_...` thành claims của user sở hữu và scope của token
+    async fn authenticate(&self, token: String) -> Result<(Claims, Vec<ApiTokenScope>)>;
+}
@@ -0,0 +1,19 @@
+use {
+    crate::{
+        entities::audit_events,
+        enums::{error::*, generic::PaginatedResponse},
+        models::audit::{AuditEvent, QueryAuditParams},
+    },
+    async_trait::async_trait,
+};
+
+#[async_trait]
+pub trait AuditService: Send + Sync {
+    // Ghi lại một thay đổi, lỗi chỉ được log để không làm hỏng thao tác chính
+    async fn record(&self, event: AuditEvent);
+    // Tra cứu audit log theo filter, có phân trang
+    async fn get_all(
+        &self,
+        params: QueryAuditParams,
+    ) -> Result<PaginatedResponse<audit_events::Model>>;
+}
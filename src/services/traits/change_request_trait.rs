@@ -0,0 +1,57 @@
+use {
+    crate::{
+        entities::change_requests,
+        enums::{error::*, generic::PaginatedResponse},
+        models::change_request::{
+            ChangeRequestResponse, CreateChangeRequest, QueryChangeRequestParams,
+        },
+    },
+    async_trait::async_trait,
+    uuid::Uuid,
+};
+
+// Người được share set đề xuất sửa câu hỏi, người sửa được câu hỏi duyệt
+#[async_trait]
+pub trait ChangeRequestService: Send + Sync {
+    async fn create(
+        &self,
+        caller_id: Uuid,
+        question_id: Uuid,
+        payload: CreateChangeRequest,
+    ) -> Result<change_requests::Model>;
+
+    async fn get_all_of_question(
+        &self,
+        caller_id: Uuid,
+        question_id: Uuid,
+        params: QueryChangeRequestParams,
+    ) -> Result<PaginatedResponse<change_requests::Model>>;
+
+    async fn get_all_of_set(
+        &self,
+        caller_id: Uuid,
+        set_id: Uuid,
+        params: QueryChangeRequestParams,
+    ) -> Result<PaginatedResponse<change_requests::Model>>;
+
+    // Kèm diff với câu hỏi lúc đề xuất
+    async fn get_by_id(&self, caller_id: Uuid, id: Uuid) -> Result<ChangeRequestResponse>;
+
+    // Áp dụng đề xuất qua `QnAService::update`
+    async fn accept(
+        &self,
+        caller_id: Uuid,
+        id: Uuid,
+        review_note: Option<String>,
+    ) -> Result<change_requests::Model>;
+
+    async fn reject(
+        &self,
+        caller_id: Uuid,
+        id: Uuid,
+        review_note: Option<String>,
+    ) -> Result<change_requests::Model>;
+
+    // Người đề xuất tự rút lại
+    async fn withdraw(&self, caller_id: Uuid, id: Uuid) -> Result<change_requests::Model>;
+}
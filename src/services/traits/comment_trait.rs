@@ -0,0 +1,55 @@
+use {
+    crate::{
+        enums::{error::*, generic::PaginatedResponse},
+        models::comment::{
+            CommentResponse, CommentTarget, CommentThread, CreateCommentRequest,
+            QueryCommentParams, UpdateCommentRequest,
+        },
+    },
+    async_trait::async_trait,
+    uuid::Uuid,
+};
+
+// Thảo luận trên set và câu hỏi, xem được set là đọc được, cần là creator hoặc
+// được share từ quyền Comment trở lên để viết
+#[async_trait]
+pub trait CommentService: Send + Sync {
+    async fn get_threads(
+        &self,
+        caller_id: Uuid,
+        target: CommentTarget,
+        params: QueryCommentParams,
+    ) -> Result<PaginatedResponse<CommentThread>>;
+
+    async fn create(
+        &self,
+        caller_id: Uuid,
+        target: CommentTarget,
+        payload: CreateCommentRequest,
+    ) -> Result<CommentResponse>;
+
+    // Chỉ người viết được sửa/xoá
+    async fn update(
+        &self,
+        caller_id: Uuid,
+        comment_id: Uuid,
+        payload: UpdateCommentRequest,
+    ) -> Result<CommentResponse>;
+
+    async fn delete(&self, caller_id: Uuid, comment_id: Uuid) -> Result<()>;
+
+    // Góp ý được đánh dấu đã xử lý bởi người viết, creator của set hoặc người có
+    // quyền Edit
+    async fn resolve(
+        &self,
+        caller_id: Uuid,
+        comment_id: Uuid,
+        is_resolved: bool,
+    ) -> Result<CommentResponse>;
+
+    async fn get_mentions(
+        &self,
+        caller_id: Uuid,
+        params: QueryCommentParams,
+    ) -> Result<PaginatedResponse<CommentResponse>>;
+}
@@ -0,0 +1,11 @@
+use {
+    crate::{enums::error::*, models::difficulty::CalibrationReport},
+    async_trait::async_trait,
+};
+
+#[async_trait]
+pub trait DifficultyService: Send + Sync {
+    // Ước lượng lại độ khó của question và quiz question từ toàn bộ lịch sử làm
+    // bài đã nộp
+    async fn calibrate(&self) -> Result<CalibrationReport>;
+}
@@ -0,0 +1,27 @@
+use {
+    crate::{
+        enums::error::*,
+        models::{
+            media::{MediaContent, MediaResponse, SignedUrlParams},
+            user::UserModel,
+        },
+    },
+    async_trait::async_trait,
+    uuid::Uuid,
+};
+
+#[async_trait]
+pub trait MediaService: Send + Sync {
+    // Loại file đoán từ nội dung, chỉ nhận ảnh và audio
+    async fn upload(&self, caller_id: Uuid, data: Vec<u8>) -> Result<MediaResponse>;
+    // Owner hoặc người xem được câu hỏi dùng media này
+    async fn get(&self, caller_id: Uuid, media_id: Uuid) -> Result<MediaResponse>;
+    // Tải qua link đã ký, không cần đăng nhập
+    async fn download(&self, media_id: Uuid, params: SignedUrlParams) -> Result<MediaContent>;
+    async fn delete(&self, caller_id: Uuid, media_id: Uuid) -> Result<()>;
+    // Upload ảnh làm avatar, avatar_url được ký lại mỗi lần đọc user
+    async fn upload_avatar(&self, caller_id: Uuid, data: Vec<u8>) -> Result<Option<UserModel>>;
+    // PermissionDenied nếu có media không thuộc về caller
+    async fn check_owned(&self, caller_id: Uuid, media_ids: Vec<Uuid>) -> Result<()>;
+    fn max_upload_bytes(&self) -> usize;
+}
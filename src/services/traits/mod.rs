@@ -1,7 +1,18 @@
+pub mod api_token_trait;
+pub mod audit_trait;
+pub mod change_request_trait;
+pub mod comment_trait;
+pub mod difficulty_trait;
+pub mod media_trait;
+pub mod note_trait;
+pub mod oidc_trait;
+pub mod ownership_trait;
 pub mod prelude;
 pub mod qna_trait;
 pub mod quiz_question_trait;
 pub mod quiz_trait;
 pub mod set_trait;
+pub mod tag_trait;
 pub mod test_trait;
+pub mod trash_trait;
 pub mod user_trait;
@@ -0,0 +1,39 @@
+use {
+    crate::{
+        entities::notes,
+        enums::{error::*, generic::PaginatedResponse},
+        models::note::{NoteTarget, QueryNoteParams},
+    },
+    async_trait::async_trait,
+    uuid::Uuid,
+};
+
+// Ghi chú riêng của từng user, giữ qua các bài test
+#[async_trait]
+pub trait NoteService: Send + Sync {
+    async fn get_all(
+        &self,
+        caller_id: Uuid,
+        params: QueryNoteParams,
+    ) -> Result<PaginatedResponse<notes::Model>>;
+
+    async fn get_one(&self, caller_id: Uuid, target: NoteTarget) -> Result<notes::Model>;
+
+    // Chỉ ghi chú được câu hỏi mà user xem được
+    async fn save_question_note(
+        &self,
+        caller_id: Uuid,
+        question_id: Uuid,
+        content: String,
+    ) -> Result<notes::Model>;
+
+    async fn save_quiz_question_note(
+        &self,
+        caller_id: Uuid,
+        quiz_id: Uuid,
+        quiz_question_id: Uuid,
+        content: String,
+    ) -> Result<notes::Model>;
+
+    async fn delete(&self, caller_id: Uuid, target: NoteTarget) -> Result<()>;
+}
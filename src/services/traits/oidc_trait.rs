@@ -0,0 +1,12 @@
+use {
+    crate::{enums::error::*, models::user::LoginResponse},
+    async_trait::async_trait,
+};
+
+#[async_trait]
+pub trait OidcService: Send + Sync {
+    // Trả về url để web client redirect sang identity provider
+    async fn authorize(&self) -> Result<String>;
+    // Đổi `code` lấy ID token, liên kết hoặc tạo user rồi login
+    async fn callback(&self, code: String, state: String) -> Result<LoginResponse>;
+}
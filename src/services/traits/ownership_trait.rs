@@ -0,0 +1,46 @@
+use {
+    crate::{
+        entities::ownership_transfers,
+        enums::{error::*, generic::PaginatedResponse},
+        models::{
+            audit::AuditResource,
+            ownership::{QueryTransferParams, TransferAllResponse, TransferOwnershipRequest},
+        },
+    },
+    async_trait::async_trait,
+    uuid::Uuid,
+};
+
+// Chuyển quyền sở hữu set/quiz, do chủ hiện tại hoặc Staff thực hiện
+#[async_trait]
+pub trait OwnershipService: Send + Sync {
+    async fn transfer_set(
+        &self,
+        caller_id: Uuid,
+        set_id: Uuid,
+        payload: TransferOwnershipRequest,
+    ) -> Result<ownership_transfers::Model>;
+
+    async fn transfer_quiz(
+        &self,
+        caller_id: Uuid,
+        quiz_id: Uuid,
+        payload: TransferOwnershipRequest,
+    ) -> Result<ownership_transfers::Model>;
+
+    // Chuyển toàn bộ set và quiz của một user, kể cả trong thùng rác
+    async fn transfer_all(
+        &self,
+        caller_id: Uuid,
+        from_user_id: Uuid,
+        payload: TransferOwnershipRequest,
+    ) -> Result<TransferAllResponse>;
+
+    async fn get_history(
+        &self,
+        caller_id: Uuid,
+        resource: AuditResource,
+        resource_id: Uuid,
+        params: QueryTransferParams,
+    ) -> Result<PaginatedResponse<ownership_transfers::Model>>;
+}
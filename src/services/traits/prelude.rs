@@ -1,4 +1,9 @@
 pub use super::{
-    qna_trait::QnAService, quiz_question_trait::QuizQuestionService, quiz_trait::QuizService,
-    set_trait::SetService, test_trait::TestService, user_trait::UserService,
+    api_token_trait::ApiTokenService, audit_trait::AuditService,
+    change_request_trait::ChangeRequestService, comment_trait::CommentService,
+    difficulty_trait::DifficultyService, media_trait::MediaService, note_trait::NoteService,
+    oidc_trait::OidcService, ownership_trait::OwnershipService, qna_trait::QnAService,
+    quiz_question_trait::QuizQuestionService, quiz_trait::QuizService, set_trait::SetService,
+    tag_trait::TagService, test_trait::TestService, trash_trait::TrashService,
+    user_trait::UserService,
 };
@@ -1,8 +1,14 @@
 use {
     crate::{
-        entities::questions,
+        entities::{questions, sea_orm_active_enums::QuestionTypeEnum, tags},
         enums::{error::*, generic::PaginatedResponse},
-        models::qna::{CreateQnARequest, QueryQuestionParams, UpdateQuestionRequest},
+        models::{
+            difficulty::DifficultyRange,
+            qna::{
+                CreateQnARequest, QueryQuestionParams, QueryRevisionParams,
+                QuestionRevisionResponse, UpdateQuestionRequest,
+            },
+        },
     },
     async_trait::async_trait,
     uuid::Uuid,
@@ -13,6 +19,14 @@ pub trait QnAService: Sync + Send {
     // Create a question and add it to a set
     async fn create(&self, caller_id: Uuid, payload: CreateQnARequest) -> Result<questions::Model>;
 
+    // Kiểm tra quyền sửa và chuẩn hoá payload, trả kèm question trước khi sửa
+    async fn prepare_update(
+        &self,
+        caller_id: Uuid,
+        qna_id: Uuid,
+        payload: UpdateQuestionRequest,
+    ) -> Result<(questions::Model, UpdateQuestionRequest)>;
+
     // Update information of a question and all answers of that question
     async fn update(
         &self,
@@ -21,6 +35,22 @@ pub trait QnAService: Sync + Send {
         payload: UpdateQuestionRequest,
     ) -> Result<Option<questions::Model>>;
 
+    // Lịch sử sửa của question, ai xem được question thì xem được
+    async fn get_revisions(
+        &self,
+        caller_id: Uuid,
+        qna_id: Uuid,
+        params: QueryRevisionParams,
+    ) -> Result<PaginatedResponse<QuestionRevisionResponse>>;
+
+    // Khôi phục một revision cũ thành revision mới, cần quyền sửa question
+    async fn rollback(
+        &self,
+        caller_id: Uuid,
+        qna_id: Uuid,
+        revision_id: Uuid,
+    ) -> Result<questions::Model>;
+
     // Delete a question
     async fn delete(&self, caller_id: Uuid, qna_id: Uuid) -> Result<()>;
 
@@ -41,4 +71,24 @@ pub trait QnAService: Sync + Send {
         set_id: Uuid,
         params: QueryQuestionParams,
     ) -> Result<PaginatedResponse<questions::Model>>;
+
+    async fn get_tags(&self, caller_id: Uuid, qna_id: Uuid) -> Result<Vec<tags::Model>>;
+
+    // Thay toàn bộ tag của question, cần quyền sửa question
+    async fn set_tags(
+        &self,
+        caller_id: Uuid,
+        qna_id: Uuid,
+        tag_ids: Vec<Uuid>,
+    ) -> Result<Vec<tags::Model>>;
+
+    // Chọn ngẫu nhiên question gắn tag trong các set caller xem được
+    async fn get_random_by_tags(
+        &self,
+        caller_id: Uuid,
+        tag_ids: Vec<Uuid>,
+        r#type: Option<QuestionTypeEnum>,
+        difficulty: DifficultyRange,
+        count: u64,
+    ) -> Result<Vec<Uuid>>;
 }
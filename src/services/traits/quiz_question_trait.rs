@@ -2,7 +2,8 @@ use {
     crate::{
         enums::error::*,
         models::quiz_question::{
-            CreateQuizQuestionFromQuestion, CreateQuizQuestionRequest, QuizQuestionResponse,
+            CreateQuizQuestionFromMistakes, CreateQuizQuestionFromQuestion,
+            CreateQuizQuestionFromTags, CreateQuizQuestionRequest, QuizQuestionResponse,
             UpdateQuizQuestionRequest,
         },
     },
@@ -25,6 +26,20 @@ pub trait QuizQuestionService: Sync + Send {
         payload: CreateQuizQuestionFromQuestion,
     ) -> Result<Vec<QuizQuestionResponse>>;
 
+    // Lấy ngẫu nhiên câu hỏi theo tag rồi thêm như create_from_question
+    async fn create_from_tags(
+        &self,
+        caller_id: Uuid,
+        payload: CreateQuizQuestionFromTags,
+    ) -> Result<Vec<QuizQuestionResponse>>;
+
+    // Chép các câu người gọi làm sai gần đây vào quiz của họ để ôn lại
+    async fn create_from_mistakes(
+        &self,
+        caller_id: Uuid,
+        payload: CreateQuizQuestionFromMistakes,
+    ) -> Result<Vec<QuizQuestionResponse>>;
+
     async fn update(
         &self,
         caller_id: Uuid,
@@ -1,10 +1,10 @@
 use {
     crate::{
-        entities::{sets, shared_sets},
+        entities::{sets, shared_sets, tags},
         enums::error::*,
         models::set::{
-            AllSetsOfUserResponse, CreateSetRequest, ShareSetForUser, SharedPermission,
-            UpdateSetRequest,
+            AllSetsOfUserResponse, CreateSetRequest, InviteToSetRequest, ShareSetForUser,
+            SharedPermission, UpdateSetRequest,
         },
     },
     async_trait::async_trait,
@@ -46,6 +46,16 @@ pub trait SetService: Send + Sync {
         payload: Vec<ShareSetForUser>,
     ) -> Result<Vec<shared_sets::Model>>;
 
+    // Invite someone by email, the set is shared once they accept
+    async fn invite(
+        &self,
+        caller_id: Uuid,
+        set_id: Uuid,
+        payload: InviteToSetRequest,
+    ) -> Result<()>;
+
+    async fn accept_invite(&self, caller_id: Uuid, token: String) -> Result<shared_sets::Model>;
+
     async fn check_share_permission(
         &self,
         set_id: Uuid,
@@ -54,4 +64,15 @@ pub trait SetService: Send + Sync {
     ) -> Result<bool>;
 
     async fn is_creator(&self, set_id: Uuid, caller_id: Uuid) -> Result<bool>;
+
+    // Get tags of a set the caller can view
+    async fn get_tags(&self, caller_id: Uuid, set_id: Uuid) -> Result<Vec<tags::Model>>;
+
+    // Replace all tags of a set, caller must be creator or shared with edit
+    async fn set_tags(
+        &self,
+        caller_id: Uuid,
+        set_id: Uuid,
+        tag_ids: Vec<Uuid>,
+    ) -> Result<Vec<tags::Model>>;
 }
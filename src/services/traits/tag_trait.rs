@@ -0,0 +1,52 @@
+use {
+    crate::{
+        entities::{sea_orm_active_enums::QuestionTypeEnum, tags},
+        enums::error::*,
+        models::{
+            difficulty::DifficultyRange,
+            tag::{CreateTagRequest, TagAccuracy, UpdateTagRequest},
+        },
+    },
+    async_trait::async_trait,
+    uuid::Uuid,
+};
+
+// Tag dùng chung cho mọi user, `parent_id` tạo thành cây chủ đề
+#[async_trait]
+pub trait TagService: Send + Sync {
+    async fn get_all(&self) -> Result<Vec<tags::Model>>;
+
+    async fn create(&self, caller_id: Uuid, payload: CreateTagRequest) -> Result<tags::Model>;
+
+    // Không cho chuyển tag vào chính cây con của nó
+    async fn update(&self, tag_id: Uuid, payload: UpdateTagRequest) -> Result<tags::Model>;
+
+    // Tag con được chuyển lên tag cha của tag bị xoá
+    async fn delete(&self, tag_id: Uuid) -> Result<()>;
+
+    // Các hàm gắn tag dưới đây không kiểm tra quyền, service gọi tự kiểm tra
+    async fn get_question_tags(&self, question_id: Uuid) -> Result<Vec<tags::Model>>;
+
+    async fn replace_question_tags(
+        &self,
+        question_id: Uuid,
+        tag_ids: Vec<Uuid>,
+    ) -> Result<Vec<tags::Model>>;
+
+    async fn get_set_tags(&self, set_id: Uuid) -> Result<Vec<tags::Model>>;
+
+    async fn replace_set_tags(&self, set_id: Uuid, tag_ids: Vec<Uuid>) -> Result<Vec<tags::Model>>;
+
+    // Câu hỏi ngẫu nhiên gắn tag (tính cả tag con) trong các set đã cho
+    async fn random_question_ids(
+        &self,
+        tag_ids: Vec<Uuid>,
+        set_ids: Vec<Uuid>,
+        r#type: Option<QuestionTypeEnum>,
+        difficulty: DifficultyRange,
+        count: u64,
+    ) -> Result<Vec<Uuid>>;
+
+    // Tỉ lệ đúng theo tag trên các bài test đã nộp của caller
+    async fn accuracy(&self, caller_id: Uuid) -> Result<Vec<TagAccuracy>>;
+}
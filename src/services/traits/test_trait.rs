@@ -1,10 +1,10 @@
 use {
     crate::{
-        entities::{test_question_results, tests},
+        entities::{test_adaptive_steps, test_question_results, tests},
         enums::{error::*, generic::PaginatedResponse},
         models::test::{
-            CreateTest, QueryTestParams, ResolveTestRequest, ResultResponse, SolutionResponse,
-            TestingQuestion,
+            CreateTest, QueryTestParams, ResolveTestRequest, ResolveTestResponse, ResultResponse,
+            SolutionResponse, TestingQuestion,
         },
     },
     uuid::Uuid,
@@ -50,7 +50,9 @@ pub trait TestService: Sync + Send {
         test_id: Uuid,
         quiz_question_id: Uuid,
         payloads: ResolveTestRequest,
-    ) -> Result<Option<tests::Model>>;
+    ) -> Result<ResolveTestResponse>;
+    // Cấp session mới cho thiết bị hiện tại, các thiết bị khác sẽ bị conflict
+    async fn take_over_session(&self, caller_id: Uuid, test_id: Uuid) -> Result<tests::Model>;
     async fn submit_one(
         &self,
         caller_id: Uuid,
@@ -63,4 +65,21 @@ pub trait TestService: Sync + Send {
         test_id: Uuid,
         quiz_question_id: Uuid,
     ) -> Result<SolutionResponse>;
+    // Các câu đã hỏi của bài test thích ứng cùng năng lực ước lượng sau mỗi câu,
+    // chỉ xem được sau khi nộp bài
+    async fn get_adaptive_path(
+        &self,
+        caller_id: Uuid,
+        test_id: Uuid,
+    ) -> Result<Vec<test_adaptive_steps::Model>>;
+
+    // Đánh dấu/bỏ đánh dấu câu của bài test, trạng thái trả về trong danh sách
+    // trạng thái câu hỏi
+    async fn flag_question(
+        &self,
+        caller_id: Uuid,
+        test_id: Uuid,
+        quiz_question_id: Uuid,
+        is_flagged: bool,
+    ) -> Result<test_question_results::Model>;
 }
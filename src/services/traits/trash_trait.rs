@@ -0,0 +1,26 @@
+use {
+    crate::{
+        entities::{questions, quizes, sets},
+        enums::error::*,
+        models::trash::{PurgeReport, TrashResponse},
+    },
+    async_trait::async_trait,
+    uuid::Uuid,
+};
+
+#[async_trait]
+pub trait TrashService: Send + Sync {
+    // Set, câu hỏi, quiz đã xoá mềm của caller
+    async fn get_all(&self, caller_id: Uuid) -> Result<TrashResponse>;
+    // Khôi phục kèm các câu hỏi bị xoá cùng set
+    async fn restore_set(&self, caller_id: Uuid, set_id: Uuid) -> Result<sets::Model>;
+    async fn restore_question(
+        &self,
+        caller_id: Uuid,
+        question_id: Uuid,
+    ) -> Result<questions::Model>;
+    // Khôi phục kèm các câu hỏi bị xoá cùng quiz
+    async fn restore_quiz(&self, caller_id: Uuid, quiz_id: Uuid) -> Result<quizes::Model>;
+    // Xoá hẳn nội dung quá hạn giữ trong thùng rác
+    async fn purge_expired(&self) -> Result<PurgeReport>;
+}
@@ -2,7 +2,10 @@ use {
     crate::{
         entities::{sea_orm_active_enums::RoleEnum, users},
         enums::error::*,
-        models::user::{LoginRequest, RegisterUserRequest, UpdateUserRequest, UserModel},
+        models::user::{
+            LoginRequest, LoginResponse, RegisterUserRequest, TotpSetupResponse, UpdateUserRequest,
+            UserModel,
+        },
     },
     async_trait::async_trait,
     uuid::Uuid,
@@ -16,7 +19,12 @@ pub trait UserService: Sync + Send {
 
     async fn register_user(&self, payload: RegisterUserRequest) -> Result<UserModel>;
 
-    async fn login(&self, payload: LoginRequest) -> Result<String>;
+    async fn login(&self, payload: LoginRequest) -> Result<LoginResponse>;
+    // bước 2 của login khi user bật hoặc bắt buộc TOTP
+    async fn login_totp(&self, challenge_token: String, code: String) -> Result<LoginResponse>;
+    async fn login_totp_setup(&self, challenge_token: String) -> Result<TotpSetupResponse>;
+    // hoàn tất login cho user đã được identity provider xác thực
+    async fn login_external(&self, user_id: Uuid) -> Result<LoginResponse>;
 
     async fn update_self(
         &self,
@@ -24,6 +32,9 @@ pub trait UserService: Sync + Send {
         payload: UpdateUserRequest,
     ) -> Result<Option<UserModel>>;
 
+    // Gán media đã upload làm avatar, thay cho avatar_url cũ
+    async fn set_avatar_media(&self, caller_id: Uuid, media_id: Uuid) -> Result<UserModel>;
+
     async fn update_password(
         &self,
         caller_id: Uuid,
@@ -39,7 +50,25 @@ pub trait UserService: Sync + Send {
         role: RoleEnum,
     ) -> Result<Option<UserModel>>;
 
-    async fn delete(&self, user_id: Uuid) -> Result<()>;
+    // staff bootstrap / invite
+    async fn create_staff(&self, payload: RegisterUserRequest) -> Result<UserModel>;
+    async fn invite_staff(&self, caller_id: Uuid, email: String) -> Result<()>;
+    async fn accept_staff_invite(&self, caller_id: Uuid, token: String) -> Result<UserModel>;
+
+    async fn delete(&self, caller_id: Uuid, user_id: Uuid) -> Result<()>;
+
+    // two-factor auth
+    async fn setup_totp(&self, caller_id: Uuid) -> Result<TotpSetupResponse>;
+    async fn activate_totp(&self, caller_id: Uuid, code: String) -> Result<Vec<String>>;
+    async fn disable_totp(&self, caller_id: Uuid, code: String) -> Result<()>;
+    // only admin role
+    async fn reset_totp(&self, caller_id: Uuid, user_id: Uuid) -> Result<()>;
+
+    // email verification
+    async fn verify_email(&self, token: String) -> Result<UserModel>;
+    async fn resend_verification(&self, email: String) -> Result<()>;
 
     // forget password
+    async fn forgot_password(&self, email: String) -> Result<()>;
+    async fn reset_password(&self, token: String, new_password: String) -> Result<()>;
 }
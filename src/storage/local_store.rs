@@ -0,0 +1,85 @@
+use {
+    super::BlobStore,
+    crate::enums::error::*,
+    async_trait::async_trait,
+    std::{io::ErrorKind, path::PathBuf},
+    tokio::fs,
+};
+
+// Lưu mỗi blob thành một file `dir/<key>`
+pub struct LocalBlobStore {
+    dir: PathBuf,
+}
+
+impl LocalBlobStore {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    fn path_of(&self, key: &str) -> Result<PathBuf> {
+        // key do server sinh, chặn thêm để không ghi ra ngoài `dir`
+        if key.is_empty() || key.split('/').any(|part| part.is_empty() || part == "..") {
+            return Err(Error::FileTypeInvalid);
+        }
+
+        Ok(self.dir.join(key))
+    }
+}
+
+#[async_trait]
+impl BlobStore for LocalBlobStore {
+    async fn put(&self, key: &str, _content_type: &str, data: Vec<u8>) -> Result<()> {
+        let path = self.path_of(key)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .await
+                .map_err(|_| Error::CreateFileFailed)?;
+        }
+
+        fs::write(&path, data)
+            .await
+            .map_err(|_| Error::CreateFileFailed)
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>> {
+        match fs::read(self.path_of(key)?).await {
+            Ok(data) => Ok(data),
+            Err(e) if e.kind() == ErrorKind::NotFound => Err(Error::RecordNotFound),
+            Err(e) => Err(Error::Anyhow(e.into())),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        match fs::remove_file(self.path_of(key)?).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(Error::Anyhow(e.into())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_local_store_round_trip() {
+        let dir = std::env::temp_dir().join(format!("flashcard_media_{}", uuid::Uuid::new_v4()));
+        let store = LocalBlobStore::new(dir.clone());
+
+        store
+            .put("image/a.png", "image/png", b"png".to_vec())
+            .await
+            .unwrap();
+        assert_eq!(store.get("image/a.png").await.unwrap(), b"png");
+
+        store.delete("image/a.png").await.unwrap();
+        assert!(matches!(
+            store.get("image/a.png").await,
+            Err(Error::RecordNotFound)
+        ));
+        assert!(store.get("../secret").await.is_err());
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,80 @@
+use {
+    crate::{
+        config::{StorageConfig, StorageKind},
+        enums::error::*,
+    },
+    async_trait::async_trait,
+    local_store::LocalBlobStore,
+    s3_store::S3BlobStore,
+    std::sync::Arc,
+};
+
+pub mod local_store;
+pub mod s3_store;
+
+// Nơi lưu nội dung file upload, metadata nằm trong bảng media
+#[async_trait]
+pub trait BlobStore: Send + Sync {
+    async fn put(&self, key: &str, content_type: &str, data: Vec<u8>) -> Result<()>;
+    async fn get(&self, key: &str) -> Result<Vec<u8>>;
+    async fn delete(&self, key: &str) -> Result<()>;
+}
+
+pub fn init_blob_store(cfg: &StorageConfig) -> Result<Arc<dyn BlobStore>> {
+    match cfg.kind {
+        StorageKind::Local => Ok(Arc::new(LocalBlobStore::new(cfg.dir.clone()))),
+        StorageKind::S3 => {
+            let s3 = cfg
+                .s3
+                .as_ref()
+                .ok_or(Error::FieldNotFound("storage.s3".into()))?;
+            Ok(Arc::new(S3BlobStore::try_new(s3)?))
+        }
+    }
+}
+
+// Đoán content type từ magic bytes, không tin header client gửi lên
+pub fn sniff_content_type(data: &[u8]) -> Option<&'static str> {
+    let starts = |magic: &[u8]| data.starts_with(magic);
+    let at = |offset: usize, magic: &[u8]| data.get(offset..offset + magic.len()) == Some(magic);
+
+    if starts(b"\x89PNG\r\n\x1a\n") {
+        Some("image/png")
+    } else if starts(b"\xff\xd8\xff") {
+        Some("image/jpeg")
+    } else if starts(b"GIF87a") || starts(b"GIF89a") {
+        Some("image/gif")
+    } else if starts(b"RIFF") && at(8, b"WEBP") {
+        Some("image/webp")
+    } else if starts(b"RIFF") && at(8, b"WAVE") {
+        Some("audio/wav")
+    } else if starts(b"OggS") {
+        Some("audio/ogg")
+    } else if starts(b"ID3") || data.len() > 1 && data[0] == 0xff && data[1] & 0xe0 == 0xe0 {
+        Some("audio/mpeg")
+    } else if at(4, b"ftypM4A") {
+        Some("audio/mp4")
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sniff_known_and_unknown_files() {
+        assert_eq!(
+            sniff_content_type(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR"),
+            Some("image/png")
+        );
+        assert_eq!(
+            sniff_content_type(b"RIFF\x24\0\0\0WAVEfmt "),
+            Some("audio/wav")
+        );
+        assert_eq!(sniff_content_type(b"ID3\x04\0"), Some("audio/mpeg"));
+        assert_eq!(sniff_content_type(b"<svg onload=alert(1)>"), None);
+        assert_eq!(sniff_content_type(b""), None);
+    }
+}
@@ -0,0 +1,313 @@
+use {
+    super::BlobStore,
+    crate::{config::S3Config, enums::error::*, utils::helpers::hmac_sha256},
+    async_trait::async_trait,
+    chrono::Utc,
+    reqwest::{Client, Method, StatusCode, Url},
+    sha2::{Digest, Sha256},
+};
+
+// S3 hoặc dịch vụ tương thích (MinIO, R2, ...), dùng path-style
+// `endpoint/bucket/key` và ký request bằng SigV4
+pub struct S3BlobStore {
+    client: Client,
+    endpoint: Url,
+    bucket: String,
+    region: String,
+    access_key: String,
+    secret_key: String,
+}
+
+pub struct SigningInput<'a> {
+    pub method: &'a str,
+    pub path: &'a str,
+    pub host: &'a str,
+    pub payload_hash: &'a str,
+    pub amz_date: &'a str,
+}
+
+// Chữ ký SigV4 cho request không có query string, chỉ ký host và các header
+// x-amz-*
+pub fn sign_v4(input: &SigningInput, region: &str, secret_key: &str) -> Result<String> {
+    let canonical_request = format!(
+        "{}\n{}\n\nhost:{}\nx-amz-content-sha256:{}\nx-amz-date:{}\n\n{SIGNED_HEADERS}\n{}",
+        input.method,
+        input.path,
+        input.host,
+        input.payload_hash,
+        input.amz_date,
+        input.payload_hash
+    );
+    let date = input.amz_date.get(..8).ok_or(Error::InvalidToken)?;
+    let scope = format!("{date}/{region}/s3/aws4_request");
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{}\n{scope}\n{}",
+        input.amz_date,
+        hex::encode(Sha256::digest(canonical_request.as_bytes()))
+    );
+
+    let mut key = hmac_sha256(format!("AWS4{secret_key}").as_bytes(), date.as_bytes())?;
+    for part in [region, "s3", "aws4_request"] {
+        key = hmac_sha256(&key, part.as_bytes())?;
+    }
+
+    Ok(hex::encode(hmac_sha256(&key, string_to_sign.as_bytes())?))
+}
+
+const SIGNED_HEADERS: &str = "host;x-amz-content-sha256;x-amz-date";
+
+// Encode từng segment của key theo quy tắc URI của SigV4, giữ nguyên `/`
+fn encode_key(key: &str) -> String {
+    key.bytes()
+        .map(|b| {
+            match b {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => {
+                    (b as char).to_string()
+                }
+                _ => format!("%{b:02X}"),
+            }
+        })
+        .collect()
+}
+
+impl S3BlobStore {
+    pub fn try_new(cfg: &S3Config) -> Result<Self> {
+        let endpoint = Url::parse(&cfg.endpoint)
+            .map_err(|_| Error::FieldNotFound("storage.s3.endpoint".into()))?;
+
+        Ok(Self {
+            client: Client::new(),
+            endpoint,
+            bucket: cfg.bucket.clone(),
+            region: cfg.region.clone(),
+            access_key: cfg.access_key.clone(),
+            secret_key: cfg.secret_key.clone(),
+        })
+    }
+
+    async fn send(
+        &self,
+        method: Method,
+        key: &str,
+        content_type: Option<&str>,
+        body: Vec<u8>,
+    ) -> Result<reqwest::Response> {
+        let path = format!(
+            "{}/{}/{}",
+            self.endpoint.path().trim_end_matches('/'),
+            self.bucket,
+            encode_key(key)
+        );
+        let mut url = self.endpoint.clone();
+        url.set_path(&path);
+
+        let host = match url.port() {
+            Some(port) => format!("{}:{port}", url.host_str().unwrap_or_default()),
+            None => url.host_str().unwrap_or_default().to_string(),
+        };
+        let payload_hash = hex::encode(Sha256::digest(&body));
+        let amz_date = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+        let signature = sign_v4(
+            &SigningInput {
+                method: method.as_str(),
+                path: &path,
+                host: &host,
+                payload_hash: &payload_hash,
+                amz_date: &amz_date,
+            },
+            &self.region,
+            &self.secret_key,
+        )?;
+        let authorization = format!(
+            "AWS4-HMAC-SHA256 Credential={}/{}/{}/s3/aws4_request, SignedHeaders={SIGNED_HEADERS}, Signature={signature}",
+            self.access_key,
+            &amz_date[..8],
+            self.region
+        );
+
+        let mut req = self.client.request(method, url);
+        if let Some(content_type) = content_type {
+            req = req.header("content-type", content_type);
+        }
+
+        req.header("x-amz-content-sha256", payload_hash)
+            .header("x-amz-date", amz_date)
+            .header("authorization", authorization)
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| Error::Anyhow(e.into()))
+    }
+}
+
+#[async_trait]
+impl BlobStore for S3BlobStore {
+    async fn put(&self, key: &str, content_type: &str, data: Vec<u8>) -> Result<()> {
+        let res = self
+            .send(Method::PUT, key, Some(content_type), data)
+            .await?;
+        if !res.status().is_success() {
+            return Err(Error::CreateFileFailed);
+        }
+
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>> {
+        let res = self.send(Method::GET, key, None, vec![]).await?;
+        match res.status() {
+            StatusCode::NOT_FOUND => Err(Error::RecordNotFound),
+            status if !status.is_success() => {
+                Err(Error::Anyhow(anyhow::anyhow!(
+                    "s3 get {key} returned {status}"
+                )))
+            }
+            _ => {
+                Ok(res
+                    .bytes()
+                    .await
+                    .map_err(|e| Error::Anyhow(e.into()))?
+                    .to_vec())
+            }
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        let res = self.send(Method::DELETE, key, None, vec![]).await?;
+        let status = res.status();
+        if !status.is_success() && status != StatusCode::NOT_FOUND {
+            return Err(Error::Anyhow(anyhow::anyhow!(
+                "s3 delete {key} returned {status}"
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        axum::{
+            body::Bytes,
+            extract::{Path, State},
+            http::{HeaderMap, Method as HttpMethod, StatusCode as HttpStatus, Uri},
+            routing::any,
+            Router,
+        },
+        std::{
+            collections::HashMap,
+            sync::{Arc, Mutex},
+        },
+    };
+
+    const SECRET: &str = "minio-secret";
+
+    type Objects = Arc<Mutex<HashMap<String, Vec<u8>>>>;
+
+    // Giả lập MinIO: kiểm tra chữ ký SigV4 rồi lưu object trong bộ nhớ
+    async fn fake_s3(
+        State(objects): State<Objects>,
+        Path((bucket, key)): Path<(String, String)>,
+        method: HttpMethod,
+        uri: Uri,
+        headers: HeaderMap,
+        body: Bytes,
+    ) -> (HttpStatus, Vec<u8>) {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or_default()
+                .to_string()
+        };
+        let payload_hash = header("x-amz-content-sha256");
+        let expected = sign_v4(
+            &SigningInput {
+                method: method.as_str(),
+                path: uri.path(),
+                host: &header("host"),
+                payload_hash: &payload_hash,
+                amz_date: &header("x-amz-date"),
+            },
+            "us-east-1",
+            SECRET,
+        )
+        .unwrap();
+        if bucket != "media"
+            || payload_hash != hex::encode(Sha256::digest(&body))
+            || !header("authorization").ends_with(&format!("Signature={expected}"))
+        {
+            return (HttpStatus::FORBIDDEN, vec![]);
+        }
+
+        let mut objects = objects.lock().unwrap();
+        match method {
+            HttpMethod::PUT => {
+                objects.insert(key, body.to_vec());
+                (HttpStatus::OK, vec![])
+            }
+            HttpMethod::GET => {
+                match objects.get(&key) {
+                    Some(data) => (HttpStatus::OK, data.clone()),
+                    None => (HttpStatus::NOT_FOUND, vec![]),
+                }
+            }
+            HttpMethod::DELETE => {
+                objects.remove(&key);
+                (HttpStatus::NO_CONTENT, vec![])
+            }
+            _ => (HttpStatus::METHOD_NOT_ALLOWED, vec![]),
+        }
+    }
+
+    async fn spawn_fake_s3() -> String {
+        let app = Router::new()
+            .route("/{bucket}/{*key}", any(fake_s3))
+            .with_state(Objects::default());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        format!("http://{addr}")
+    }
+
+    fn store(endpoint: String, secret_key: &str) -> S3BlobStore {
+        S3BlobStore::try_new(&S3Config {
+            endpoint,
+            bucket: "media".into(),
+            region: "us-east-1".into(),
+            access_key: "minio".into(),
+            secret_key: secret_key.into(),
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_s3_store_round_trip() {
+        let store = store(spawn_fake_s3().await, SECRET);
+
+        store
+            .put("audio/a b.mp3", "audio/mpeg", b"ID3".to_vec())
+            .await
+            .unwrap();
+        assert_eq!(store.get("audio/a b.mp3").await.unwrap(), b"ID3");
+
+        store.delete("audio/a b.mp3").await.unwrap();
+        assert!(matches!(
+            store.get("audio/a b.mp3").await,
+            Err(Error::RecordNotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_s3_store_rejects_wrong_secret() {
+        let store = store(spawn_fake_s3().await, "wrong");
+
+        assert!(matches!(
+            store.put("image/a.png", "image/png", b"png".to_vec()).await,
+            Err(Error::CreateFileFailed)
+        ));
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{
+    entities::question_revisions,
+    models::qna::{
+        AnswerChange, AnswerChangeKind, AnswerDTO, ContentFormatChange, QuestionDiff, TextChange,
+    },
+};
+
+// `answers` json của câu hỏi, revision, đề xuất
+pub fn parse_answers(answers: &serde_json::Value) -> Vec<AnswerDTO> {
+    serde_json::from_value(answers.clone()).unwrap_or_default()
+}
+
+// Ghép đáp án cũ và mới theo nội dung (bỏ khoảng trắng hai đầu), giữ thứ tự
+// của đáp án mới, các đáp án cũ bị bỏ nằm cuối
+pub fn diff_answers(before: &[AnswerDTO], after: &[AnswerDTO]) -> Vec<AnswerChange> {
+    let mut unmatched = before.iter().collect::<Vec<_>>();
+
+    let mut changes = after
+        .iter()
+        .map(|answer| {
+            match unmatched
+                .iter()
+                .position(|old| old.content.trim() == answer.content.trim())
+            {
+                Some(idx) => {
+                    let old = unmatched.remove(idx);
+                    let kind =
+                        if old.is_answer == answer.is_answer && old.media_id == answer.media_id {
+                            AnswerChangeKind::Unchanged
+                        } else {
+                            AnswerChangeKind::Updated
+                        };
+                    AnswerChange {
+                        kind,
+                        before: Some(old.clone()),
+                        after: Some(answer.clone()),
+                    }
+                }
+                None => {
+                    AnswerChange {
+                        kind: AnswerChangeKind::Added,
+                        before: None,
+                        after: Some(answer.clone()),
+                    }
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    changes.extend(unmatched.into_iter().map(|old| {
+        AnswerChange {
+            kind: AnswerChangeKind::Removed,
+            before: Some(old.clone()),
+            after: None,
+        }
+    }));
+
+    changes
+}
+
+// Diff giữa hai revision của câu hỏi
+pub fn diff_revisions(
+    before: &question_revisions::Model,
+    after: &question_revisions::Model,
+) -> QuestionDiff {
+    let before_answers = parse_answers(&before.answers);
+    let after_answers = parse_answers(&after.answers);
+
+    QuestionDiff {
+        content: (before.content != after.content).then(|| {
+            TextChange {
+                before: before.content.clone(),
+                after: after.content.clone(),
+            }
+        }),
+        content_format: (before.content_format != after.content_format).then_some(
+            ContentFormatChange {
+                before: before.content_format,
+                after: after.content_format,
+            },
+        ),
+        answers: (before_answers != after_answers)
+            .then(|| diff_answers(&before_answers, &after_answers)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answer(content: &str, is_answer: bool) -> AnswerDTO {
+        AnswerDTO {
+            content: content.to_string(),
+            is_answer,
+            media_id: None,
+        }
+    }
+
+    #[test]
+    fn matches_answers_by_content() {
+        let before = vec![
+            answer("Paris", true),
+            answer("Rome", false),
+            answer("Berlin", false),
+        ];
+        let after = vec![
+            answer("Rome", true),
+            answer(" Paris ", true),
+            answer("Madrid", false),
+        ];
+
+        let kinds = diff_answers(&before, &after)
+            .into_iter()
+            .map(|c| c.kind)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            kinds,
+            vec![
+                AnswerChangeKind::Updated,
+                AnswerChangeKind::Unchanged,
+                AnswerChangeKind::Added,
+                AnswerChangeKind::Removed,
+            ]
+        );
+    }
+
+    #[test]
+    fn duplicate_contents_are_matched_once() {
+        let before = vec![answer("A", false)];
+        let after = vec![answer("A", false), answer("A", true)];
+
+        let changes = diff_answers(&before, &after);
+
+        assert_eq!(changes[0].kind, AnswerChangeKind::Unchanged);
+        assert_eq!(changes[1].kind, AnswerChangeKind::Added);
+        assert_eq!(changes.len(), 2);
+    }
+}
@@ -0,0 +1,158 @@
+use std::{collections::HashMap, hash::Hash};
+
+// Độ khó theo thang logit của mô hình Rasch (IRT 1PL): 0 là trung bình, dương
+// là khó hơn. Prior N(0, 1) cho năng lực user và độ khó giữ ước lượng hữu hạn
+// khi một câu toàn đúng hoặc toàn sai
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Estimate {
+    pub difficulty: f64,
+    // 1 - sai số chuẩn, trong khoảng (0, 1), tăng theo số lượt làm
+    pub confidence: f64,
+    pub attempts: u64,
+}
+
+fn sigmoid(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+// Cộng dồn (đạo hàm, thông tin Fisher) của log-likelihood theo từng key
+fn accumulate<K: Hash + Eq + Copy>(
+    items: impl Iterator<Item = (K, f64, f64)>,
+) -> HashMap<K, (f64, f64)> {
+    let mut res: HashMap<K, (f64, f64)> = HashMap::new();
+    for (key, residual, p) in items {
+        let entry = res.entry(key).or_default();
+        entry.0 += residual;
+        entry.1 += p * (1.0 - p);
+    }
+    res
+}
+
+// Ước lượng MAP bằng các bước Newton xen kẽ giữa năng lực user và độ khó,
+// `attempts` là (user, câu hỏi, đúng hay sai)
+pub fn calibrate<U, I>(attempts: &[(U, I, bool)], iterations: usize) -> HashMap<I, Estimate>
+where
+    U: Hash + Eq + Copy,
+    I: Hash + Eq + Copy,
+{
+    let mut ability: HashMap<U, f64> = attempts.iter().map(|(u, _, _)| (*u, 0.0)).collect();
+    let mut difficulty: HashMap<I, f64> = attempts.iter().map(|(_, i, _)| (*i, 0.0)).collect();
+    let mut information: HashMap<I, (f64, f64)> = HashMap::new();
+
+    for _ in 0..iterations.max(1) {
+        let grads = accumulate(attempts.iter().map(|(u, i, correct)| {
+            let p = sigmoid(ability[u] - difficulty[i]);
+            (*u, f64::from(u8::from(*correct)) - p, p)
+        }));
+        for (u, (grad, info)) in grads {
+            let theta = ability.entry(u).or_default();
+            *theta += (grad - *theta) / (info + 1.0);
+        }
+
+        information = accumulate(attempts.iter().map(|(u, i, correct)| {
+            let p = sigmoid(ability[u] - difficulty[i]);
+            (*i, p - f64::from(u8::from(*correct)), p)
+        }));
+        for (i, (grad, info)) in &information {
+            let b = difficulty.entry(*i).or_default();
+            *b += (grad - *b) / (info + 1.0);
+        }
+    }
+
+    let mut counts: HashMap<I, u64> = HashMap::new();
+    for (_, i, _) in attempts {
+        *counts.entry(*i).or_default() += 1;
+    }
+
+    difficulty
+        .into_iter()
+        .map(|(i, b)| {
+            let info = information.get(&i).map_or(0.0, |(_, info)| *info);
+            let estimate = Estimate {
+                difficulty: b,
+                confidence: 1.0 - 1.0 / (info + 1.0).sqrt(),
+                attempts: counts[&i],
+            };
+            (i, estimate)
+        })
+        .collect()
+}
+
+// Năng lực MAP (prior N(0, 1)) và sai số chuẩn của người làm bài từ các câu
+// đã trả lời, `responses` là (độ khó, đúng hay sai)
+pub fn estimate_ability(responses: &[(f64, bool)]) -> (f64, f64) {
+    let mut theta = 0.0;
+    let mut info = 1.0;
+
+    for _ in 0..50 {
+        let mut grad = -theta;
+        info = 1.0;
+        for (b, correct) in responses {
+            let p = sigmoid(theta - b);
+            grad += f64::from(u8::from(*correct)) - p;
+            info += p * (1.0 - p);
+        }
+
+        let step = grad / info;
+        theta += step;
+        if step.abs() < 1e-6 {
+            break;
+        }
+    }
+
+    (theta, 1.0 / info.sqrt())
+}
+
+// Với mô hình 1PL, câu có độ khó gần năng lực nhất cho nhiều thông tin nhất
+pub fn pick_next<K>(ability: f64, candidates: impl IntoIterator<Item = (K, f64)>) -> Option<K> {
+    candidates
+        .into_iter()
+        .min_by(|(_, a), (_, b)| (a - ability).abs().total_cmp(&(b - ability).abs()))
+        .map(|(key, _)| key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn harder_questions_get_higher_difficulty() {
+        let mut attempts = vec![];
+        for user in 0..20 {
+            // câu 0 gần như ai cũng đúng, câu 1 đa số sai, câu 2 toàn đúng
+            attempts.push((user, 0, user != 0));
+            attempts.push((user, 1, user < 3));
+            attempts.push((user, 2, true));
+        }
+        attempts.push((0, 3, false));
+
+        let res = calibrate(&attempts, 20);
+
+        assert!(res[&1].difficulty > res[&0].difficulty);
+        assert!(res[&2].difficulty < res[&0].difficulty);
+        assert!(res[&2].difficulty.is_finite());
+        assert_eq!(res[&1].attempts, 20);
+        // ít lượt làm thì độ tin cậy thấp
+        assert!(res[&3].confidence < res[&0].confidence);
+        assert!(res
+            .values()
+            .all(|e| e.confidence > 0.0 && e.confidence < 1.0));
+    }
+
+    #[test]
+    fn ability_follows_answers_and_next_question_matches_it() {
+        let (start, start_se) = estimate_ability(&[]);
+        assert_eq!(start, 0.0);
+        assert_eq!(start_se, 1.0);
+
+        let (strong, strong_se) = estimate_ability(&[(0.0, true), (1.0, true), (2.0, true)]);
+        let (weak, _) = estimate_ability(&[(0.0, false), (-1.0, false), (1.0, true)]);
+        assert!(strong > 0.0 && weak < 0.0);
+        assert!(strong_se < start_se);
+
+        let pool = [("easy", -2.0), ("medium", 0.3), ("hard", 2.5)];
+        assert_eq!(pick_next(0.0, pool), Some("medium"));
+        assert_eq!(pick_next(3.0, pool), Some("hard"));
+        assert_eq!(pick_next::<&str>(0.0, []), None);
+    }
+}
@@ -1,4 +1,14 @@
-use {crate::models::quiz::QuestionCounts, chrono::NaiveDateTime};
+use {
+    crate::{
+        enums::error::{Error, FieldError, Result},
+        models::quiz::QuestionCounts,
+    },
+    chrono::NaiveDateTime,
+    hmac::{Hmac, Mac},
+    serde_json::{json, Map, Value},
+    sha2::{Digest, Sha256},
+    uuid::Uuid,
+};
 
 pub fn check_test_status(
     started_at: Option<NaiveDateTime>,
@@ -18,3 +28,93 @@ pub fn check_test_status(
 pub fn total_question_count(counts: QuestionCounts) -> i32 {
     counts.check_boxes + counts.check_boxes + counts.text_fill
 }
+
+// Hash cho các secret ngẫu nhiên (recovery code, API token), không dùng cho mật
+// khẩu
+pub fn sha256_hex(value: &str) -> String {
+    Sha256::digest(value.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+// Danh sách id trong query string dạng `a,b,c`
+pub fn parse_id_list(field: &str, value: &str) -> Result<Vec<Uuid>> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(|id| {
+            Uuid::parse_str(id).map_err(|_| {
+                Error::Validation(vec![FieldError::new(
+                    field,
+                    "invalid_id",
+                    format!("'{id}' is not a valid id"),
+                )])
+            })
+        })
+        .collect()
+}
+
+pub fn hmac_sha256(key: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key)
+        .map_err(|e| Error::Anyhow(anyhow::anyhow!(e.to_string())))?;
+    mac.update(data);
+    Ok(mac.finalize().into_bytes().to_vec())
+}
+
+// Các field khác nhau giữa hai object JSON dạng `{field: {before, after}}`,
+// None khi không phải object hoặc không có gì thay đổi
+pub fn json_diff(before: &Value, after: &Value) -> Option<Value> {
+    let (Value::Object(before), Value::Object(after)) = (before, after) else {
+        return None;
+    };
+
+    let mut changes = Map::new();
+    for key in before.keys().chain(after.keys()) {
+        let old = before.get(key).unwrap_or(&Value::Null);
+        let new = after.get(key).unwrap_or(&Value::Null);
+        if old != new && !changes.contains_key(key) {
+            changes.insert(key.clone(), json!({ "before": old, "after": new }));
+        }
+    }
+
+    (!changes.is_empty()).then_some(Value::Object(changes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_diff_only_changed_fields() {
+        let before = json!({ "name": "a", "description": "x", "removed": 1 });
+        let after = json!({ "name": "b", "description": "x", "added": true });
+
+        assert_eq!(
+            json_diff(&before, &after),
+            Some(json!({
+                "name": { "before": "a", "after": "b" },
+                "removed": { "before": 1, "after": null },
+                "added": { "before": null, "after": true },
+            }))
+        );
+        assert_eq!(json_diff(&before, &before), None);
+        assert_eq!(json_diff(&json!([1]), &json!([2])), None);
+    }
+
+    #[test]
+    fn id_list_from_query_string() {
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+
+        assert_eq!(
+            parse_id_list("tag_ids", &format!("{a}, {b},")).unwrap(),
+            vec![a, b]
+        );
+        assert!(matches!(
+            parse_id_list("tag_ids", "x"),
+            Err(Error::Validation(fields)) if fields[0].field == "tag_ids"
+        ));
+    }
+}
@@ -1,43 +1,146 @@
 use {
-    crate::enums::error::{Error, Result},
+    super::jwt_keys::{JwtKeys, TokenKind},
+    crate::{
+        config::JwtConfig,
+        entities::sea_orm_active_enums::TokenPurposeEnum,
+        enums::error::{Error, Result},
+    },
     chrono::{Duration, Utc},
-    jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation},
+    once_cell::sync::OnceCell,
     serde::{Deserialize, Serialize},
+    serde_json::Value,
     std::env,
     uuid::Uuid,
 };
 
+static JWT_KEYS: OnceCell<JwtKeys> = OnceCell::new();
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Claims {
     pub id: Uuid,
     pub role: String,
+    pub jti: Uuid,
+    pub exp: usize,
+}
+
+// Claims của token dùng một lần gửi qua email (verify email, reset password,
+// invite). `jti` là id của bản ghi trong bảng user_tokens
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ActionClaims {
+    pub jti: Uuid,
+    pub sub: String,
+    pub purpose: TokenPurposeEnum,
+    pub exp: usize,
+}
+
+// Claims của token tạm sau bước nhập mật khẩu, chỉ dùng để hoàn tất login bằng
+// TOTP
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ChallengeClaims {
+    pub sub: Uuid,
+    pub challenge: String,
     pub exp: usize,
 }
 
+const TOTP_CHALLENGE: &str = "totp";
+const CHALLENGE_TTL_MINUTES: i64 = 5;
+
+// Nạp key một lần lúc khởi động, không cấu hình [jwt] thì dùng HS256 với
+// JWT_SECRET
+pub fn init_jwt_keys(cfg: Option<&JwtConfig>) -> Result<()> {
+    let keys = match cfg {
+        Some(cfg) => JwtKeys::from_config(cfg)?,
+        None => {
+            let secret =
+                env::var("JWT_SECRET").map_err(|_| Error::EnvVarNotFound("JWT_SECRET".into()))?;
+            JwtKeys::from_secret(&secret)
+        }
+    };
+
+    JWT_KEYS
+        .set(keys)
+        .map_err(|_| Error::Anyhow(anyhow::anyhow!("jwt keys are already initialized")))
+}
+
+fn jwt_keys() -> Result<&'static JwtKeys> {
+    JWT_KEYS.get().ok_or(Error::Anyhow(anyhow::anyhow!(
+        "jwt keys are not initialized"
+    )))
+}
+
+pub fn jwks() -> Result<Value> {
+    Ok(jwt_keys()?.jwks())
+}
+
 pub fn encode_jwt(user_id: Uuid, user_role: String) -> Result<String> {
+    let keys = jwt_keys()?;
     let claims = Claims {
         id: user_id,
         role: user_role,
-        exp: (Utc::now() + Duration::days(1)).timestamp() as usize,
+        jti: Uuid::new_v4(),
+        exp: (Utc::now() + keys.access_token_ttl()).timestamp() as usize,
     };
 
-    let secret = env::var("JWT_SECRET").map_err(|_| Error::EnvVarNotFound("JWT_SECRET".into()))?;
-
-    encode(
-        &Header::default(),
-        &claims,
-        &EncodingKey::from_secret(secret.as_bytes()),
-    )
-    .map_err(Error::EncodeJwtFailed)
+    keys.sign(TokenKind::Access, claims)
+        .map_err(Error::EncodeJwtFailed)
 }
 
 pub fn decode_jwt(token: String) -> Result<Claims> {
-    let secret = env::var("JWT_SECRET").map_err(|_| Error::EnvVarNotFound("JWT_SECRET".into()))?;
-    let key = DecodingKey::from_secret(secret.as_bytes());
+    jwt_keys()?
+        .verify(TokenKind::Access, &token)
+        .map_err(Error::DecodeJwtFailed)
+}
+
+pub fn encode_action_token(
+    jti: Uuid,
+    email: String,
+    purpose: TokenPurposeEnum,
+    expires_in: Duration,
+) -> Result<String> {
+    let claims = ActionClaims {
+        jti,
+        sub: email,
+        purpose,
+        exp: (Utc::now() + expires_in).timestamp() as usize,
+    };
+
+    jwt_keys()?
+        .sign(TokenKind::Action, claims)
+        .map_err(Error::EncodeJwtFailed)
+}
+
+pub fn decode_action_token(token: &str, purpose: TokenPurposeEnum) -> Result<ActionClaims> {
+    let claims: ActionClaims = jwt_keys()?
+        .verify(TokenKind::Action, token)
+        .map_err(|_| Error::InvalidToken)?;
 
-    let claims = decode(&token, &key, &Validation::default())
-        .map_err(Error::DecodeJwtFailed)?
-        .claims;
+    if claims.purpose != purpose {
+        return Err(Error::InvalidToken);
+    }
 
     Ok(claims)
 }
+
+pub fn encode_challenge_token(user_id: Uuid) -> Result<String> {
+    let claims = ChallengeClaims {
+        sub: user_id,
+        challenge: TOTP_CHALLENGE.into(),
+        exp: (Utc::now() + Duration::minutes(CHALLENGE_TTL_MINUTES)).timestamp() as usize,
+    };
+
+    jwt_keys()?
+        .sign(TokenKind::Challenge, claims)
+        .map_err(Error::EncodeJwtFailed)
+}
+
+pub fn decode_challenge_token(token: &str) -> Result<Uuid> {
+    let claims: ChallengeClaims = jwt_keys()?
+        .verify(TokenKind::Challenge, token)
+        .map_err(|_| Error::InvalidToken)?;
+
+    if claims.challenge != TOTP_CHALLENGE {
+        return Err(Error::InvalidToken);
+    }
+
+    Ok(claims.sub)
+}
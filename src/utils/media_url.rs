@@ -0,0 +1,75 @@
+use {
+    super::helpers::hmac_sha256,
+    crate::{
+        config::StorageConfig,
+        enums::error::{Error, Result},
+        models::media::SignedUrlParams,
+    },
+    chrono::Utc,
+    once_cell::sync::OnceCell,
+    uuid::Uuid,
+};
+
+struct MediaUrlSigner {
+    secret: String,
+    ttl_secs: i64,
+}
+
+static SIGNER: OnceCell<MediaUrlSigner> = OnceCell::new();
+
+// Khoá ký link tải media phải cố định giữa các lần chạy và các instance, không
+// cấu hình thì không khởi động
+pub fn init_media_url(cfg: &StorageConfig) -> Result<()> {
+    if cfg.url_secret.is_empty() {
+        return Err(Error::FieldNotFound("storage.url_secret".into()));
+    }
+
+    SIGNER
+        .set(MediaUrlSigner {
+            secret: cfg.url_secret.clone(),
+            ttl_secs: cfg.url_ttl_secs,
+        })
+        .map_err(|_| Error::Anyhow(anyhow::anyhow!("media url signer is already initialized")))
+}
+
+fn signer() -> Result<&'static MediaUrlSigner> {
+    SIGNER.get().ok_or(Error::Anyhow(anyhow::anyhow!(
+        "media url signer is not initialized"
+    )))
+}
+
+fn signature(secret: &str, media_id: Uuid, expires: i64) -> Result<String> {
+    let signature = hmac_sha256(
+        secret.as_bytes(),
+        format!("{media_id}:{expires}").as_bytes(),
+    )?;
+
+    Ok(hex::encode(signature))
+}
+
+// Link tải có hạn `storage.url_ttl_secs`, trả về cùng thời điểm hết hạn
+pub fn signed_url(media_id: Uuid) -> Result<(String, i64)> {
+    let signer = signer()?;
+    let expires = Utc::now().timestamp() + signer.ttl_secs;
+    let signature = signature(&signer.secret, media_id, expires)?;
+
+    Ok((
+        format!("/api/media/{media_id}/content?expires={expires}&signature={signature}"),
+        expires,
+    ))
+}
+
+pub fn verify(media_id: Uuid, params: &SignedUrlParams) -> Result<bool> {
+    if params.expires < Utc::now().timestamp() {
+        return Ok(false);
+    }
+
+    let expected = signature(&signer()?.secret, media_id, params.expires)?;
+    // so sánh không phụ thuộc vị trí byte khác nhau đầu tiên
+    Ok(expected.len() == params.signature.len()
+        && expected
+            .bytes()
+            .zip(params.signature.bytes())
+            .fold(0, |acc, (a, b)| acc | (a ^ b))
+            == 0)
+}
@@ -0,0 +1,117 @@
+use {
+    chrono::NaiveDateTime,
+    std::{
+        cmp::Reverse,
+        collections::{hash_map::Entry, HashMap},
+    },
+    uuid::Uuid,
+};
+
+// Một lần trả lời đã chấm của user
+#[derive(Debug, Clone, Copy)]
+pub struct AnsweredItem {
+    pub quiz_question_id: Uuid,
+    pub sample_id: Option<Uuid>,
+    pub is_correct: bool,
+    pub answered_at: NaiveDateTime,
+}
+
+// Các câu làm sai trong khoảng thời gian, mỗi câu hỏi gốc (`sample_id`, không
+// có thì chính quiz question) lấy lần sai gần nhất, bỏ các câu sau đó đã đúng
+// liên tiếp `mastery_streak` lần. `history` theo thứ tự thời gian, kết quả mới
+// nhất trước
+pub fn mistake_pool(
+    history: &[AnsweredItem],
+    from: Option<NaiveDateTime>,
+    to: Option<NaiveDateTime>,
+    mastery_streak: usize,
+) -> Vec<Uuid> {
+    // key -> (số lần đúng liên tiếp, lần sai gần nhất trong khoảng)
+    let mut items: HashMap<Uuid, (usize, Option<(NaiveDateTime, Uuid)>)> = HashMap::new();
+
+    for item in history {
+        let key = item.sample_id.unwrap_or(item.quiz_question_id);
+        let entry = match items.entry(key) {
+            Entry::Occupied(e) => e.into_mut(),
+            Entry::Vacant(e) => e.insert((0, None)),
+        };
+
+        if item.is_correct {
+            entry.0 += 1;
+        } else {
+            entry.0 = 0;
+            let in_range = from.is_none_or(|from| item.answered_at >= from)
+                && to.is_none_or(|to| item.answered_at <= to);
+            if in_range {
+                entry.1 = Some((item.answered_at, item.quiz_question_id));
+            }
+        }
+    }
+
+    let mut pool = items
+        .into_values()
+        .filter(|(streak, _)| *streak < mastery_streak)
+        .filter_map(|(_, mistake)| mistake)
+        .collect::<Vec<_>>();
+    pool.sort_by_key(|(answered_at, _)| Reverse(*answered_at));
+
+    pool.into_iter().map(|(_, id)| id).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, chrono::NaiveDate};
+
+    fn at(day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 10, day)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+    }
+
+    fn answer(
+        quiz_question_id: Uuid,
+        sample_id: Option<Uuid>,
+        is_correct: bool,
+        day: u32,
+    ) -> AnsweredItem {
+        AnsweredItem {
+            quiz_question_id,
+            sample_id,
+            is_correct,
+            answered_at: at(day),
+        }
+    }
+
+    #[test]
+    fn keeps_latest_mistake_per_sample_until_mastered() {
+        let sample = Uuid::new_v4();
+        let (first_copy, second_copy) = (Uuid::new_v4(), Uuid::new_v4());
+        let mastered = Uuid::new_v4();
+        let own = Uuid::new_v4();
+        let old = Uuid::new_v4();
+
+        let history = [
+            answer(old, None, false, 1),
+            answer(first_copy, Some(sample), false, 2),
+            answer(mastered, None, false, 3),
+            answer(own, None, false, 4),
+            answer(second_copy, Some(sample), false, 5),
+            answer(mastered, None, true, 6),
+            answer(mastered, None, true, 7),
+            answer(second_copy, Some(sample), true, 8),
+        ];
+
+        // hai bản sao của cùng câu hỏi gốc chỉ lấy một, là lần sai gần nhất
+        assert_eq!(
+            mistake_pool(&history, None, None, 2),
+            vec![second_copy, own, old]
+        );
+        // lần sai ngoài khoảng thời gian không tính
+        assert_eq!(
+            mistake_pool(&history, Some(at(2)), Some(at(4)), 2),
+            vec![own, first_copy]
+        );
+        assert_eq!(mistake_pool(&history, None, None, 1), vec![own, old]);
+    }
+}
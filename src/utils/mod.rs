@@ -1,4 +1,11 @@
+pub mod diff;
+pub mod difficulty;
 pub mod enum_to_string;
 pub mod helpers;
 pub mod jwt;
+pub mod jwt_keys;
+pub mod media_url;
+pub mod mistakes;
+pub mod rich_text;
+pub mod totp;
 pub mod validator;
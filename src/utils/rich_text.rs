@@ -0,0 +1,335 @@
+use {
+    crate::{
+        entities::sea_orm_active_enums::ContentFormatEnum,
+        enums::error::{Error, FieldError, Result},
+    },
+    ammonia::Builder,
+    once_cell::sync::Lazy,
+    pulldown_cmark::{html, Event, Options, Parser, Tag, TagEnd},
+    std::ops::Range,
+};
+
+// Các lệnh LaTeX chèn link/HTML khi client render bằng KaTeX/MathJax
+const FORBIDDEN_MATH_COMMANDS: [&str; 7] = [
+    "\\href",
+    "\\url",
+    "\\includegraphics",
+    "\\htmlClass",
+    "\\htmlId",
+    "\\htmlStyle",
+    "\\htmlData",
+];
+
+static SANITIZER: Lazy<Builder<'static>> = Lazy::new(|| {
+    let mut builder = Builder::default();
+    builder.add_allowed_classes("span", &["math", "math-inline", "math-display"]);
+    builder
+});
+
+fn markdown_options() -> Options {
+    Options::ENABLE_MATH | Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH
+}
+
+fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+fn invalid(field: &str, code: &str, message: &str) -> Error {
+    Error::Validation(vec![FieldError::new(field, code, message)])
+}
+
+// Vị trí delimiter đóng đầu tiên không bị escape bằng `\`
+fn find_closing(content: &str, from: usize, close: &str) -> Option<usize> {
+    content[from..]
+        .match_indices(close)
+        .map(|(i, _)| from + i)
+        .find(|&i| {
+            let backslashes = content[..i]
+                .bytes()
+                .rev()
+                .take_while(|b| *b == b'\\')
+                .count();
+            close.starts_with('\\') || backslashes % 2 == 0
+        })
+}
+
+// Phần thân các công thức `$..$`, `$$..$$`, `\(..\)`, `\[..\]`, lỗi khi có
+// delimiter không được đóng
+fn math_spans(content: &str) -> std::result::Result<Vec<&str>, ()> {
+    let bytes = content.as_bytes();
+    let mut spans = vec![];
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let (open_len, close) = match &bytes[i..] {
+            [b'\\', b'(', ..] => (2, "\\)"),
+            [b'\\', b'[', ..] => (2, "\\]"),
+            // ký tự đã escape, kể cả `\$`
+            [b'\\', _, ..] => {
+                i += 2;
+                continue;
+            }
+            [b'$', b'$', ..] => (2, "$$"),
+            [b'$', ..] => (1, "$"),
+            _ => {
+                i += 1;
+                continue;
+            }
+        };
+
+        let start = i + open_len;
+        let end = find_closing(content, start, close).ok_or(())?;
+        spans.push(&content[start..end]);
+        i = end + close.len();
+    }
+
+    Ok(spans)
+}
+
+fn check_math(field: &str, content: &str) -> Result<()> {
+    let spans = math_spans(content).map_err(|_| {
+        invalid(
+            field,
+            "math_unclosed",
+            "Math delimiter is not closed, escape a literal dollar sign as \\$",
+        )
+    })?;
+
+    for span in spans {
+        let mut depth = 0i32;
+        let mut chars = span.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => {
+                    chars.next();
+                }
+                '{' => depth += 1,
+                '}' => depth -= 1,
+                _ => {}
+            }
+            if depth < 0 {
+                break;
+            }
+        }
+        if depth != 0 {
+            return Err(invalid(
+                field,
+                "math_unbalanced_braces",
+                "Braces in a math expression are not balanced",
+            ));
+        }
+
+        let has_forbidden = FORBIDDEN_MATH_COMMANDS.iter().any(|cmd| {
+            span.match_indices(cmd)
+                .any(|(i, _)| !span[i + cmd.len()..].starts_with(|c: char| c.is_ascii_alphabetic()))
+        });
+        if has_forbidden {
+            return Err(invalid(
+                field,
+                "math_forbidden_command",
+                "Math expressions cannot contain links or HTML commands",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+// Vùng HTML nhúng và vùng code của markdown
+fn markdown_ranges(content: &str) -> (Vec<Range<usize>>, Vec<Range<usize>>) {
+    let mut raw_html = vec![];
+    let mut code = vec![];
+
+    for (event, range) in Parser::new_ext(content, markdown_options()).into_offset_iter() {
+        match event {
+            Event::Html(_) | Event::InlineHtml(_) => raw_html.push(range),
+            Event::Code(_) | Event::Start(Tag::CodeBlock(_)) => code.push(range),
+            _ => {}
+        }
+    }
+
+    (raw_html, code)
+}
+
+// Làm sạch nội dung trước khi lưu:
+// - Plain: giữ nguyên, client luôn hiển thị như văn bản
+// - Markdown: bỏ HTML nhúng, kiểm tra công thức ngoài vùng code
+// - Html: chỉ giữ thẻ/thuộc tính an toàn, kiểm tra công thức
+pub fn sanitize(format: ContentFormatEnum, field: &str, content: &str) -> Result<String> {
+    match format {
+        ContentFormatEnum::Plain => Ok(content.to_string()),
+        ContentFormatEnum::Markdown => {
+            // bỏ lặp đến khi hết HTML, tránh `<scr<b>ipt>` ghép lại thành `<script>`
+            let mut stripped = content.to_string();
+            loop {
+                let (raw_html, _) = markdown_ranges(&stripped);
+                if raw_html.is_empty() {
+                    break;
+                }
+                let mut next = String::with_capacity(stripped.len());
+                let mut last = 0;
+                for range in raw_html {
+                    next.push_str(&stripped[last..range.start]);
+                    last = range.end;
+                }
+                next.push_str(&stripped[last..]);
+                stripped = next;
+            }
+
+            // `$` trong code không phải công thức
+            let (_, code) = markdown_ranges(&stripped);
+            let mut masked = stripped.clone().into_bytes();
+            for range in code {
+                masked[range].fill(b' ');
+            }
+            check_math(field, &String::from_utf8_lossy(&masked))?;
+
+            Ok(stripped)
+        }
+        ContentFormatEnum::Html => {
+            let cleaned = SANITIZER.clean(content).to_string();
+            check_math(field, &cleaned)?;
+
+            Ok(cleaned)
+        }
+    }
+}
+
+// HTML an toàn để hiển thị trực tiếp, công thức giữ delimiter `\(..\)` /
+// `\[..\]` để client render bằng KaTeX/MathJax
+pub fn render_html(format: ContentFormatEnum, content: &str) -> String {
+    match format {
+        ContentFormatEnum::Plain => {
+            format!("<p>{}</p>", escape_html(content).replace('\n', "<br>\n"))
+        }
+        ContentFormatEnum::Markdown => {
+            let events = Parser::new_ext(content, markdown_options()).filter_map(|event| {
+                match event {
+                    Event::Html(_) | Event::InlineHtml(_) => None,
+                    Event::InlineMath(math) => {
+                        Some(Event::InlineHtml(
+                            format!(
+                                "<span class=\"math math-inline\">\\({}\\)</span>",
+                                escape_html(&math)
+                            )
+                            .into(),
+                        ))
+                    }
+                    Event::DisplayMath(math) => {
+                        Some(Event::InlineHtml(
+                            format!(
+                                "<span class=\"math math-display\">\\[{}\\]</span>",
+                                escape_html(&math)
+                            )
+                            .into(),
+                        ))
+                    }
+                    Event::End(TagEnd::HtmlBlock) | Event::Start(Tag::HtmlBlock) => None,
+                    event => Some(event),
+                }
+            });
+
+            let mut out = String::with_capacity(content.len() * 2);
+            html::push_html(&mut out, events);
+            SANITIZER.clean(&out).to_string()
+        }
+        ContentFormatEnum::Html => SANITIZER.clean(content).to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error_code(result: Result<String>) -> String {
+        match result {
+            Err(Error::Validation(fields)) => fields[0].code.clone(),
+            other => panic!("expected validation error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn html_is_sanitized_on_write() {
+        let res = sanitize(
+            ContentFormatEnum::Html,
+            "content",
+            "<p onclick=\"x()\">Hi <script>alert(1)</script><a href=\"javascript:x()\">a</a></p>",
+        )
+        .unwrap();
+
+        assert!(!res.contains("script"));
+        assert!(!res.contains("onclick"));
+        assert!(!res.contains("javascript"));
+        assert!(res.starts_with("<p>Hi "));
+    }
+
+    #[test]
+    fn markdown_drops_raw_html_and_keeps_math() {
+        let res = sanitize(
+            ContentFormatEnum::Markdown,
+            "content",
+            "**Solve** $x^{2} < 4$ <img src=x onerror=alert(1)>\n\n`echo $HOME`",
+        )
+        .unwrap();
+        assert_eq!(res, "**Solve** $x^{2} < 4$ \n\n`echo $HOME`");
+
+        for nested in [
+            "x <scr<b>ipt>alert(1)</scr<b>ipt>",
+            "<im<b>g src=x onerror=alert(1)>",
+        ] {
+            let res = sanitize(ContentFormatEnum::Markdown, "content", nested).unwrap();
+            assert!(!res.contains('<'), "{res}");
+        }
+
+        let html = render_html(ContentFormatEnum::Markdown, &res);
+        assert!(html.contains("<strong>Solve</strong>"));
+        assert!(html.contains("<span class=\"math math-inline\">\\(x^{2} &lt; 4\\)</span>"));
+        assert!(html.contains("<code>echo $HOME</code>"));
+    }
+
+    #[test]
+    fn invalid_math_is_rejected() {
+        assert_eq!(
+            error_code(sanitize(ContentFormatEnum::Markdown, "content", "costs $5")),
+            "math_unclosed"
+        );
+        assert_eq!(
+            error_code(sanitize(
+                ContentFormatEnum::Html,
+                "content",
+                "\\(\\frac{1}{2\\)"
+            )),
+            "math_unbalanced_braces"
+        );
+        assert_eq!(
+            error_code(sanitize(
+                ContentFormatEnum::Markdown,
+                "content",
+                "$$\\href{javascript:x()}{y}$$"
+            )),
+            "math_forbidden_command"
+        );
+        // dollar đã escape và văn bản thường không bị kiểm tra
+        assert!(sanitize(ContentFormatEnum::Markdown, "content", "costs \\$5").is_ok());
+        assert!(sanitize(ContentFormatEnum::Plain, "content", "costs $5").is_ok());
+    }
+
+    #[test]
+    fn plain_is_escaped_on_render() {
+        assert_eq!(
+            render_html(ContentFormatEnum::Plain, "<b>a</b>\nb"),
+            "<p>&lt;b&gt;a&lt;/b&gt;<br>\nb</p>"
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use {
+    super::helpers::sha256_hex,
+    crate::enums::error::{Error, Result},
+    totp_rs::{Algorithm, Secret, TOTP},
+    uuid::Uuid,
+};
+
+const DIGITS: usize = 6;
+const STEP_SECS: u64 = 30;
+// chấp nhận lệch 1 bước (±30s) giữa server và điện thoại
+const SKEW_STEPS: i64 = 1;
+const RECOVERY_CODE_COUNT: usize = 10;
+
+pub fn generate_secret() -> String {
+    Secret::generate_secret().to_encoded().to_string()
+}
+
+pub fn build_totp(secret: &str, issuer: &str, account: &str) -> Result<TOTP> {
+    let secret = Secret::Encoded(secret.to_string())
+        .to_bytes()
+        .map_err(|e| Error::Anyhow(anyhow::anyhow!("{e:?}")))?;
+
+    TOTP::new(
+        Algorithm::SHA1,
+        DIGITS,
+        1,
+        STEP_SECS,
+        secret,
+        Some(issuer.to_string()),
+        account.to_string(),
+    )
+    .map_err(|e| Error::Anyhow(anyhow::anyhow!("{e:?}")))
+}
+
+// Trả về bước thời gian khớp với code, bỏ qua các bước <= `last_step` để chống
+// dùng lại code
+pub fn verify_code(totp: &TOTP, code: &str, now_secs: u64, last_step: Option<i64>) -> Option<i64> {
+    let current = (now_secs / STEP_SECS) as i64;
+
+    (current - SKEW_STEPS..=current + SKEW_STEPS)
+        .filter(|step| *step >= 0 && last_step.is_none_or(|last| *step > last))
+        .find(|step| totp.generate(*step as u64 * STEP_SECS) == code.trim())
+}
+
+pub fn generate_recovery_codes() -> Vec<String> {
+    (0..RECOVERY_CODE_COUNT)
+        .map(|_| {
+            let raw = Uuid::new_v4().simple().to_string();
+            format!("{}-{}", &raw[..5], &raw[5..10])
+        })
+        .collect()
+}
+
+// Recovery code là chuỗi ngẫu nhiên nên sha256 là đủ, không cần bcrypt
+pub fn hash_recovery_code(code: &str) -> String {
+    let normalized: String = code
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+
+    sha256_hex(&normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_code_rejects_reused_step() {
+        let secret = generate_secret();
+        let totp = build_totp(&secret, "Flashcard", "staff@example.com").unwrap();
+        let now = 1_700_000_000;
+        let code = totp.generate(now);
+
+        let step = verify_code(&totp, &code, now, None).unwrap();
+        assert_eq!(step, (now / STEP_SECS) as i64);
+        assert_eq!(verify_code(&totp, &code, now, Some(step)), None);
+    }
+
+    #[test]
+    fn test_hash_recovery_code_is_normalized() {
+        let code = &generate_recovery_codes()[0];
+
+        assert_eq!(
+            hash_recovery_code(code),
+            hash_recovery_code(&code.to_uppercase().replace('-', " "))
+        );
+    }
+}
@@ -1,24 +1,196 @@
-use crate::{
-    entities::sea_orm_active_enums::QuestionTypeEnum,
-    models::quiz_question::CreateQuizQuestionAnswer,
+use {
+    crate::{
+        config::PasswordPolicyConfig,
+        entities::sea_orm_active_enums::QuestionTypeEnum,
+        enums::error::{Error, FieldError},
+        models::{
+            qna::{AnswerDTO, CreateQnARequest},
+            quiz_question::CreateQuizQuestionAnswer,
+        },
+    },
+    axum::{
+        extract::{FromRequest, Request},
+        Json,
+    },
+    once_cell::sync::OnceCell,
+    serde::de::DeserializeOwned,
+    validator::{Validate, ValidationError},
 };
 
-pub fn validate_answer(
-    question_type: &QuestionTypeEnum,
-    answers: &[CreateQuizQuestionAnswer],
-) -> bool {
+static PASSWORD_POLICY: OnceCell<PasswordPolicyConfig> = OnceCell::new();
+
+pub fn init_password_policy(cfg: &PasswordPolicyConfig) {
+    let _ = PASSWORD_POLICY.set(cfg.clone());
+}
+
+fn password_policy() -> &'static PasswordPolicyConfig {
+    PASSWORD_POLICY.get_or_init(PasswordPolicyConfig::default)
+}
+
+fn check_answer_flags(question_type: &QuestionTypeEnum, is_answers: &[bool]) -> bool {
     match question_type {
         QuestionTypeEnum::CheckBoxes => {
             // CheckBoxes must have exactly one correct answer
-            !answers.is_empty() && answers.iter().filter(|a| a.is_answer).count() == 1
+            !is_answers.is_empty() && is_answers.iter().filter(|a| **a).count() == 1
         }
         QuestionTypeEnum::MultipleChoice => {
             // MultipleChoice must have at least one correct answer
-            !answers.is_empty() && answers.iter().any(|a| a.is_answer)
+            !is_answers.is_empty() && is_answers.iter().any(|a| *a)
         }
         QuestionTypeEnum::TextFill => {
             // TextFill must have exactly one answer and it must be correct
-            answers.len() == 1 && answers[0].is_answer
+            is_answers.len() == 1 && is_answers[0]
         }
     }
 }
+
+pub fn validate_answer(
+    question_type: &QuestionTypeEnum,
+    answers: &[CreateQuizQuestionAnswer],
+) -> bool {
+    let is_answers = answers.iter().map(|a| a.is_answer).collect::<Vec<_>>();
+    check_answer_flags(question_type, &is_answers)
+}
+
+// Đáp án sửa cho câu hỏi đã có loại
+pub fn validate_answers_of_type(question_type: &QuestionTypeEnum, answers: &[AnswerDTO]) -> bool {
+    let is_answers = answers.iter().map(|a| a.is_answer).collect::<Vec<_>>();
+    check_answer_flags(question_type, &is_answers)
+}
+
+pub fn validate_qna_answers(payload: &CreateQnARequest) -> Result<(), ValidationError> {
+    let is_answers = payload
+        .answers
+        .iter()
+        .map(|a| a.is_answer)
+        .collect::<Vec<_>>();
+    if check_answer_flags(&payload.r#type, &is_answers) {
+        return Ok(());
+    }
+
+    Err(ValidationError::new("invalid_answers")
+        .with_message("Answers do not match the question type".into()))
+}
+
+pub fn validate_password(password: &str) -> Result<(), ValidationError> {
+    let policy = password_policy();
+
+    let rules = [
+        (
+            password.chars().count() >= policy.min_length,
+            "password_too_short",
+            format!(
+                "Password must have at least {} characters",
+                policy.min_length
+            ),
+        ),
+        (
+            !policy.require_lowercase || password.chars().any(|c| c.is_lowercase()),
+            "password_no_lowercase",
+            "Password must contain a lowercase letter".to_string(),
+        ),
+        (
+            !policy.require_uppercase || password.chars().any(|c| c.is_uppercase()),
+            "password_no_uppercase",
+            "Password must contain an uppercase letter".to_string(),
+        ),
+        (
+            !policy.require_digit || password.chars().any(|c| c.is_ascii_digit()),
+            "password_no_digit",
+            "Password must contain a digit".to_string(),
+        ),
+        (
+            !policy.require_symbol || password.chars().any(|c| !c.is_alphanumeric()),
+            "password_no_symbol",
+            "Password must contain a symbol".to_string(),
+        ),
+    ];
+
+    match rules.into_iter().find(|(ok, _, _)| !ok) {
+        Some((_, code, message)) => Err(ValidationError::new(code).with_message(message.into())),
+        None => Ok(()),
+    }
+}
+
+// Như `Json<T>` nhưng chạy `Validate`, lỗi trả 422 kèm lỗi từng field
+pub struct ValidatedJson<T>(pub T);
+
+impl<T, S> FromRequest<S> for ValidatedJson<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = Error;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(value) = Json::<T>::from_request(req, state)
+            .await
+            .map_err(|rejection| {
+                Error::Validation(vec![FieldError::new(
+                    "body",
+                    "invalid_json",
+                    rejection.body_text(),
+                )])
+            })?;
+
+        value.validate()?;
+
+        Ok(Self(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::models::qna::AnswerDTO, uuid::Uuid};
+
+    fn field_codes(payload: &CreateQnARequest) -> Vec<(String, String)> {
+        let Error::Validation(fields) = payload.validate().unwrap_err().into() else {
+            unreachable!()
+        };
+        fields.into_iter().map(|f| (f.field, f.code)).collect()
+    }
+
+    #[test]
+    fn nested_errors_have_field_paths() {
+        let mut payload = CreateQnARequest {
+            content: "What is 1 + 1?".into(),
+            content_format: Default::default(),
+            r#type: QuestionTypeEnum::MultipleChoice,
+            answers: vec![AnswerDTO {
+                content: "".into(),
+                is_answer: false,
+                media_id: None,
+            }],
+            set_id: Uuid::new_v4(),
+            media_id: None,
+        };
+        assert_eq!(
+            field_codes(&payload),
+            vec![("answers[0].content".into(), "length".into())]
+        );
+
+        // kiểm tra cấp struct chỉ chạy khi các field đã hợp lệ
+        payload.answers[0].content = "2".into();
+        assert_eq!(
+            field_codes(&payload),
+            vec![("body".into(), "invalid_answers".into())]
+        );
+    }
+
+    #[test]
+    fn password_policy_rules() {
+        assert!(validate_password("Secret123").is_ok());
+        assert_eq!(
+            validate_password("Sh0rt").unwrap_err().code,
+            "password_too_short"
+        );
+        assert_eq!(
+            validate_password("secret123").unwrap_err().code,
+            "password_no_uppercase"
+        );
+        assert_eq!(
+            validate_password("SecretSecret").unwrap_err().code,
+            "password_no_digit"
+        );
+    }
+}